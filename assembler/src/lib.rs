@@ -1,6 +1,6 @@
 pub mod parsing;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, path::Path};
 
 use cpu::chips::{b8::B8, b32::B32, thread::instructions::Instruction};
 
@@ -43,9 +43,25 @@ pub async fn assemble_file(input_path: &str, output_path: &str) -> Result<()> {
     tokio::fs::write(output_path, contents.clone())
         .await
         .context("writing output file")?;
+    write_symbols_file(output_path, &labels).await?;
     info!("assembled file: {input_path}",);
     Ok(())
 }
+/// writes `<output>.sym` with addresses of all labels, one `name address` per line. used by the
+/// debugger for breaking on labels.
+async fn write_symbols_file(output_path: &str, labels: &HashMap<String, u32>) -> Result<()> {
+    let mut labels: Vec<(&String, &u32)> = labels.iter().collect();
+    labels.sort_by_key(|(name, addr)| (**addr, *name));
+
+    let mut text = String::new();
+    for (name, addr) in labels {
+        // labels are stored 1 before the instruction because of jmp, debugger needs the real address
+        text.push_str(&format!("{name} {}\n", addr + 1));
+    }
+    tokio::fs::write(Path::new(output_path).with_extension("sym"), text)
+        .await
+        .context("writing symbols file")
+}
 fn instruction_data_to_binary(
     instruction_data: InstructionData,
     labels: &HashMap<String, u32>,
@@ -79,7 +79,7 @@ fn parse_instruction(
                 "RJmp" => parse_instruction_1(&tokens, line_nr, Instruction::RJmp).context("RJmp"),
                 "Jmpc" => parse_instruction_2(&tokens, line_nr, Instruction::Jmpc).context("Jmpc"),
                 "Init" => parse_instruction_2(&tokens, line_nr, Instruction::Init).context("Init"),
-                "Intr" => parse_instruction_2(&tokens, line_nr, Instruction::Intr).context("Intr"),
+                "Intr" => parse_instruction_3(&tokens, line_nr, Instruction::Intr).context("Intr"),
                 "Idt" => parse_instruction_1(&tokens, line_nr, Instruction::Idt).context("Idt"),
                 "Phrp" => parse_instruction_2(&tokens, line_nr, Instruction::Phrp).context("Phrp"),
                 "Read" => parse_instruction_2(&tokens, line_nr, Instruction::Read).context("Read"),
@@ -136,9 +136,18 @@ fn parse_instruction(
                 "Syscall" => parse_instruction_3(&tokens, line_nr, Instruction::Syscall).context("Syscall"),
 
                 "Push" => parse_instruction_1(&tokens, line_nr, Instruction::Push).context("Push"),
+                "Pop" => parse_instruction_1(&tokens, line_nr, Instruction::Pop).context("Pop"),
                 "Call" => parse_instruction_1(&tokens, line_nr, Instruction::Call).context("Call"),
                 "Ret" => Ok((Some(Instruction::Ret()), None)).context("Ret"),
                 "Iret"=>parse_instruction_1(&tokens, line_nr, Instruction::IRet).context("IRet"), 
+                "Ictx" => parse_instruction_2(&tokens, line_nr, Instruction::Ictx).context("Ictx"),
+                "Gmsk" => parse_instruction_1(&tokens, line_nr, Instruction::Gmsk).context("Gmsk"),
+                "Smsk" => parse_instruction_1(&tokens, line_nr, Instruction::Smsk).context("Smsk"),
+                "Tid" => parse_instruction_1(&tokens, line_nr, Instruction::Tid).context("Tid"),
+                "Cas" => parse_instruction_3(&tokens, line_nr, Instruction::Cas).context("Cas"),
+                "Xadd" => parse_instruction_2(&tokens, line_nr, Instruction::Xadd).context("Xadd"),
+                "Xchg" => parse_instruction_2(&tokens, line_nr, Instruction::Xchg).context("Xchg"),
+                "Fence" => Ok((Some(Instruction::Fence()), None)).context("Fence"),
 
                 not_handled_name => Err(anyhow!(
                     "instruction with name: {not_handled_name} doesn't exist / is not currently handled. line: {line_nr}"
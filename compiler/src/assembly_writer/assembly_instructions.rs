@@ -6,7 +6,15 @@ pub fn syscall(
     format!("Syscall r{syscall_id_register} , r{input_data_register} , r{output_data_register}\n")
 }
 pub fn init(register_thread_index: u8, start_address_register: u8) -> String {
-    format!("Init r{register_thread_index} , r{start_address_register}")
+    format!("Init r{register_thread_index} , r{start_address_register}\n")
+}
+/// Sends interrupt of kind `y` with data `z` to thread with index `x`.
+pub fn intr(register_thread_index: u8, kind_register: u8, data_register: u8) -> String {
+    format!("Intr r{register_thread_index} , r{kind_register} , r{data_register}\n")
+}
+/// Writes index of the running thread into `x`.
+pub fn tid(output_register: u8) -> String {
+    format!("Tid r{output_register}\n")
 }
 pub fn comment(text: &str) -> String {
     format!("// {}\n", text.replace("\n", "\n //"))
@@ -43,6 +51,37 @@ pub fn write(destination_address_register: u8, source_register: u8) -> String {
 pub fn iret(address_register: u8) -> String {
     format!("Iret r{address_register}\n")
 }
+/// Sets range of registers saved on interrupt entry and restored by `Iret`.
+pub fn ictx(first_register: u8, last_register: u8) -> String {
+    format!("Ictx r{first_register} , r{last_register}\n")
+}
+/// Reads interrupt mask of the thread into `x`.
+pub fn gmsk(output_register: u8) -> String {
+    format!("Gmsk r{output_register}\n")
+}
+/// Sets interrupt mask of the thread to value of `x`.
+pub fn smsk(mask_register: u8) -> String {
+    format!("Smsk r{mask_register}\n")
+}
+/// Writes random number from `y` to `z` (both included) into `x`.
+pub fn rng(output_register: u8, min_register: u8, max_register: u8) -> String {
+    format!("Rng r{output_register} , r{min_register} , r{max_register}\n")
+}
+/// If word at address in `x` equals `y` it's replaced by `z`, `y` gets the old word.
+pub fn cas(address_register: u8, expected_register: u8, new_register: u8) -> String {
+    format!("Cas r{address_register} , r{expected_register} , r{new_register}\n")
+}
+/// Adds `y` to word at address in `x`, `y` gets the old word.
+pub fn xadd(address_register: u8, value_register: u8) -> String {
+    format!("Xadd r{address_register} , r{value_register}\n")
+}
+/// Swaps `y` with word at address in `x`.
+pub fn xchg(address_register: u8, value_register: u8) -> String {
+    format!("Xchg r{address_register} , r{value_register}\n")
+}
+pub fn fence() -> String {
+    "Fence\n".to_string()
+}
 
 pub fn relative_jmp(address_register: u8) -> String {
     format!("RJmp r{address_register}\n")
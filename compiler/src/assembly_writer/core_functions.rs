@@ -196,6 +196,288 @@ pub fn idt(input_expr: Expression, assembly_data: &mut AssemblyData) -> Result<E
     })
 }
 
+/// sends interrupt of the kind with data to the thread, kind 9 is a message from other thread
+pub fn interrupt_thread(
+    thread_index_expr: Expression,
+    kind_expr: Expression,
+    data_expr: Expression,
+    assembly_data: &mut AssemblyData,
+) -> Result<ExpressionOutput> {
+    let mut output_code = String::new();
+
+    let thread_index_expr_out = handle_expr(thread_index_expr, assembly_data)?;
+    let kind_expr_out = handle_expr(kind_expr, assembly_data)?;
+    let data_expr_out = handle_expr(data_expr, assembly_data)?;
+    output_code += &(thread_index_expr_out.code + &kind_expr_out.code + &data_expr_out.code);
+
+    let thread_index_register = assembly_data.get_free_register()?;
+    let kind_register = assembly_data.get_free_register()?;
+    let data_register = assembly_data.get_free_register()?;
+    output_code += &thread_index_expr_out
+        .data
+        .context("thread index")?
+        .read_register(thread_index_register, 0, assembly_data)?;
+    output_code += &kind_expr_out
+        .data
+        .context("interrupt kind")?
+        .read_register(kind_register, 0, assembly_data)?;
+    output_code += &data_expr_out
+        .data
+        .context("interrupt data")?
+        .read_register(data_register, 0, assembly_data)?;
+
+    output_code +=
+        &assembly_instructions::intr(thread_index_register, kind_register, data_register);
+
+    assembly_data.mark_registers_free(&[thread_index_register, kind_register, data_register]);
+
+    Ok(ExpressionOutput {
+        code: output_code,
+        data: None,
+    })
+}
+
+/// value that the cpu left in a register before the first instruction, eg. where the heap is.
+/// Compiler hands out registers from the highest one down, so the low ones are still intact at
+/// the start of a program.
+pub fn boot_register(
+    register_expr: Expression,
+    assembly_data: &mut AssemblyData,
+) -> Result<ExpressionOutput> {
+    let Expression::Number(boot_register, _) = register_expr else {
+        bail!("expected input expression to be a number - index of the register")
+    };
+    let Result::Ok(boot_register) = u8::try_from(boot_register) else {
+        bail!("there is no register: {boot_register}")
+    };
+    let mut output_code = String::new();
+    let alloc_out = assembly_data.allocate_stack(1)?;
+    output_code += &alloc_out.0;
+    let output_data = Data {
+        stack_frame_offset: alloc_out.1 as i32,
+        size: 1,
+        data_type: DataType::U32,
+    };
+    let value_register = assembly_data.get_free_register()?;
+    output_code += &cp(value_register, boot_register);
+    output_code += &output_data.write_register(value_register, 0, assembly_data)?;
+    assembly_data.mark_registers_free(&[value_register]);
+    Ok(ExpressionOutput {
+        code: output_code,
+        data: Some(output_data),
+    })
+}
+
+pub fn thread_index(assembly_data: &mut AssemblyData) -> Result<ExpressionOutput> {
+    let mut output_code = String::new();
+    let alloc_out = assembly_data.allocate_stack(1)?;
+    output_code += &alloc_out.0;
+    let output_data = Data {
+        stack_frame_offset: alloc_out.1 as i32,
+        size: 1,
+        data_type: DataType::U32,
+    };
+    let index_register = assembly_data.get_free_register()?;
+    output_code += &assembly_instructions::tid(index_register);
+    output_code += &output_data.write_register(index_register, 0, assembly_data)?;
+    assembly_data.mark_registers_free(&[index_register]);
+    Ok(ExpressionOutput {
+        code: output_code,
+        data: Some(output_data),
+    })
+}
+
+/// first and last are indexes of registers that interrupt entry saves
+pub fn interrupt_context(
+    first_register_expr: Expression,
+    last_register_expr: Expression,
+    assembly_data: &mut AssemblyData,
+) -> Result<ExpressionOutput> {
+    let mut output_code = String::new();
+
+    let first_expr_out = handle_expr(first_register_expr, assembly_data)?;
+    let last_expr_out = handle_expr(last_register_expr, assembly_data)?;
+    output_code += &(first_expr_out.code + &last_expr_out.code);
+
+    let first_register = assembly_data.get_free_register()?;
+    let last_register = assembly_data.get_free_register()?;
+    output_code += &first_expr_out
+        .data
+        .context("first register")?
+        .read_register(first_register, 0, assembly_data)?;
+    output_code += &last_expr_out.data.context("last register")?.read_register(
+        last_register,
+        0,
+        assembly_data,
+    )?;
+
+    output_code += &assembly_instructions::ictx(first_register, last_register);
+
+    assembly_data.mark_registers_free(&[first_register, last_register]);
+
+    Ok(ExpressionOutput {
+        code: output_code,
+        data: None,
+    })
+}
+
+pub fn interrupt_mask(assembly_data: &mut AssemblyData) -> Result<ExpressionOutput> {
+    let mut output_code = String::new();
+    let alloc_out = assembly_data.allocate_stack(1)?;
+    output_code += &alloc_out.0;
+    let output_data = Data {
+        stack_frame_offset: alloc_out.1 as i32,
+        size: 1,
+        data_type: DataType::U32,
+    };
+    let mask_register = assembly_data.get_free_register()?;
+    output_code += &assembly_instructions::gmsk(mask_register);
+    output_code += &output_data.write_register(mask_register, 0, assembly_data)?;
+    assembly_data.mark_registers_free(&[mask_register]);
+    Ok(ExpressionOutput {
+        code: output_code,
+        data: Some(output_data),
+    })
+}
+
+/// random u32 from min to max, both included
+pub fn rng(
+    min_expr: Expression,
+    max_expr: Expression,
+    assembly_data: &mut AssemblyData,
+) -> Result<ExpressionOutput> {
+    let mut output_code = String::new();
+    let min_expr_out = handle_expr(min_expr, assembly_data)?;
+    let max_expr_out = handle_expr(max_expr, assembly_data)?;
+    output_code += &(min_expr_out.code + &max_expr_out.code);
+
+    let alloc_out = assembly_data.allocate_stack(1)?;
+    output_code += &alloc_out.0;
+    let output_data = Data {
+        stack_frame_offset: alloc_out.1 as i32,
+        size: 1,
+        data_type: DataType::U32,
+    };
+
+    let min_register = assembly_data.get_free_register()?;
+    let max_register = assembly_data.get_free_register()?;
+    let output_register = assembly_data.get_free_register()?;
+    output_code +=
+        &min_expr_out
+            .data
+            .context("min value")?
+            .read_register(min_register, 0, assembly_data)?;
+    output_code +=
+        &max_expr_out
+            .data
+            .context("max value")?
+            .read_register(max_register, 0, assembly_data)?;
+    output_code += &assembly_instructions::rng(output_register, min_register, max_register);
+    output_code += &output_data.write_register(output_register, 0, assembly_data)?;
+
+    assembly_data.mark_registers_free(&[min_register, max_register, output_register]);
+    Ok(ExpressionOutput {
+        code: output_code,
+        data: Some(output_data),
+    })
+}
+
+/// atomically replaces word at addr with new if it equals expected, returns the old word
+pub fn compare_and_swap(
+    addr_expr: Expression,
+    expected_expr: Expression,
+    new_expr: Expression,
+    assembly_data: &mut AssemblyData,
+) -> Result<ExpressionOutput> {
+    atomic_operation(
+        [addr_expr, expected_expr, new_expr],
+        |registers| assembly_instructions::cas(registers[0], registers[1], registers[2]),
+        assembly_data,
+    )
+}
+/// atomically adds value to word at addr, returns the old word
+pub fn fetch_add(
+    addr_expr: Expression,
+    value_expr: Expression,
+    assembly_data: &mut AssemblyData,
+) -> Result<ExpressionOutput> {
+    atomic_operation(
+        [addr_expr, value_expr],
+        |registers| assembly_instructions::xadd(registers[0], registers[1]),
+        assembly_data,
+    )
+}
+/// atomically writes value to word at addr, returns the old word
+pub fn exchange(
+    addr_expr: Expression,
+    value_expr: Expression,
+    assembly_data: &mut AssemblyData,
+) -> Result<ExpressionOutput> {
+    atomic_operation(
+        [addr_expr, value_expr],
+        |registers| assembly_instructions::xchg(registers[0], registers[1]),
+        assembly_data,
+    )
+}
+/// every atomic instruction takes address first and writes the old word into the second register
+fn atomic_operation<const N: usize>(
+    exprs: [Expression; N],
+    instruction: impl FnOnce(&[u8; N]) -> String,
+    assembly_data: &mut AssemblyData,
+) -> Result<ExpressionOutput> {
+    let mut output_code = String::new();
+    let mut datas = Vec::with_capacity(N);
+    for expr in exprs {
+        let expr_out = handle_expr(expr, assembly_data)?;
+        output_code += &expr_out.code;
+        datas.push(expr_out.data.context("atomic operation input")?);
+    }
+
+    let alloc_out = assembly_data.allocate_stack(1)?;
+    output_code += &alloc_out.0;
+    let output_data = Data {
+        stack_frame_offset: alloc_out.1 as i32,
+        size: 1,
+        data_type: DataType::U32,
+    };
+
+    let mut registers = [0; N];
+    for (register, data) in registers.iter_mut().zip(datas) {
+        *register = assembly_data.get_free_register()?;
+        output_code += &data.read_register(*register, 0, assembly_data)?;
+    }
+    output_code += &instruction(&registers);
+    output_code += &output_data.write_register(registers[1], 0, assembly_data)?;
+
+    assembly_data.mark_registers_free(&registers);
+    Ok(ExpressionOutput {
+        code: output_code,
+        data: Some(output_data),
+    })
+}
+
+pub fn set_interrupt_mask(
+    mask_expr: Expression,
+    assembly_data: &mut AssemblyData,
+) -> Result<ExpressionOutput> {
+    let mut output_code = String::new();
+    let expr_out = handle_expr(mask_expr, assembly_data)?;
+    output_code += &expr_out.code;
+
+    let mask_register = assembly_data.get_free_register()?;
+    output_code +=
+        &expr_out
+            .data
+            .context("mask")?
+            .read_register(mask_register, 0, assembly_data)?;
+    output_code += &assembly_instructions::smsk(mask_register);
+    assembly_data.mark_registers_free(&[mask_register]);
+    Ok(ExpressionOutput {
+        code: output_code,
+        data: None,
+    })
+}
+
 pub fn direct_reference_access(
     input_expr: Expression,
     assembly_data: &mut AssemblyData,
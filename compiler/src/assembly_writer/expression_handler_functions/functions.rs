@@ -3,9 +3,10 @@ pub mod data_types;
 use crate::assembly_writer::{
     assembly_instructions,
     core_functions::{
-        self, access_static_variable, create_static_variable, direct_reference_access, free, idt,
-        init_thread, jump, malloc, mark, memory_access, peripheral, print, read_addr,
-        read_addr_of_function, syscall,
+        self, access_static_variable, boot_register, compare_and_swap, create_static_variable,
+        direct_reference_access, exchange, fetch_add, free, idt, init_thread, interrupt_context,
+        interrupt_mask, interrupt_thread, jump, malloc, mark, memory_access, peripheral, print,
+        read_addr, read_addr_of_function, rng, set_interrupt_mask, syscall, thread_index,
     },
     data_types::FunctionInputData,
     helper_methods,
@@ -565,6 +566,82 @@ pub fn handle_core_function_call(
             expect_input_len(values, 1).context("idt")?;
             Ok(Some(idt(values[0].to_owned(), assembly_data)?))
         }
+        "interrupt_mask" => {
+            expect_input_len(values, 0).context("interrupt_mask")?;
+            Ok(Some(interrupt_mask(assembly_data)?))
+        }
+        "set_interrupt_mask" => {
+            expect_input_len(values, 1).context("set_interrupt_mask")?;
+            Ok(Some(set_interrupt_mask(
+                values[0].to_owned(),
+                assembly_data,
+            )?))
+        }
+        "interrupt_thread" => {
+            expect_input_len(values, 3).context("interrupt_thread")?;
+            Ok(Some(interrupt_thread(
+                values[0].to_owned(),
+                values[1].to_owned(),
+                values[2].to_owned(),
+                assembly_data,
+            )?))
+        }
+        "boot_register" => {
+            expect_input_len(values, 1).context("boot_register")?;
+            Ok(Some(boot_register(values[0].to_owned(), assembly_data)?))
+        }
+        "thread_index" => {
+            expect_input_len(values, 0).context("thread_index")?;
+            Ok(Some(thread_index(assembly_data)?))
+        }
+        "compare_and_swap" => {
+            expect_input_len(values, 3).context("compare_and_swap")?;
+            Ok(Some(compare_and_swap(
+                values[0].to_owned(),
+                values[1].to_owned(),
+                values[2].to_owned(),
+                assembly_data,
+            )?))
+        }
+        "fetch_add" => {
+            expect_input_len(values, 2).context("fetch_add")?;
+            Ok(Some(fetch_add(
+                values[0].to_owned(),
+                values[1].to_owned(),
+                assembly_data,
+            )?))
+        }
+        "exchange" => {
+            expect_input_len(values, 2).context("exchange")?;
+            Ok(Some(exchange(
+                values[0].to_owned(),
+                values[1].to_owned(),
+                assembly_data,
+            )?))
+        }
+        "fence" => {
+            expect_input_len(values, 0).context("fence")?;
+            Ok(Some(ExpressionOutput {
+                code: assembly_instructions::fence(),
+                data: None,
+            }))
+        }
+        "rng" => {
+            expect_input_len(values, 2).context("rng")?;
+            Ok(Some(rng(
+                values[0].to_owned(),
+                values[1].to_owned(),
+                assembly_data,
+            )?))
+        }
+        "interrupt_context" => {
+            expect_input_len(values, 2).context("interrupt_context")?;
+            Ok(Some(interrupt_context(
+                values[0].to_owned(),
+                values[1].to_owned(),
+                assembly_data,
+            )?))
+        }
         "access_reference" => {
             expect_input_len(values, 1).context("access_reference")?;
             Ok(Some(direct_reference_access(
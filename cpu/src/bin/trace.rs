@@ -0,0 +1,104 @@
+//! tool for binary traces written with `--trace`.
+//! `print` replays a trace as text, `diff` finds the first record where two runs diverge.
+use std::{collections::VecDeque, path::PathBuf};
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use cpu::trace::{TraceReader, TraceRecord};
+
+#[derive(Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+#[derive(Subcommand)]
+enum Command {
+    /// Print records of a trace
+    Print {
+        file: PathBuf,
+        /// Only records of this thread
+        #[arg(long)]
+        thread: Option<u16>,
+        /// Skip records before this cycle
+        #[arg(long, default_value_t = 0)]
+        from_cycle: u64,
+        /// Stop after this many printed records
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+    /// Compare two traces and show where they diverge. exits with 1 if they differ
+    Diff {
+        a: PathBuf,
+        b: PathBuf,
+        /// How many equal records before the divergence to show
+        #[arg(long, default_value_t = 10)]
+        context: usize,
+    },
+}
+
+fn main() -> Result<()> {
+    match Args::parse().command {
+        Command::Print {
+            file,
+            thread,
+            from_cycle,
+            limit,
+        } => {
+            let records = TraceReader::open(&file)?.filter(|record| match record {
+                Ok(record) => {
+                    record.cycle >= from_cycle
+                        && thread.is_none_or(|thread| record.thread == thread)
+                }
+                Err(_) => true,
+            });
+            for record in records.take(limit.unwrap_or(usize::MAX)) {
+                println!("{}", record?);
+            }
+        }
+        Command::Diff { a, b, context } => {
+            if !diff(TraceReader::open(&a)?, TraceReader::open(&b)?, context)? {
+                std::process::exit(1);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// returns true if traces are the same
+fn diff(mut a: TraceReader, mut b: TraceReader, context: usize) -> Result<bool> {
+    let mut previous: VecDeque<TraceRecord> = VecDeque::with_capacity(context);
+    let mut index: u64 = 0;
+    loop {
+        let (record_a, record_b) = match (a.read_record()?, b.read_record()?) {
+            (None, None) => {
+                println!("traces are the same, {index} records");
+                return Ok(true);
+            }
+            (Some(record_a), Some(record_b)) if record_a == record_b => {
+                if previous.len() == context {
+                    previous.pop_front();
+                }
+                if context > 0 {
+                    previous.push_back(record_a);
+                }
+                index += 1;
+                continue;
+            }
+            records => records,
+        };
+
+        println!("traces diverge at record {index}");
+        for record in &previous {
+            println!("  {record}");
+        }
+        match record_a {
+            Some(record) => println!("a {record}"),
+            None => println!("a ended"),
+        }
+        match record_b {
+            Some(record) => println!("b {record}"),
+            None => println!("b ended"),
+        }
+        return Ok(false);
+    }
+}
@@ -3,7 +3,7 @@ pub mod alu;
 pub mod b32;
 pub mod b8;
 pub mod bit;
+pub mod division;
 pub mod memory;
 pub mod multiplication;
-pub mod division;
 pub mod thread;
@@ -6,9 +6,9 @@ use super::b32::B32;
 /// Unsigned 32-bit division using the restoring (shift-subtract) algorithm.
 /// Returns None for division-by-zero, otherwise Some((quotient, remainder)).
 
-pub fn div_mod_restoring(dividend: u32, divisor: u32) -> (u32, u32) {
+pub fn div_mod_restoring(dividend: u32, divisor: u32) -> Option<(u32, u32)> {
     if divisor == 0 {
-        panic!("tried dividing by 0!");
+        return None;
     }
 
     // rem holds 64 bits: upper 32 are the running remainder, lower 32 hold (and shift in) dividend bits.
@@ -33,22 +33,31 @@ pub fn div_mod_restoring(dividend: u32, divisor: u32) -> (u32, u32) {
     }
 
     let remainder = (reminder >> 32) as u32;
-    (quotient, remainder)
+    Some((quotient, remainder))
+}
+
+impl B32 {
+    /// None when dividing by 0
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        div_mod_restoring(self.0, rhs.0).map(|result| B32(result.0))
+    }
+    /// None when dividing by 0
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        div_mod_restoring(self.0, rhs.0).map(|result| B32(result.1))
+    }
 }
 
 impl Div for B32 {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        let result = div_mod_restoring(self.0, rhs.0);
-        B32(result.0)
+        self.checked_div(rhs).expect("tried dividing by 0!")
     }
 }
 impl Rem for B32 {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        let result = div_mod_restoring(self.0, rhs.0);
-        B32(result.1)
+        self.checked_rem(rhs).expect("tried dividing by 0!")
     }
 }
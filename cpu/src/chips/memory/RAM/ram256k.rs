@@ -22,6 +22,13 @@ impl RAM256k {
     }
 
     pub const RAM_START_INDEX: u8 = 5 * 3;
+    /// number of 32 bit words
+    pub const SIZE: u32 = 8 * 32 * 1024;
+
+    /// addresses past the end wrap around in `read` and `write`, so callers have to check them
+    pub fn contains(addr: B32) -> bool {
+        addr.0 < Self::SIZE
+    }
 
     pub fn read(&self, addr: B32) -> B32 {
         let (sel_1, sel_2, sel_3) = get_selectors(addr, Self::RAM_START_INDEX);
@@ -1,26 +1,27 @@
 pub mod instructions;
-use anyhow::{Context, Ok, Result, anyhow};
-use crossbeam_queue::ArrayQueue;
+pub mod mmu;
+use anyhow::{Context, Ok, Result, anyhow, bail};
 use instructions::Instruction;
-use log::{debug, info};
+use log::{debug, info, warn};
+use mmu::{MMU, MemoryAccessKind};
 use std::{
     cell::OnceCell,
+    collections::VecDeque,
     sync::{
-        OnceLock,
-        atomic::{AtomicBool, AtomicU32},
+        Mutex, OnceLock,
+        atomic::{AtomicBool, AtomicU32, AtomicU64},
     },
-    time::Duration,
 };
-use tokio::time::sleep;
 
 use crate::{
-    MEMORY, SHOW_INSTRUCTION_FETCHING_DEBUG,
+    SHOW_INSTRUCTION_FETCHING_DEBUG,
     chips::{b32::B32, memory::RAM::ram256::RAM256},
     error::handle_error,
+    machine::Machine,
+    memory_map::Layout,
+    snapshot::{SnapshotReader, SnapshotWriter},
 };
 
-pub static CLOCK_MS: u64 = 1;
-
 use std::convert::TryFrom;
 
 use super::b8::B8;
@@ -34,12 +35,87 @@ pub enum InterruptKind {
     Timer = 1,
     Keyboard = 2,
     Mouse = 3,
+    /// data holds the virtual address that couldn't be translated
+    PageFault = 4,
+    /// data holds the word that couldn't be decoded as an instruction, or `Intr` with a kind that
+    /// isn't an interrupt
+    IllegalInstruction = 5,
+    /// data holds `FaultCause::Div` or `FaultCause::Mod`
+    DivideByZero = 6,
+    /// physical address is outside of memory or the stack region, data holds `FaultCause` of the
+    /// access
+    BadAddress = 7,
+    /// `Init` or `Intr` got index of thread that doesn't exist, data holds `FaultCause`
+    BadThreadIndex = 8,
+    /// send by other thread with `Intr`, data holds its message
+    Ipi = 9,
+    /// serial received bytes, data holds how many are waiting
+    SerialInput = 10,
+    /// gpu finished showing a frame, data holds the frame count
+    VBlank = 11,
+    /// other thread rang the doorbell of this thread's mailbox, data holds how many messages
+    /// are waiting
+    Mailbox = 12,
 }
 impl From<InterruptKind> for u32 {
     fn from(val: InterruptKind) -> Self {
         val as u32
     }
 }
+impl InterruptKind {
+    /// handler of higher priority can preempt handler of lower one when nesting is enabled
+    pub fn priority(self) -> u32 {
+        match self {
+            InterruptKind::Syscall => 0,
+            InterruptKind::Keyboard
+            | InterruptKind::Mouse
+            | InterruptKind::Ipi
+            | InterruptKind::SerialInput
+            | InterruptKind::VBlank
+            | InterruptKind::Mailbox => 1,
+            InterruptKind::Timer => 2,
+            // instruction that raised the fault can't continue until it is handled
+            InterruptKind::PageFault
+            | InterruptKind::IllegalInstruction
+            | InterruptKind::DivideByZero
+            | InterruptKind::BadAddress
+            | InterruptKind::BadThreadIndex => 3,
+        }
+    }
+    /// faults are raised by the running instruction, so they ignore the mask
+    pub fn is_fault(self) -> bool {
+        self.priority() == 3
+    }
+}
+
+/// bit of the interrupt mask that lets interrupts of higher priority preempt running handler,
+/// other bits mask interrupt kind with the same index
+pub const INTERRUPT_MASK_NESTING_BIT: u32 = 31;
+/// interrupts that come when this many are already waiting are dropped and counted, faults are
+/// queued anyway
+pub const INTERRUPT_QUEUE_LEN: usize = 16;
+
+/// cause code pushed as data of cpu faults, tells what the faulting instruction was doing
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultCause {
+    Div = 0,
+    Mod = 1,
+    Read = 2,
+    Write = 3,
+    Execute = 4,
+    Init = 5,
+    Intr = 6,
+    /// `Push` or `Call` above the stack region of the thread
+    StackOverflow = 7,
+    /// `Pop` or `Ret` below the base of the stack region
+    StackUnderflow = 8,
+}
+impl From<FaultCause> for u32 {
+    fn from(val: FaultCause) -> Self {
+        val as u32
+    }
+}
 
 #[derive(Debug)]
 pub struct Interrupt {
@@ -54,66 +130,237 @@ impl TryFrom<u32> for InterruptKind {
             1 => Ok(InterruptKind::Timer),
             2 => Ok(InterruptKind::Keyboard),
             3 => Ok(InterruptKind::Mouse),
+            4 => Ok(InterruptKind::PageFault),
+            5 => Ok(InterruptKind::IllegalInstruction),
+            6 => Ok(InterruptKind::DivideByZero),
+            7 => Ok(InterruptKind::BadAddress),
+            8 => Ok(InterruptKind::BadThreadIndex),
+            9 => Ok(InterruptKind::Ipi),
+            10 => Ok(InterruptKind::SerialInput),
+            11 => Ok(InterruptKind::VBlank),
+            12 => Ok(InterruptKind::Mailbox),
             _ => Err(anyhow!("{val} was not a valid interrupt type index!")),
         }
     }
 }
 
+pub enum InterruptEntry {
+    /// jumps to the handler
+    Jump(Instruction),
+    /// writing the entry to the stack raised a fault, nothing runs in this cycle
+    Faulted,
+}
+
 pub struct IDT {
     pub base_addr: AtomicU32,
 }
 
 pub struct InterruptController {
+    /// set by `Idt`
     pub interrupts_enabled: AtomicBool,
-    pub interrupts: ArrayQueue<Interrupt>,
+    /// interrupts waiting to be handled, in order in which they came
+    pending: Mutex<VecDeque<Interrupt>>,
+    dropped_interrupts: AtomicU32,
+    /// set with `Smsk`, see `INTERRUPT_MASK_NESTING_BIT`
+    mask: AtomicU32,
+    /// bit n is set while handler of priority n runs
+    in_service: AtomicU32,
     pub IDT: IDT,
+    /// registers first..first + count are saved on interrupt entry, set with `Ictx`
+    context_first_register: AtomicU32,
+    context_register_count: AtomicU32,
 }
 const ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::Relaxed;
 impl InterruptController {
-    pub fn get_interrupt_instruction(&self, thread: &Thread) -> Option<Instruction> {
-        if let Some(interrupt) = self.interrupts.pop()
-            && self.interrupts_enabled.load(ORDERING)
-        {
-            self.interrupts_enabled.store(false, ORDERING);
-
-            let interrupt_function_pointer =
-                self.IDT.base_addr.load(ORDERING) + interrupt.kind as u32;
-            let memory = MEMORY.get().expect("memory was not yet initialized");
-            let interrupt_function_addr = memory.read(B32(interrupt_function_pointer as u32));
-            // -1 because jmp instruction advances instruction addr by 1
-            let current_addr = thread.registers.read(CURRENT_ADDR_REGISTER) - B32(1);
-
-            let stack_head = thread.registers.read(STACK_HEAD_REGISTER);
-            thread
-                .registers
-                .write(stack_head + B32(0), CPU_REGISTER_2, true);
-            thread
-                .registers
-                .write(B32(interrupt.data), CPU_REGISTER_1, true);
-            thread.Write(CPU_REGISTER_2, CPU_REGISTER_1, true);
+    pub fn get_interrupt_instruction(
+        &self,
+        machine: &Machine,
+        thread: &Thread,
+    ) -> Result<Option<InterruptEntry>> {
+        let Some(interrupt) = self.take_acceptable_interrupt() else {
+            return Ok(None);
+        };
+        // pc of the instruction that runs next, for faults it's the one that faulted
+        let return_addr = thread.registers.read(CURRENT_ADDR_REGISTER);
 
-            thread
-                .registers
-                .write(stack_head + B32(2), CPU_REGISTER_2, true);
-            thread.registers.write(current_addr, CPU_REGISTER_1, true);
-            thread.Write(CPU_REGISTER_2, CPU_REGISTER_1, true);
+        let entry = match self.read_handler_addr(machine, thread, interrupt.kind)? {
+            Some(handler_addr) => self
+                .write_entry(machine, thread, &interrupt, return_addr)?
+                .map(|frame| (handler_addr, frame)),
+            None => None,
+        };
+        let Some((handler_addr, frame)) = entry else {
+            if interrupt.kind.is_fault() {
+                bail!(
+                    "{:?} with data: {} couldn't be handled, reading its handler or writing its entry to the stack faulted! pc-address: {return_addr}",
+                    interrupt.kind,
+                    interrupt.data,
+                );
+            }
+            // the new fault is handled first and then the entry is tried again
+            self.requeue(interrupt);
+            return Ok(Some(InterruptEntry::Faulted));
+        };
+        self.in_service
+            .fetch_or(1 << interrupt.kind.priority(), ORDERING);
+        thread
+            .registers
+            .write(frame + B32(2), STACK_HEAD_REGISTER, true);
 
-            thread
-                .registers
-                .write(stack_head + B32(2), STACK_HEAD_REGISTER, true);
+        // pc is set here, so the jump doesn't need a scratch register and the handler gets all of
+        // them as the interrupted code left them
+        thread
+            .registers
+            .write(handler_addr, CURRENT_ADDR_REGISTER, true);
+        Ok(Some(InterruptEntry::Jump(Instruction::Jmp(
+            CURRENT_ADDR_REGISTER,
+        ))))
+    }
+    /// IDT is read from physical memory, entry outside of ram and rom raises bad address. returns
+    /// None if it did
+    fn read_handler_addr(
+        &self,
+        machine: &Machine,
+        thread: &Thread,
+        kind: InterruptKind,
+    ) -> Result<Option<B32>> {
+        let addr = B32(self.IDT.base_addr.load(ORDERING)) + B32(kind as u32);
+        if !machine.memory.allows(addr, MemoryAccessKind::Read) {
+            thread.raise_bad_address(MemoryAccessKind::Read)?;
+            return Ok(None);
+        }
+        Ok(Some(machine.memory.read(addr)))
+    }
+    /// writes saved registers, interrupt data and return addr to the stack and returns addr of the
+    /// frame. returns None if a write raised a fault, the stack head isn't moved then.
+    fn write_entry(
+        &self,
+        machine: &Machine,
+        thread: &Thread,
+        interrupt: &Interrupt,
+        return_addr: B32,
+    ) -> Result<Option<B32>> {
+        // registers of the interrupted code go first, `Iret` finds them below the frame.
+        // stack head points at the first free slot, but compiled code keeps the return addr of a
+        // call there until the called function allocates it, so the entry starts one above it
+        let entry_addr = thread.registers.read(STACK_HEAD_REGISTER) + B32(1);
+        let context_registers = self.context_registers();
+        for (offset, register) in context_registers.iter().enumerate() {
+            let value = thread.registers.read(*register);
+            if !thread.write_memory(machine, value, entry_addr + B32(offset as u32), true)? {
+                return Ok(None);
+            }
+        }
+        let frame = entry_addr + B32(context_registers.len() as u32);
 
-            thread
-                .registers
-                .write(interrupt_function_addr, CPU_REGISTER_1, true);
-            return Some(Instruction::Jmp(CPU_REGISTER_1));
+        if !thread.write_memory(machine, B32(interrupt.data), frame + B32(0), true)?
+            || !thread.write_memory(machine, return_addr, frame + B32(2), true)?
+        {
+            return Ok(None);
         }
-        None
+        Ok(Some(frame))
     }
 
+    /// handler with the highest priority is the one that is running, so it is the one that ends
     fn end_interrupt(&self) {
-        self.interrupts_enabled.store(true, ORDERING);
+        let in_service = self.in_service.load(ORDERING);
+        if let Some(priority) = highest_bit(in_service) {
+            self.in_service
+                .store(in_service & !(1 << priority), ORDERING);
+        }
+    }
+    /// whether a halted thread has a reason to run, masked interrupts count too
+    pub fn wakes_halted(&self) -> bool {
+        self.interrupts_enabled.load(ORDERING) && !self.pending.lock().unwrap().is_empty()
+    }
+    /// whether interrupt of this kind would be handled now
+    pub fn accepts(&self, kind: InterruptKind) -> bool {
+        if !self.interrupts_enabled.load(ORDERING) {
+            return false;
+        }
+        let mask = self.mask.load(ORDERING);
+        if !kind.is_fault() && mask & (1 << kind as u32) != 0 {
+            return false;
+        }
+        match highest_bit(self.in_service.load(ORDERING)) {
+            None => true,
+            Some(running_priority) => {
+                mask & (1 << INTERRUPT_MASK_NESTING_BIT) != 0 && kind.priority() > running_priority
+            }
+        }
+    }
+    /// the first of interrupts with the highest priority that can be handled now
+    fn take_acceptable_interrupt(&self) -> Option<Interrupt> {
+        let mut pending = self.pending.lock().unwrap();
+        let mut best: Option<usize> = None;
+        for (index, interrupt) in pending.iter().enumerate() {
+            if !self.accepts(interrupt.kind) {
+                continue;
+            }
+            if best.is_none_or(|best| interrupt.kind.priority() > pending[best].kind.priority()) {
+                best = Some(index);
+            }
+        }
+        pending.remove(best?)
+    }
+    /// faults are never dropped, the instruction that raised one would run again and fault forever
+    fn queue(&self, interrupt: Interrupt) {
+        let mut pending = self.pending.lock().unwrap();
+        if pending.len() >= INTERRUPT_QUEUE_LEN && !interrupt.kind.is_fault() {
+            let dropped = self.dropped_interrupts.fetch_add(1, ORDERING) + 1;
+            warn!(
+                "interrupt queue is full, dropped {:?} with data: {}. dropped so far: {dropped}",
+                interrupt.kind, interrupt.data
+            );
+            return;
+        }
+        pending.push_back(interrupt);
+    }
+    /// puts back interrupt whose entry couldn't be finished, it keeps its place in the order
+    fn requeue(&self, interrupt: Interrupt) {
+        self.pending.lock().unwrap().push_front(interrupt);
+    }
+    pub fn is_pending(&self, kind: InterruptKind) -> bool {
+        self.pending
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|interrupt| interrupt.kind == kind)
+    }
+    /// interrupts that were lost because the queue was full
+    pub fn dropped_interrupts(&self) -> u32 {
+        self.dropped_interrupts.load(ORDERING)
+    }
+    pub fn mask(&self) -> u32 {
+        self.mask.load(ORDERING)
+    }
+    pub fn set_mask(&self, mask: u32) {
+        self.mask.store(mask, ORDERING);
+    }
+
+    /// registers saved on interrupt entry, pc is left out because `Iret` sets it
+    pub fn context_registers(&self) -> Vec<B8> {
+        let first = self.context_first_register.load(ORDERING);
+        let count = self.context_register_count.load(ORDERING);
+        (first..first + count)
+            .map(|register| B8(register as u8))
+            .filter(|register| register.0 != CURRENT_ADDR_REGISTER.0)
+            .collect()
+    }
+    pub fn set_context_registers(&self, first: B8, last: B8) {
+        let count = if last.0 < first.0 {
+            0
+        } else {
+            (last.0 - first.0) as u32 + 1
+        };
+        self.context_first_register.store(first.0 as u32, ORDERING);
+        self.context_register_count.store(count, ORDERING);
     }
 }
+fn highest_bit(value: u32) -> Option<u32> {
+    (value != 0).then(|| 31 - value.leading_zeros())
+}
+
 pub const STACK_HEAD_REGISTER: B8 = B8(254);
 pub const STACK_FRAME_REGISTER: B8 = B8(255);
 pub const CPU_REGISTER_1: B8 = B8(253);
@@ -121,98 +368,232 @@ pub const CPU_REGISTER_2: B8 = B8(252);
 pub const CURRENT_ADDR_REGISTER: B8 = B8(251);
 pub struct Thread {
     pub interrupt_controller: InterruptController,
+    pub mmu: MMU,
     registers: RAM256,
     is_halting: AtomicBool,
+    /// cycles of the machine's virtual clock left until thread wakes up from `Sleep`
+    sleep_cycles_left: AtomicU32,
     stack_base_addr: B32,
+    /// in words, pushing past it is a stack overflow
+    stack_size: u32,
+    /// cycles in which this thread ran an instruction or an interrupt entry, for the debugger. not
+    /// saved in snapshots
+    instructions_run: AtomicU64,
 }
 impl Thread {
+    /// halted thread wakes up even if the interrupt is masked, it is handled once the mask allows
+    /// it
     pub fn interrupt(&self, interrupt: Interrupt) {
-        self.interrupt_controller.interrupts.push(interrupt);
-        self.is_halting.store(false, ORDERING);
-    }
-    pub async fn run_loop(&self) {
-        info!("run_loop");
-        loop {
-            if self.is_halting.load(std::sync::atomic::Ordering::Relaxed) {
-                sleep(Duration::from_millis(1));
-                continue;
-            }
+        self.interrupt_controller.queue(interrupt);
+        if self.interrupt_controller.wakes_halted() {
+            self.is_halting.store(false, ORDERING);
+        }
+    }
+    /// runs one cycle of this thread. halted thread does nothing and sleeping one only counts down
+    /// its sleep.
+    pub fn step(&self, machine: &Machine) -> Result<()> {
+        if self.is_halting.load(ORDERING) {
+            return Ok(());
+        }
+        let sleep_cycles_left = self.sleep_cycles_left.load(ORDERING);
+        if sleep_cycles_left > 0 {
+            self.sleep_cycles_left
+                .store(sleep_cycles_left - 1, ORDERING);
+            return Ok(());
+        }
 
-            let instruction = self.fetch_instruction();
-            if SHOW_INSTRUCTION_FETCHING_DEBUG {
-                info!("pc-address: {}", self.registers.read(CURRENT_ADDR_REGISTER));
-                info!("fetch_instruction: {:?}", instruction);
-            }
-            if let Err(err) = self
-                .run_instruction(instruction, true)
-                .await
-                .context("encountered error while running instruction on thread")
-            {
-                handle_error(err);
-            }
-            self.registers.increment(CURRENT_ADDR_REGISTER);
+        self.instructions_run.fetch_add(1, ORDERING);
+        self.fetch_and_run_instruction(machine)?;
+        self.registers.increment(CURRENT_ADDR_REGISTER);
+        Ok(())
+    }
+    pub fn instructions_run(&self) -> u64 {
+        self.instructions_run.load(ORDERING)
+    }
+    pub fn is_halting(&self) -> bool {
+        self.is_halting.load(ORDERING)
+    }
+    pub fn is_sleeping(&self) -> bool {
+        self.sleep_cycles_left.load(ORDERING) > 0
+    }
+    pub fn read_register(&self, register: B8) -> B32 {
+        self.registers.read(register)
+    }
+    pub fn write_register(&self, register: B8, data: B32) {
+        self.registers.write(data, register, true);
+    }
+    /// starts at the os and tells it where things are: r0 command line address, r1 heap start,
+    /// r2 heap end, r3 serial address, r4 framebuffer address
+    pub fn set_boot_registers(&self, layout: &Layout) {
+        self.write_register(CURRENT_ADDR_REGISTER, B32(layout.os_addr));
+        self.write_register(B8(0), B32(layout.command_line_addr));
+        self.write_register(B8(1), B32(layout.heap_start));
+        self.write_register(B8(2), B32(layout.heap_end));
+        self.write_register(B8(3), B32(layout.serial_addr));
+        self.write_register(B8(4), B32(layout.framebuffer_addr));
+    }
+
+    fn fetch_and_run_instruction(&self, machine: &Machine) -> Result<()> {
+        let pc = self.registers.read(CURRENT_ADDR_REGISTER);
+        // instruction fetch can page fault, then the interrupt is handled on next fetch
+        let Some((instruction, interrupt_entry)) = self.fetch_instruction(machine)? else {
+            return Ok(());
+        };
+        machine.trace_instruction(pc, instruction, interrupt_entry);
+        if SHOW_INSTRUCTION_FETCHING_DEBUG {
+            info!("pc-address: {}", self.registers.read(CURRENT_ADDR_REGISTER));
+            info!("fetch_instruction: {:?}", instruction);
         }
+        self.run_instruction(machine, instruction, true)
     }
 
-    fn fetch_instruction(&self) -> Instruction {
+    /// second value is true when the instruction comes from interrupt entry
+    fn fetch_instruction(&self, machine: &Machine) -> Result<Option<(Instruction, bool)>> {
         // could use mux because i can convert instruction into B32 but this is simpler
-        match self.interrupt_controller.get_interrupt_instruction(self) {
-            Some(interrupt_instruction) => interrupt_instruction,
-            None => self.read_instruction_form_current_pc_memory(),
+        match self
+            .interrupt_controller
+            .get_interrupt_instruction(machine, self)?
+        {
+            Some(InterruptEntry::Jump(interrupt_instruction)) => {
+                Ok(Some((interrupt_instruction, true)))
+            }
+            // the fault is handled on next fetch
+            Some(InterruptEntry::Faulted) => Ok(None),
+            None => Ok(self
+                .read_instruction_form_current_pc_memory(machine)?
+                .map(|instruction| (instruction, false))),
         }
     }
-    fn read_instruction_form_current_pc_memory(&self) -> Instruction {
+    fn read_instruction_form_current_pc_memory(
+        &self,
+        machine: &Machine,
+    ) -> Result<Option<Instruction>> {
         let addr = self.registers.read(CURRENT_ADDR_REGISTER);
-        let data = MEMORY.get().unwrap().read(addr);
+        let Some(data) = self.read_memory(machine, addr, MemoryAccessKind::Execute)? else {
+            return Ok(None);
+        };
 
-        data.into()
+        match Instruction::try_from(data) {
+            Result::Ok(instruction) => Ok(Some(instruction)),
+            Err(err) => {
+                debug!("illegal instruction at pc-address: {}: {err}", addr.0);
+                self.raise_fault(Interrupt {
+                    kind: InterruptKind::IllegalInstruction,
+                    data: data.0,
+                })?;
+                Ok(None)
+            }
+        }
     }
 }
 
-pub async fn clock_cycle(thread: &Thread) {
-    let memory = MEMORY
-        .get()
-        .expect("gui loop run before init function or memory was not yet initialized");
-    let mut not_very_accurate_time_sec: u32 = 0;
-    loop {
-        tokio::time::sleep(Duration::from_secs(1)).await;
-        not_very_accurate_time_sec = 1;
-        if thread
-            .interrupt_controller
+impl Thread {
+    pub(crate) fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        writer.u32(self.stack_base_addr.0);
+        writer.u32(self.stack_size);
+        for register in 0..=255 {
+            writer.u32(self.registers.read(B8(register)).0);
+        }
+        writer.bool(self.is_halting.load(ORDERING));
+        writer.u32(self.sleep_cycles_left.load(ORDERING));
+
+        let controller = &self.interrupt_controller;
+        writer.bool(controller.interrupts_enabled.load(ORDERING));
+        writer.u32(controller.IDT.base_addr.load(ORDERING));
+        writer.u32(controller.context_first_register.load(ORDERING));
+        writer.u32(controller.context_register_count.load(ORDERING));
+        writer.u32(controller.mask.load(ORDERING));
+        writer.u32(controller.in_service.load(ORDERING));
+        writer.u32(controller.dropped_interrupts.load(ORDERING));
+        self.mmu.write_snapshot(writer);
+
+        let pending = controller.pending.lock().unwrap();
+        writer.u32(pending.len() as u32);
+        for interrupt in pending.iter() {
+            writer.u32(interrupt.kind.into());
+            writer.u32(interrupt.data);
+        }
+    }
+    pub(crate) fn read_snapshot(reader: &mut SnapshotReader) -> Result<Thread> {
+        let stack_base_addr = B32(reader.u32()?);
+        let thread = create_thread(stack_base_addr, reader.u32()?);
+        for register in 0..=255 {
+            thread
+                .registers
+                .write(B32(reader.u32()?), B8(register), true);
+        }
+        thread.is_halting.store(reader.bool()?, ORDERING);
+        thread.sleep_cycles_left.store(reader.u32()?, ORDERING);
+
+        let controller = &thread.interrupt_controller;
+        controller
             .interrupts_enabled
-            .load(ORDERING)
+            .store(reader.bool()?, ORDERING);
+        controller.IDT.base_addr.store(reader.u32()?, ORDERING);
+        controller
+            .context_first_register
+            .store(reader.u32()?, ORDERING);
+        controller
+            .context_register_count
+            .store(reader.u32()?, ORDERING);
+        controller.mask.store(reader.u32()?, ORDERING);
+        controller.in_service.store(reader.u32()?, ORDERING);
+        controller.dropped_interrupts.store(reader.u32()?, ORDERING);
+        thread.mmu.read_snapshot(reader)?;
+
+        let interrupt_count = reader.u32()? as usize;
+        let mut pending = controller.pending.lock().unwrap();
+        for _ in 0..interrupt_count {
+            let kind = InterruptKind::try_from(reader.u32()?)?;
+            let data = reader.u32()?;
+            pending.push_back(Interrupt { kind, data });
+        }
+        // only faults can go past the length of the queue
+        if pending
+            .iter()
+            .filter(|interrupt| !interrupt.kind.is_fault())
+            .count()
+            > INTERRUPT_QUEUE_LEN
         {
-            thread.interrupt(Interrupt {
-                kind: InterruptKind::Timer,
-                data: 0,
-            });
-            break;
+            return Err(anyhow!(
+                "snapshot has more queued interrupts than fit in the queue"
+            ));
         }
+        drop(pending);
+        Ok(thread)
     }
 }
-pub fn spawn_threads(thread_count: usize) {
+
+pub fn spawn_threads(thread_count: usize, layout: &Layout) -> Vec<Thread> {
     let mut threads = Vec::with_capacity(thread_count);
     for i in 0..thread_count {
-        threads.push(create_thread(B32((30000 + 20000 * i) as u32)));
+        threads.push(create_thread(layout.stack_base_addr(i), layout.stack_size));
     }
-    THREADS.get_or_init(|| threads);
+    threads
 }
-pub fn create_thread(stack_base_addr: B32) -> Thread {
+pub fn create_thread(stack_base_addr: B32, stack_size: u32) -> Thread {
     let registers = RAM256::new();
     registers.write(stack_base_addr, B8(255), true);
     registers.write(stack_base_addr, B8(254), true);
     Thread {
         interrupt_controller: InterruptController {
             interrupts_enabled: AtomicBool::new(false),
-            interrupts: ArrayQueue::new(5),
+            pending: Mutex::new(VecDeque::with_capacity(INTERRUPT_QUEUE_LEN)),
+            dropped_interrupts: AtomicU32::new(0),
+            mask: AtomicU32::new(0),
+            in_service: AtomicU32::new(0),
             IDT: IDT {
                 base_addr: AtomicU32::new(0),
             },
+            context_first_register: AtomicU32::new(0),
+            context_register_count: AtomicU32::new(0),
         },
+        mmu: MMU::new(),
         stack_base_addr,
+        stack_size,
         registers,
         is_halting: (AtomicBool::new(false)),
+        sleep_cycles_left: AtomicU32::new(0),
+        instructions_run: AtomicU64::new(0),
     }
 }
-
-pub static THREADS: OnceLock<Vec<Thread>> = OnceLock::new();
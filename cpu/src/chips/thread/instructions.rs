@@ -1,3 +1,4 @@
+pub mod atomic;
 pub mod bitwise;
 
 pub(crate) mod comparison;
@@ -17,13 +18,14 @@ use crate::chips::{
 };
 
 use super::Thread;
+use crate::machine::Machine;
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Instruction {
     Jmp(B8),
     Jmpc(B8, B8),
     Init(B8, B8),
-    Intr(B8, B8),
+    Intr(B8, B8, B8),
     Idt(B8),
     Phrp(B8, B8),
     Read(B8, B8),
@@ -66,6 +68,14 @@ pub enum Instruction {
     Ret(),
     IRet(B8),
     RJmpc(B8, B8),
+    Ictx(B8, B8),
+    Gmsk(B8),
+    Smsk(B8),
+    Tid(B8),
+    Cas(B8, B8, B8),
+    Xadd(B8, B8),
+    Xchg(B8, B8),
+    Fence(),
 }
 
 impl From<Instruction> for B8 {
@@ -74,7 +84,7 @@ impl From<Instruction> for B8 {
             Instruction::Jmp(_) => B8(0),
             Instruction::Jmpc(_, _) => B8(1),
             Instruction::Init(_, _) => B8(2),
-            Instruction::Intr(_, _) => B8(3),
+            Instruction::Intr(_, _, _) => B8(3),
             Instruction::Idt(_) => B8(4),
             Instruction::Phrp(_, _) => B8(5),
             Instruction::Read(_, _) => B8(6),
@@ -113,17 +123,28 @@ impl From<Instruction> for B8 {
             Instruction::RJmp(_) => B8(40),
 
             Instruction::RJmpc(_, _) => B8(41),
+            Instruction::Ictx(_, _) => B8(42),
+            Instruction::Gmsk(_) => B8(43),
+            Instruction::Smsk(_) => B8(44),
+            Instruction::Tid(_) => B8(45),
+            Instruction::Cas(_, _, _) => B8(46),
+            Instruction::Xadd(_, _) => B8(47),
+            Instruction::Xchg(_, _) => B8(48),
+            Instruction::Fence() => B8(49),
         }
     }
 }
 
-impl From<B32> for Instruction {
-    fn from(value: B32) -> Self {
-        match value.byte(0).0 {
+/// unknown opcodes are an error, cpu turns them into illegal instruction fault
+impl TryFrom<B32> for Instruction {
+    type Error = anyhow::Error;
+
+    fn try_from(value: B32) -> Result<Self> {
+        Ok(match value.byte(0).0 {
             0 => Self::Jmp(value.byte(1)),
             1 => Self::Jmpc(value.byte(1), value.byte(2)),
             2 => Self::Init(value.byte(1), value.byte(2)),
-            3 => Self::Intr(value.byte(1), value.byte(2)),
+            3 => Self::Intr(value.byte(1), value.byte(2), value.byte(3)),
             4 => Self::Idt(value.byte(1)),
             5 => Self::Phrp(value.byte(1), value.byte(2)),
             6 => Self::Read(value.byte(1), value.byte(2)),
@@ -163,10 +184,18 @@ impl From<B32> for Instruction {
             40 => Self::RJmp(value.byte(1)),
 
             41 => Self::RJmpc(value.byte(1), value.byte(2)),
+            42 => Self::Ictx(value.byte(1), value.byte(2)),
+            43 => Self::Gmsk(value.byte(1)),
+            44 => Self::Smsk(value.byte(1)),
+            45 => Self::Tid(value.byte(1)),
+            46 => Self::Cas(value.byte(1), value.byte(2), value.byte(3)),
+            47 => Self::Xadd(value.byte(1), value.byte(2)),
+            48 => Self::Xchg(value.byte(1), value.byte(2)),
+            49 => Self::Fence(),
             index => {
-                panic!("conversion form B32 to instruction with index: {index} is not supported")
+                bail!("conversion form B32 to instruction with index: {index} is not supported")
             }
-        }
+        })
     }
 }
 impl From<Instruction> for B32 {
@@ -206,14 +235,16 @@ impl From<Instruction> for B32 {
                     B8(0), // fill
                 ])
             }
-            Instruction::Intr(thread_index_register, interrupt_type_index_register) => {
-                B32::from_bytes([
-                    value.into(), // command index
-                    thread_index_register,
-                    interrupt_type_index_register,
-                    B8(0), // fill
-                ])
-            }
+            Instruction::Intr(
+                thread_index_register,
+                interrupt_type_index_register,
+                data_register,
+            ) => B32::from_bytes([
+                value.into(), // command index
+                thread_index_register,
+                interrupt_type_index_register,
+                data_register,
+            ]),
             Instruction::Idt(address_register) => B32::from_bytes([
                 value.into(), // command index
                 address_register,
@@ -500,44 +531,107 @@ impl From<Instruction> for B32 {
                 B8(0), // fill
                 B8(0), // fill
             ]),
+            Instruction::Ictx(first_register, last_register) => B32::from_bytes([
+                value.into(), // command index
+                first_register,
+                last_register,
+                B8(0), // fill
+            ]),
+            Instruction::Gmsk(output_register) => B32::from_bytes([
+                value.into(), // command index
+                output_register,
+                B8(0), // fill
+                B8(0), // fill
+            ]),
+            Instruction::Smsk(mask_register) => B32::from_bytes([
+                value.into(), // command index
+                mask_register,
+                B8(0), // fill
+                B8(0), // fill
+            ]),
+            Instruction::Tid(output_register) => B32::from_bytes([
+                value.into(), // command index
+                output_register,
+                B8(0), // fill
+                B8(0), // fill
+            ]),
+            Instruction::Cas(address_register, expected_register, new_register) => {
+                B32::from_bytes([
+                    value.into(), // command index
+                    address_register,
+                    expected_register,
+                    new_register,
+                ])
+            }
+            Instruction::Xadd(address_register, value_register) => B32::from_bytes([
+                value.into(), // command index
+                address_register,
+                value_register,
+                B8(0), // fill
+            ]),
+            Instruction::Xchg(address_register, value_register) => B32::from_bytes([
+                value.into(), // command index
+                address_register,
+                value_register,
+                B8(0), // fill
+            ]),
+            Instruction::Fence() => B32::from_bytes([
+                value.into(), // command index
+                B8(0),        // fill
+                B8(0),        // fill
+                B8(0),        // fill
+            ]),
         }
     }
 }
 
 impl Thread {
-    pub async fn run_instruction(&self, instruction: Instruction, run: bool) -> Result<()> {
+    pub fn run_instruction(
+        &self,
+        machine: &Machine,
+        instruction: Instruction,
+        run: bool,
+    ) -> Result<()> {
         match instruction {
             Instruction::Jmp(register_jump_target) => self.Jmp(register_jump_target, run),
             Instruction::Jmpc(register_jump_target, register_condition) => {
                 self.Jmpc(register_jump_target, register_condition, run)
             }
             Instruction::Init(start_address_register, register_thread_index) => {
-                self.Init(start_address_register, register_thread_index, run)?
-            }
-            Instruction::Intr(thread_index_register, interrupt_type_index_register) => {
-                self.Intr(thread_index_register, interrupt_type_index_register, run)
+                self.Init(machine, start_address_register, register_thread_index, run)?
             }
+            Instruction::Intr(
+                thread_index_register,
+                interrupt_type_index_register,
+                data_register,
+            ) => self.Intr(
+                machine,
+                thread_index_register,
+                interrupt_type_index_register,
+                data_register,
+                run,
+            )?,
             Instruction::Idt(address_register) => self.Idt(address_register, run),
             Instruction::Phrp(index_register, data_register) => {
-                self.Phrp(index_register, data_register, run).await?
+                self.Phrp(machine, index_register, data_register, run)?
             }
             Instruction::Read(source_address_register, destination_register) => {
-                self.Read(source_address_register, destination_register, run)
+                self.Read(machine, source_address_register, destination_register, run)?
             }
             Instruction::Write(destination_address_register, source_register) => {
-                self.Write(destination_address_register, source_register, run)
+                self.Write(machine, destination_address_register, source_register, run)?
             }
             Instruction::Cp(source_register, destination_register) => {
                 self.Cp(source_register, destination_register, run)
             }
             Instruction::Clr(register) => self.Clr(register, run),
-            Instruction::Set(register) => self.Set(register, run),
+            Instruction::Set(register) => self.Set(machine, register, run)?,
             Instruction::Pgt(address_register) => self.Pgt(address_register, run),
             Instruction::Add(a_register, b_register) => self.Add(a_register, b_register, run),
             Instruction::Sub(a_register, b_register) => self.Sub(a_register, b_register, run),
-            Instruction::Div(a_register, b_register) => self.Div(a_register, b_register, run),
+            Instruction::Div(a_register, b_register) => self.Div(a_register, b_register, run)?,
             Instruction::Mul(a_register, b_register) => self.Mul(a_register, b_register, run),
-            Instruction::Mod(a_register, b_register) => self.Mod(a_register, b_register, run),
+            Instruction::Mod(a_register, b_register) => self.Mod(a_register, b_register, run)?,
             Instruction::Neg(register) => self.Neg(register, run),
             Instruction::Abs(register) => self.Abs(register, run),
             Instruction::And(a_register, b_register) => self.And(a_register, b_register, run),
@@ -562,30 +656,51 @@ impl Thread {
                 self.Gt(a_register, b_register, out_register, run)
             }
             Instruction::Halt() => self.Halt(run),
-            Instruction::Sleep(length_register) => self.Sleep(length_register, run).await,
+            Instruction::Sleep(length_register) => self.Sleep(length_register, run),
             Instruction::Rng(out_register, min_register, max_register) => {
-                self.Rng(out_register, min_register, max_register, run)
+                self.Rng(machine, out_register, min_register, max_register, run)
             }
             Instruction::Syscall(
                 system_call_index_register,
                 argument_block_register,
                 output_data_addr_register,
             ) => self.Syscall(
+                machine,
                 system_call_index_register,
                 argument_block_register,
                 output_data_addr_register,
                 run,
-            ),
-            Instruction::Push(contents_register) => self.Push(contents_register, run),
-            Instruction::Pop(output_register) => self.Pop(output_register, run),
-            Instruction::Call(address_register) => self.Call(address_register, run),
-            Instruction::Ret() => self.Ret(run),
+            )?,
+            Instruction::Push(contents_register) => self.Push(machine, contents_register, run)?,
+            Instruction::Pop(output_register) => self.Pop(machine, output_register, run)?,
+            Instruction::Call(address_register) => self.Call(machine, address_register, run)?,
+            Instruction::Ret() => self.Ret(machine, run)?,
 
             Instruction::RJmp(addresss_register) => self.RJmp(addresss_register, run),
             Instruction::RJmpc(addresss_register, condition_register) => {
                 self.RJmpc(condition_register, addresss_register, run)
             }
-            Instruction::IRet(address_register) => self.Iret(address_register, run),
+            Instruction::IRet(address_register) => self.Iret(machine, address_register, run)?,
+            Instruction::Ictx(first_register, last_register) => {
+                self.Ictx(first_register, last_register, run)
+            }
+            Instruction::Gmsk(output_register) => self.Gmsk(output_register, run),
+            Instruction::Smsk(mask_register) => self.Smsk(mask_register, run),
+            Instruction::Tid(output_register) => self.Tid(machine, output_register, run),
+            Instruction::Cas(address_register, expected_register, new_register) => self.Cas(
+                machine,
+                address_register,
+                expected_register,
+                new_register,
+                run,
+            )?,
+            Instruction::Xadd(address_register, value_register) => {
+                self.Xadd(machine, address_register, value_register, run)?
+            }
+            Instruction::Xchg(address_register, value_register) => {
+                self.Xchg(machine, address_register, value_register, run)?
+            }
+            Instruction::Fence() => self.Fence(run),
         }
         Ok(())
     }
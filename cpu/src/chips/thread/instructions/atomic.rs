@@ -0,0 +1,98 @@
+use anyhow::Result;
+
+use crate::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{Thread, mmu::MemoryAccessKind},
+    },
+    machine::Machine,
+};
+
+// Machine runs one instruction of one thread at a time, so every instruction below reads and
+// writes its word without any other thread running in between. When the read or the write
+// faults nothing is changed and the instruction runs again after the handler.
+impl Thread {
+    /// if word at addr equals `expected` it's replaced by `new`. `expected` gets the old word,
+    /// so the swap happened when it didn't change.
+    pub fn Cas(
+        &self,
+        machine: &Machine,
+        address_register: B8,
+        expected_register: B8,
+        new_register: B8,
+        run: bool,
+    ) -> Result<()> {
+        self.read_modify_write(
+            machine,
+            address_register,
+            expected_register,
+            |old| {
+                (old == self.registers.read(expected_register))
+                    .then(|| self.registers.read(new_register))
+            },
+            run,
+        )
+    }
+    /// adds value to the word at addr, value register gets the old word
+    pub fn Xadd(
+        &self,
+        machine: &Machine,
+        address_register: B8,
+        value_register: B8,
+        run: bool,
+    ) -> Result<()> {
+        self.read_modify_write(
+            machine,
+            address_register,
+            value_register,
+            |old| {
+                Some(B32(old
+                    .0
+                    .wrapping_add(self.registers.read(value_register).0)))
+            },
+            run,
+        )
+    }
+    /// swaps value with the word at addr
+    pub fn Xchg(
+        &self,
+        machine: &Machine,
+        address_register: B8,
+        value_register: B8,
+        run: bool,
+    ) -> Result<()> {
+        self.read_modify_write(
+            machine,
+            address_register,
+            value_register,
+            |_| Some(self.registers.read(value_register)),
+            run,
+        )
+    }
+    /// every memory access is already visible to other threads before the next instruction,
+    /// so there is nothing to wait for. It marks places that depend on the ordering.
+    pub fn Fence(&self, run: bool) {}
+
+    /// modify gets the old word and returns the new one, None leaves the word as it was
+    fn read_modify_write(
+        &self,
+        machine: &Machine,
+        address_register: B8,
+        old_value_register: B8,
+        modify: impl FnOnce(B32) -> Option<B32>,
+        run: bool,
+    ) -> Result<()> {
+        let addr = self.registers.read(address_register);
+        let Some(old) = self.read_memory(machine, addr, MemoryAccessKind::Read)? else {
+            return Ok(());
+        };
+        if let Some(new) = modify(old)
+            && !self.write_memory(machine, new, addr, run)?
+        {
+            return Ok(());
+        }
+        self.registers.write(old, old_value_register, run);
+        Ok(())
+    }
+}
@@ -1,26 +1,32 @@
 use anyhow::Result;
 use log::info;
-use std::{
-    sync::{self, atomic::Ordering::Relaxed},
-    time::Duration,
-};
-use tokio::time::sleep;
+use std::sync::{self, atomic::Ordering::Relaxed};
 
 use crate::{
     B8,
-    chips::thread::{CURRENT_ADDR_REGISTER, ORDERING, THREADS, Thread, clock_cycle},
-    peripherals::call_peripheral,
+    chips::{
+        b32::B32,
+        thread::{
+            CURRENT_ADDR_REGISTER, FaultCause, Interrupt, InterruptKind, ORDERING,
+            STACK_HEAD_REGISTER, Thread, instructions::Instruction, mmu::MemoryAccessKind,
+        },
+    },
+    machine::Machine,
 };
 
 impl Thread {
+    /// doesn't stop while an interrupt waits, so code can mask it, check for work and halt without
+    /// missing one that came in between
     pub fn Halt(&self, run: bool) {
-        self.is_halting.store(true, Relaxed);
+        self.is_halting
+            .store(!self.interrupt_controller.wakes_halted(), Relaxed);
     }
-    pub async fn Sleep(&self, length_register: B8, run: bool) {
-        sleep(Duration::from_millis(
-            self.registers.read(length_register).0 as u64,
-        ))
-        .await;
+    /// length is in cycles of the machine's virtual clock
+    pub fn Sleep(&self, length_register: B8, run: bool) {
+        if run {
+            self.sleep_cycles_left
+                .store(self.registers.read(length_register).0, Relaxed);
+        }
     }
     pub fn Jmp(&self, register_jump_target: B8, run: bool) {
         // store addr
@@ -43,13 +49,16 @@ impl Thread {
     }
     pub fn Init(
         &self,
+        machine: &Machine,
         register_thread_index: B8,
         start_address_register: B8,
         run: bool,
     ) -> Result<()> {
         let thread_index = self.registers.read(register_thread_index);
         let start_address = self.registers.read(start_address_register);
-        let thread = &THREADS.get().unwrap()[thread_index.0 as usize];
+        let Some(thread) = machine.threads.get(thread_index.0 as usize) else {
+            return self.raise_bad_thread_index(FaultCause::Init);
+        };
 
         thread
             .registers
@@ -59,20 +68,115 @@ impl Thread {
         Ok(())
     }
 
-    pub fn Intr(&self, thread_index_register: B8, interrupt_type_index_register: B8, run: bool) {
+    /// data register is passed as data of the interrupt, for `InterruptKind::Ipi` it's the message.
+    /// Kind that isn't an interrupt raises illegal instruction with this instruction as data.
+    pub fn Intr(
+        &self,
+        machine: &Machine,
+        thread_index_register: B8,
+        interrupt_type_index_register: B8,
+        data_register: B8,
+        run: bool,
+    ) -> Result<()> {
         let thread_index = self.registers.read(thread_index_register);
         let interrupt_type = self.registers.read(interrupt_type_index_register);
-        THREADS.get().expect("THREADS were not initialized")[thread_index.0 as usize].interrupt(
-            crate::chips::thread::Interrupt {
-                kind: interrupt_type.0.try_into().unwrap(),
-                data: 0,
-            },
-        );
+        let data = self.registers.read(data_register);
+        let Some(thread) = machine.threads.get(thread_index.0 as usize) else {
+            return self.raise_bad_thread_index(FaultCause::Intr);
+        };
+        let Ok(kind) = InterruptKind::try_from(interrupt_type.0) else {
+            let instruction = Instruction::Intr(
+                thread_index_register,
+                interrupt_type_index_register,
+                data_register,
+            );
+            return self.raise_fault(Interrupt {
+                kind: InterruptKind::IllegalInstruction,
+                data: B32::from(instruction).0,
+            });
+        };
+        if !run {
+            return Ok(());
+        }
+        thread.interrupt(Interrupt { kind, data: data.0 });
+        Ok(())
+    }
+    /// writes index of this thread, the same one that `Init` and `Intr` use
+    pub fn Tid(&self, machine: &Machine, output_register: B8, run: bool) {
+        self.registers
+            .write(B32(machine.thread_index(self) as u32), output_register, run);
+    }
+    fn raise_bad_thread_index(&self, cause: FaultCause) -> Result<()> {
+        self.raise_fault(Interrupt {
+            kind: InterruptKind::BadThreadIndex,
+            data: cause.into(),
+        })
     }
     const ORDERING: sync::atomic::Ordering = Relaxed;
-    pub fn Iret(&self, address_register: B8, run: bool) {
-        self.Jmp(address_register, run);
+    /// jumps only after the context is restored, so a fault while reading it returns into the
+    /// handler and `Iret` runs again
+    pub fn Iret(&self, machine: &Machine, address_register: B8, run: bool) -> Result<()> {
+        // address register can be one of the restored ones
+        let return_addr = self.registers.read(address_register);
+        if !self.restore_interrupt_context(machine, run)? {
+            return Ok(());
+        }
+        // entry pushes pc of the instruction that runs next, -1 because pc is incremented after
+        // this instruction
+        self.registers
+            .write(return_addr - B32(1), CURRENT_ADDR_REGISTER, run);
         self.interrupt_controller.end_interrupt();
+        Ok(())
+    }
+    /// stack head has to point at the return addr slot, the same way as right after the entry.
+    /// returns false if page fault was raised, then nothing is changed
+    fn restore_interrupt_context(&self, machine: &Machine, run: bool) -> Result<bool> {
+        let context_registers = self.interrupt_controller.context_registers();
+        let entry_addr =
+            self.registers.read(STACK_HEAD_REGISTER) - B32(2 + context_registers.len() as u32);
+        // everything is read first, a fault in the middle mustn't leave half of the registers
+        // restored
+        let mut values = Vec::with_capacity(context_registers.len());
+        for offset in 0..context_registers.len() {
+            let Some(value) = self.read_memory(
+                machine,
+                entry_addr + B32(offset as u32),
+                MemoryAccessKind::Read,
+            )?
+            else {
+                return Ok(false);
+            };
+            values.push(value);
+        }
+        for (register, value) in context_registers.iter().zip(values) {
+            self.registers.write(value, *register, run);
+        }
+        // everything that the entry pushed is popped, even when no registers were saved
+        self.registers
+            .write(entry_addr - B32(1), STACK_HEAD_REGISTER, run);
+        Ok(true)
+    }
+    /// sets which registers are saved on interrupt entry and restored by `Iret`. Registers are
+    /// read as indexes of first and last register, last smaller than first turns saving off.
+    pub fn Ictx(&self, first_register: B8, last_register: B8, run: bool) {
+        if !run {
+            return;
+        }
+        let first = self.registers.read(first_register).byte(0);
+        let last = self.registers.read(last_register).byte(0);
+        self.interrupt_controller.set_context_registers(first, last);
+    }
+    /// reads interrupt mask of this thread
+    pub fn Gmsk(&self, output_register: B8, run: bool) {
+        self.registers
+            .write(B32(self.interrupt_controller.mask()), output_register, run);
+    }
+    /// bit n masks interrupt kind n, bit 31 enables nesting of handlers
+    pub fn Smsk(&self, mask_register: B8, run: bool) {
+        if run {
+            self.interrupt_controller
+                .set_mask(self.registers.read(mask_register).0);
+        }
     }
     pub fn Idt(&self, address_register: B8, run: bool) {
         let base_addr = self.registers.read(address_register);
@@ -86,10 +190,18 @@ impl Thread {
 
         true;
     }
-    pub async fn Phrp(&self, index_register: B8, data_register: B8, run: bool) -> Result<()> {
+    pub fn Phrp(
+        &self,
+        machine: &Machine,
+        index_register: B8,
+        data_register: B8,
+        run: bool,
+    ) -> Result<()> {
         let peripheral_index = self.registers.read(index_register);
         let data = self.registers.read(data_register);
-        call_peripheral(peripheral_index, data).await?;
+        machine
+            .peripherals
+            .call(machine.thread_index(self), peripheral_index, data)?;
         Ok(())
     }
 }
@@ -1,6 +1,10 @@
+use anyhow::Result;
 use log::info;
 
-use crate::{B8, chips::thread::Thread};
+use crate::{
+    B8,
+    chips::thread::{FaultCause, Interrupt, InterruptKind, Thread},
+};
 impl Thread {
     pub fn Add(&self, a_register: B8, b_register: B8, run: bool) {
         self.registers.write(
@@ -16,12 +20,16 @@ impl Thread {
             run,
         );
     }
-    pub fn Div(&self, a_register: B8, b_register: B8, run: bool) {
-        self.registers.write(
-            self.registers.read(a_register) / self.registers.read(b_register),
-            a_register,
-            run,
-        );
+    pub fn Div(&self, a_register: B8, b_register: B8, run: bool) -> Result<()> {
+        let Some(result) = self
+            .registers
+            .read(a_register)
+            .checked_div(self.registers.read(b_register))
+        else {
+            return self.raise_divide_by_zero(FaultCause::Div);
+        };
+        self.registers.write(result, a_register, run);
+        Ok(())
     }
     pub fn Mul(&self, a_register: B8, b_register: B8, run: bool) {
         self.registers.write(
@@ -30,12 +38,22 @@ impl Thread {
             run,
         );
     }
-    pub fn Mod(&self, a_register: B8, b_register: B8, run: bool) {
-        self.registers.write(
-            self.registers.read(a_register) % self.registers.read(b_register),
-            a_register,
-            run,
-        );
+    pub fn Mod(&self, a_register: B8, b_register: B8, run: bool) -> Result<()> {
+        let Some(result) = self
+            .registers
+            .read(a_register)
+            .checked_rem(self.registers.read(b_register))
+        else {
+            return self.raise_divide_by_zero(FaultCause::Mod);
+        };
+        self.registers.write(result, a_register, run);
+        Ok(())
+    }
+    fn raise_divide_by_zero(&self, cause: FaultCause) -> Result<()> {
+        self.raise_fault(Interrupt {
+            kind: InterruptKind::DivideByZero,
+            data: cause.into(),
+        })
     }
     pub fn Neg(&self, register: B8, run: bool) {
         let read = self.registers.read(register);
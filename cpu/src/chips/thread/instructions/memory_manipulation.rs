@@ -1,28 +1,48 @@
+use anyhow::Result;
 use log::info;
 
 use crate::{
-    MEMORY,
     chips::{
         b8::B8,
         b32::B32,
-        thread::{CURRENT_ADDR_REGISTER, Thread},
+        thread::{CURRENT_ADDR_REGISTER, Thread, mmu::MemoryAccessKind},
     },
+    machine::Machine,
 };
 impl Thread {
-    pub fn Read(&self, destination_register: B8, source_address_register: B8, run: bool) {
-        let data = MEMORY
-            .get()
-            .unwrap()
-            .read(self.registers.read(source_address_register));
+    pub fn Read(
+        &self,
+        machine: &Machine,
+        destination_register: B8,
+        source_address_register: B8,
+        run: bool,
+    ) -> Result<()> {
+        let Some(data) = self.read_memory(
+            machine,
+            self.registers.read(source_address_register),
+            MemoryAccessKind::Read,
+        )?
+        else {
+            return Ok(());
+        };
 
         self.registers.write(data, destination_register, run);
+        Ok(())
     }
-    pub fn Write(&self, destination_address_register: B8, source_register: B8, run: bool) {
-        MEMORY.get().unwrap().write(
+    pub fn Write(
+        &self,
+        machine: &Machine,
+        destination_address_register: B8,
+        source_register: B8,
+        run: bool,
+    ) -> Result<()> {
+        self.write_memory(
+            machine,
             self.registers.read(source_register),
             self.registers.read(destination_address_register),
             run,
-        );
+        )?;
+        Ok(())
     }
     pub fn Cp(&self, destination_register: B8, source_register: B8, run: bool) {
         self.registers.write(
@@ -34,11 +54,15 @@ impl Thread {
     pub fn Clr(&self, register: B8, run: bool) {
         self.registers.write(B32(0), register, run);
     }
-    pub fn Set(&self, register: B8, run: bool) {
+    pub fn Set(&self, machine: &Machine, register: B8, run: bool) -> Result<()> {
         let data_addr = self.registers.read(CURRENT_ADDR_REGISTER) + B32(1);
+        // immediate value is fetched like an instruction
+        let Some(data) = self.read_memory(machine, data_addr, MemoryAccessKind::Execute)? else {
+            return Ok(());
+        };
         self.registers.increment(CURRENT_ADDR_REGISTER);
-        self.registers
-            .write(MEMORY.get().unwrap().read(data_addr), register, run);
+        self.registers.write(data, register, run);
+        Ok(())
     }
     // sets offset by value of LOAD_BASE_REGISTER
     pub fn RJmpc(&self, condition_register: B8, register: B8, run: bool) {
@@ -61,6 +85,9 @@ impl Thread {
         self.Jmp(register, run);
     }
     pub fn Pgt(&self, address_register: B8, run: bool) {
-        todo!();
+        if run {
+            self.mmu
+                .set_page_table(self.registers.read(address_register));
+        }
     }
 }
@@ -1,17 +1,80 @@
-use crate::chips::{b8::B8, thread::Thread};
+use anyhow::Result;
+
+use crate::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{
+            CURRENT_ADDR_REGISTER, FaultCause, Interrupt, InterruptKind, STACK_HEAD_REGISTER,
+            Thread, mmu::MemoryAccessKind,
+        },
+    },
+    machine::Machine,
+};
+
+// stack grows upwards, the stack head register points at the first free slot - the same way
+// compiler allocates stack.
 impl Thread {
-    pub fn Push(&self, contents_register: B8, run: bool) {
-        todo!();
+    pub fn Push(&self, machine: &Machine, contents_register: B8, run: bool) -> Result<()> {
+        self.push_value(machine, self.registers.read(contents_register), run)?;
+        Ok(())
+    }
+    pub fn Pop(&self, machine: &Machine, output_register: B8, run: bool) -> Result<()> {
+        if let Some(value) = self.pop_value(machine, run)? {
+            self.registers.write(value, output_register, run);
+        }
+        Ok(())
     }
-    pub fn Pop(&self, output_register: B8, run: bool) {
-        todo!();
+
+    pub fn Call(&self, machine: &Machine, address_register: B8, run: bool) -> Result<()> {
+        // the return addr is the addr of this instruction, the pc gets incremented after `Ret`
+        // the same way as after jmp
+        if self.push_value(machine, self.registers.read(CURRENT_ADDR_REGISTER), run)? {
+            self.Jmp(address_register, run);
+        }
+        Ok(())
+    }
+    pub fn Ret(&self, machine: &Machine, run: bool) -> Result<()> {
+        if let Some(return_addr) = self.pop_value(machine, run)? {
+            self.registers
+                .write(return_addr, CURRENT_ADDR_REGISTER, run);
+        }
+        Ok(())
     }
 
-    pub fn Call(&self, address_register: B8, run: bool) {
-        // self.stack_base_addr
-        todo!()
+    /// returns false if a fault was raised
+    fn push_value(&self, machine: &Machine, value: B32, run: bool) -> Result<bool> {
+        let stack_head = self.registers.read(STACK_HEAD_REGISTER);
+        if stack_head >= self.stack_base_addr + B32(self.stack_size) {
+            self.raise_stack_fault(FaultCause::StackOverflow)?;
+            return Ok(false);
+        }
+        if !self.write_memory(machine, value, stack_head, run)? {
+            return Ok(false);
+        }
+        self.registers
+            .write(stack_head + B32(1), STACK_HEAD_REGISTER, run);
+        Ok(true)
+    }
+    /// returns None if a fault was raised
+    fn pop_value(&self, machine: &Machine, run: bool) -> Result<Option<B32>> {
+        let stack_head = self.registers.read(STACK_HEAD_REGISTER);
+        if stack_head <= self.stack_base_addr {
+            self.raise_stack_fault(FaultCause::StackUnderflow)?;
+            return Ok(None);
+        }
+        let new_stack_head = stack_head - B32(1);
+        let Some(value) = self.read_memory(machine, new_stack_head, MemoryAccessKind::Read)? else {
+            return Ok(None);
+        };
+        self.registers
+            .write(new_stack_head, STACK_HEAD_REGISTER, run);
+        Ok(Some(value))
     }
-    pub fn Ret(&self, run: bool) {
-        todo!();
+    fn raise_stack_fault(&self, cause: FaultCause) -> Result<()> {
+        self.raise_fault(Interrupt {
+            kind: InterruptKind::BadAddress,
+            data: cause.into(),
+        })
     }
 }
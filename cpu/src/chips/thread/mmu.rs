@@ -0,0 +1,192 @@
+use std::sync::atomic::{AtomicBool, AtomicU32};
+
+use anyhow::{Result, bail};
+
+use crate::{
+    chips::{
+        b32::B32,
+        thread::{CURRENT_ADDR_REGISTER, FaultCause, Interrupt, InterruptKind, ORDERING, Thread},
+    },
+    machine::Machine,
+    memory_map::Memory,
+    snapshot::{SnapshotReader, SnapshotWriter},
+};
+
+/// page has 2^PAGE_SIZE_LOG2 words
+pub const PAGE_SIZE_LOG2: u32 = 10;
+pub const PAGE_SIZE: u32 = 1 << PAGE_SIZE_LOG2;
+/// page table is a flat array of entries, one for every virtual page.
+/// 256 pages * 1024 words is the virtual address space of a thread, pages can point anywhere in
+/// the physical one.
+pub const PAGE_TABLE_LEN: u32 = 256;
+
+// page table entry layout:
+// bit 31 -> page is present
+// bit 30 -> page is writable
+// lower bits -> index of physical page
+pub const PAGE_PRESENT_BIT: u8 = 31;
+pub const PAGE_WRITABLE_BIT: u8 = 30;
+pub const PAGE_FRAME_MASK: u32 = (1 << (32 - PAGE_SIZE_LOG2)) - 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryAccessKind {
+    Read,
+    Write,
+    Execute,
+}
+impl From<MemoryAccessKind> for FaultCause {
+    fn from(access: MemoryAccessKind) -> Self {
+        match access {
+            MemoryAccessKind::Read => FaultCause::Read,
+            MemoryAccessKind::Write => FaultCause::Write,
+            MemoryAccessKind::Execute => FaultCause::Execute,
+        }
+    }
+}
+
+/// per thread memory management unit. Translates virtual addresses using page table set by the
+/// `Pgt` instruction. Paging is disabled until page table addr is set to something else than 0.
+pub struct MMU {
+    pub page_table_addr: AtomicU32,
+    pub paging_enabled: AtomicBool,
+}
+impl MMU {
+    pub fn new() -> Self {
+        Self {
+            page_table_addr: AtomicU32::new(0),
+            paging_enabled: AtomicBool::new(false),
+        }
+    }
+    pub(crate) fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        writer.u32(self.page_table_addr.load(ORDERING));
+        writer.bool(self.paging_enabled.load(ORDERING));
+    }
+    pub(crate) fn read_snapshot(&self, reader: &mut SnapshotReader) -> Result<()> {
+        self.page_table_addr.store(reader.u32()?, ORDERING);
+        self.paging_enabled.store(reader.bool()?, ORDERING);
+        Ok(())
+    }
+    pub fn set_page_table(&self, page_table_addr: B32) {
+        self.page_table_addr.store(page_table_addr.0, ORDERING);
+        self.paging_enabled
+            .store(page_table_addr != B32(0), ORDERING);
+    }
+
+    /// returns None if the access should raise a page fault
+    pub fn translate(&self, memory: &Memory, addr: B32, access: MemoryAccessKind) -> Option<B32> {
+        if !self.paging_enabled.load(ORDERING) {
+            return Some(addr);
+        }
+
+        let page_index = addr.0 >> PAGE_SIZE_LOG2;
+        if page_index >= PAGE_TABLE_LEN {
+            return None;
+        }
+        // page table itself lives in physical memory
+        let entry = memory.read(B32(self.page_table_addr.load(ORDERING)) + B32(page_index));
+
+        if !entry.bit(PAGE_PRESENT_BIT) {
+            return None;
+        }
+        if access == MemoryAccessKind::Write && !entry.bit(PAGE_WRITABLE_BIT) {
+            return None;
+        }
+
+        let frame_index = entry.0 & PAGE_FRAME_MASK;
+        Some(B32(
+            (frame_index << PAGE_SIZE_LOG2) | (addr.0 & (PAGE_SIZE - 1))
+        ))
+    }
+}
+
+impl Thread {
+    /// returns None if page fault was raised
+    pub fn read_memory(
+        &self,
+        machine: &Machine,
+        addr: B32,
+        access: MemoryAccessKind,
+    ) -> Result<Option<B32>> {
+        match self.mmu.translate(&machine.memory, addr, access) {
+            Some(physical_addr)
+                if access == MemoryAccessKind::Read && machine.mmio.contains(physical_addr) =>
+            {
+                Ok(Some(machine.mmio_read(self, physical_addr)?))
+            }
+            Some(physical_addr) if !machine.memory.allows(physical_addr, access) => {
+                self.raise_bad_address(access)?;
+                Ok(None)
+            }
+            Some(physical_addr) => Ok(Some(machine.memory.read(physical_addr))),
+            None => {
+                self.raise_page_fault(addr)?;
+                Ok(None)
+            }
+        }
+    }
+    /// returns false if page fault was raised
+    pub fn write_memory(&self, machine: &Machine, data: B32, addr: B32, run: bool) -> Result<bool> {
+        match self
+            .mmu
+            .translate(&machine.memory, addr, MemoryAccessKind::Write)
+        {
+            Some(physical_addr) if machine.mmio.contains(physical_addr) => {
+                if run {
+                    machine.trace_memory_write(physical_addr, data);
+                    machine.mmio_write(self, physical_addr, data)?;
+                }
+                Ok(true)
+            }
+            Some(physical_addr)
+                if !machine
+                    .memory
+                    .allows(physical_addr, MemoryAccessKind::Write) =>
+            {
+                self.raise_bad_address(MemoryAccessKind::Write)?;
+                Ok(false)
+            }
+            Some(physical_addr) => {
+                if run {
+                    machine.check_watchpoints(self, physical_addr, data);
+                    machine.trace_memory_write(physical_addr, data);
+                }
+                machine.memory.write(data, physical_addr, run);
+                Ok(true)
+            }
+            None => {
+                self.raise_page_fault(addr)?;
+                Ok(false)
+            }
+        }
+    }
+
+    pub(super) fn raise_bad_address(&self, access: MemoryAccessKind) -> Result<()> {
+        self.raise_fault(Interrupt {
+            kind: InterruptKind::BadAddress,
+            data: FaultCause::from(access).into(),
+        })
+    }
+    fn raise_page_fault(&self, addr: B32) -> Result<()> {
+        self.raise_fault(Interrupt {
+            kind: InterruptKind::PageFault,
+            data: addr.0,
+        })
+    }
+    /// Aborts current instruction and queues interrupt that handles the fault. pc is moved back so
+    /// the faulting instruction is run again after the handler returns with `IRet`.
+    pub fn raise_fault(&self, interrupt: Interrupt) -> Result<()> {
+        if !self.interrupt_controller.accepts(interrupt.kind) {
+            bail!(
+                "{:?} with data: {} couldn't be handled, interrupts are disabled or handler of the same priority is running! pc-address: {}",
+                interrupt.kind,
+                interrupt.data,
+                self.registers.read(CURRENT_ADDR_REGISTER)
+            );
+        }
+        let current_addr = self.registers.read(CURRENT_ADDR_REGISTER);
+        self.registers
+            .write(current_addr - B32(1), CURRENT_ADDR_REGISTER, true);
+        self.interrupt(interrupt);
+        Ok(())
+    }
+}
@@ -0,0 +1,425 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    io::{BufRead, Write},
+    path::Path,
+};
+
+use anyhow::{Context, Result, bail};
+use log::info;
+
+use crate::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{CURRENT_ADDR_REGISTER, instructions::Instruction},
+    },
+    fb::headless::HeadlessFrameBuffer,
+    machine::{Machine, Watchpoint, WatchpointHit},
+};
+
+const HELP: &str = "commands:
+  b <addr|label>          add breakpoint, stops when pc of any running thread reaches it
+  d <addr|label>          delete breakpoint
+  bl                      list breakpoints and watchpoints
+  w <addr|label> [len]    stop after a write into [addr, addr+len)
+  dw <addr|label> [len]   delete watchpoint
+  c [cycles]              continue until a break, or until cycles pass
+  s [count]               step selected thread by count instructions (default 1), other
+                          threads run alongside it, ignores breakpoints
+  t <index>               select thread
+  r                       print registers of selected thread
+  x <addr|label> [len]    dump physical memory (default 16 words)
+  sym <file> [base]       load labels written by the assembler
+  save <file>             save snapshot of the machine
+  shot <file>             save frame that the display shows (.png or .ppm)
+  trace <file|off>        start or stop writing binary trace
+  q                       quit debugger";
+
+/// why `continue_execution` returned
+#[derive(Debug)]
+pub enum StopReason {
+    Breakpoint {
+        thread: usize,
+        addr: B32,
+    },
+    Watchpoint(Vec<WatchpointHit>),
+    CyclesDone,
+    StepsDone,
+    /// selected thread halted before it ran all steps
+    Halted {
+        thread: usize,
+    },
+}
+
+/// REPL debugger that drives a machine cycle by cycle.
+/// addresses are the values of pc, so with paging enabled they are virtual. watchpoints and
+/// memory dumps use physical addresses.
+pub struct Debugger {
+    breakpoints: BTreeSet<u32>,
+    labels: HashMap<String, u32>,
+    /// for finding the label closest to an address
+    labels_by_addr: BTreeMap<u32, String>,
+    selected_thread: usize,
+}
+impl Debugger {
+    pub fn new() -> Self {
+        Self {
+            breakpoints: BTreeSet::new(),
+            labels: HashMap::new(),
+            labels_by_addr: BTreeMap::new(),
+            selected_thread: 0,
+        }
+    }
+
+    /// loads `.sym` file written by the assembler. `base` is the address the binary was loaded at.
+    /// returns number of loaded labels
+    pub fn load_symbols(&mut self, path: &Path, base: B32) -> Result<usize> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read symbols file: {}", path.display()))?;
+        let mut count = 0;
+        for (line_nr, line) in text.lines().enumerate() {
+            let Some((name, addr)) = line.split_once(' ') else {
+                continue;
+            };
+            let addr = parse_number(addr).with_context(|| {
+                format!(
+                    "invalid address in symbols file: {} line: {line_nr}",
+                    path.display()
+                )
+            })? + base.0;
+            self.labels.insert(name.to_string(), addr);
+            self.labels_by_addr.insert(addr, name.to_string());
+            count += 1;
+        }
+        info!("loaded {count} labels from: {}", path.display());
+        Ok(count)
+    }
+    pub fn add_breakpoint(&mut self, addr: B32) {
+        self.breakpoints.insert(addr.0);
+    }
+    pub fn remove_breakpoint(&mut self, addr: B32) -> bool {
+        self.breakpoints.remove(&addr.0)
+    }
+
+    /// runs the machine until a breakpoint or watchpoint is hit, or `max_cycles` pass.
+    /// breakpoints are not checked before the first cycle, so it is possible to continue from one.
+    pub fn continue_execution(
+        &mut self,
+        machine: &mut Machine,
+        max_cycles: Option<u64>,
+    ) -> Result<StopReason> {
+        let mut cycles = 0;
+        loop {
+            if max_cycles.is_some_and(|max_cycles| cycles >= max_cycles) {
+                return Ok(StopReason::CyclesDone);
+            }
+            if cycles > 0
+                && let Some((thread, addr)) = thread_at_breakpoint(machine, &self.breakpoints)
+            {
+                return Ok(StopReason::Breakpoint { thread, addr });
+            }
+            machine.step()?;
+            cycles += 1;
+
+            let hits = machine.take_watchpoint_hits();
+            if !hits.is_empty() {
+                return Ok(StopReason::Watchpoint(hits));
+            }
+        }
+    }
+    /// runs the machine until the selected thread runs `count` instructions, or until it halts or
+    /// a watchpoint is hit. breakpoints are not checked
+    pub fn step_thread(&mut self, machine: &mut Machine, count: u64) -> Result<StopReason> {
+        step_thread(machine, self.selected_thread, count)
+    }
+    /// reads commands from `input` until `q` or end of input
+    pub fn run_repl(
+        &mut self,
+        machine: &mut Machine,
+        mut input: impl BufRead,
+        mut output: impl Write,
+    ) -> Result<()> {
+        writeln!(output, "debugger, type 'help' for list of commands")?;
+        self.print_location(machine, &mut output)?;
+        let mut line = String::new();
+        loop {
+            write!(output, "(dbg) ")?;
+            output.flush()?;
+            line.clear();
+            if input.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let Some(command) = words.first() else {
+                continue;
+            };
+            if *command == "q" {
+                return Ok(());
+            }
+            // errors are only printed, so the state after a failed instruction can still be inspected
+            if let Err(err) = self.run_command(machine, command, &words[1..], &mut output) {
+                writeln!(output, "error: {err:#}")?;
+            }
+        }
+    }
+    fn run_command(
+        &mut self,
+        machine: &mut Machine,
+        command: &str,
+        args: &[&str],
+        output: &mut impl Write,
+    ) -> Result<()> {
+        match command {
+            "help" | "h" => writeln!(output, "{HELP}")?,
+            "b" => {
+                let addr = self.parse_address(argument(args, 0)?)?;
+                self.add_breakpoint(addr);
+                writeln!(output, "breakpoint at {}", self.describe_address(addr))?;
+            }
+            "d" => {
+                let addr = self.parse_address(argument(args, 0)?)?;
+                if !self.remove_breakpoint(addr) {
+                    bail!("there is no breakpoint at {}", self.describe_address(addr));
+                }
+            }
+            "bl" => {
+                for addr in &self.breakpoints {
+                    writeln!(output, "breakpoint {}", self.describe_address(B32(*addr)))?;
+                }
+                for watchpoint in machine.watchpoints() {
+                    writeln!(
+                        output,
+                        "watchpoint {} len: {}",
+                        self.describe_address(watchpoint.start),
+                        watchpoint.len
+                    )?;
+                }
+            }
+            "w" => {
+                let watchpoint = self.parse_watchpoint(args)?;
+                machine.add_watchpoint(watchpoint);
+            }
+            "dw" => {
+                let watchpoint = self.parse_watchpoint(args)?;
+                if !machine.remove_watchpoint(watchpoint) {
+                    bail!("there is no such watchpoint");
+                }
+            }
+            "c" => {
+                let max_cycles = args.first().map(|arg| parse_number(arg)).transpose()?;
+                let reason = self.continue_execution(machine, max_cycles.map(u64::from))?;
+                self.print_stop_reason(&reason, output)?;
+                self.print_location(machine, output)?;
+            }
+            "s" => {
+                let count = args.first().map(|arg| parse_number(arg)).transpose()?;
+                let reason = self.step_thread(machine, count.unwrap_or(1) as u64)?;
+                self.print_stop_reason(&reason, output)?;
+                self.print_location(machine, output)?;
+            }
+            "t" => {
+                let index = parse_number(argument(args, 0)?)? as usize;
+                if index >= machine.threads.len() {
+                    bail!("thread index: {index} out of range");
+                }
+                self.selected_thread = index;
+                self.print_location(machine, output)?;
+            }
+            "r" => {
+                let thread = &machine.threads[self.selected_thread];
+                for row in 0..32 {
+                    for column in 0..8 {
+                        let index = row * 8 + column;
+                        write!(
+                            output,
+                            "r{index:<3} {:#010x}  ",
+                            thread.read_register(B8(index as u8)).0
+                        )?;
+                    }
+                    writeln!(output)?;
+                }
+                writeln!(
+                    output,
+                    "interrupt mask {:#010x}  dropped interrupts {}",
+                    thread.interrupt_controller.mask(),
+                    thread.interrupt_controller.dropped_interrupts()
+                )?;
+            }
+            "x" => {
+                let start = self.parse_address(argument(args, 0)?)?;
+                let len = args.get(1).map(|arg| parse_number(arg)).transpose()?;
+                for row_start in (0..len.unwrap_or(16)).step_by(4) {
+                    write!(output, "{:#010x}:", start.0.wrapping_add(row_start))?;
+                    for offset in row_start..row_start.saturating_add(4).min(len.unwrap_or(16)) {
+                        write!(
+                            output,
+                            " {:#010x}",
+                            machine.memory.read(start + B32(offset)).0
+                        )?;
+                    }
+                    writeln!(output)?;
+                }
+            }
+            "save" => machine.save_snapshot(Path::new(argument(args, 0)?))?,
+            "shot" => HeadlessFrameBuffer::new(machine.display())
+                .save_image(Path::new(argument(args, 0)?))?,
+            "trace" => match argument(args, 0)? {
+                "off" => machine.stop_trace()?,
+                path => machine.start_trace(Path::new(path))?,
+            },
+            "sym" => {
+                let base = args.get(1).map(|arg| parse_number(arg)).transpose()?;
+                let count =
+                    self.load_symbols(Path::new(argument(args, 0)?), B32(base.unwrap_or(0)))?;
+                writeln!(output, "loaded {count} labels")?;
+            }
+            command => bail!("unknown command: {command}, type 'help' for list of commands"),
+        }
+        Ok(())
+    }
+
+    fn print_stop_reason(&self, reason: &StopReason, output: &mut impl Write) -> Result<()> {
+        match reason {
+            StopReason::Breakpoint { thread, addr } => writeln!(
+                output,
+                "thread {thread} hit breakpoint at {}",
+                self.describe_address(*addr)
+            )?,
+            StopReason::Watchpoint(hits) => {
+                for hit in hits {
+                    writeln!(
+                        output,
+                        "thread {} wrote {:#010x} -> {:#010x} at {} in cycle {}",
+                        hit.thread,
+                        hit.old.0,
+                        hit.new.0,
+                        self.describe_address(hit.addr),
+                        hit.cycle
+                    )?;
+                }
+            }
+            StopReason::CyclesDone => writeln!(output, "cycles done")?,
+            StopReason::StepsDone => {}
+            StopReason::Halted { thread } => writeln!(output, "thread {thread} is halted")?,
+        }
+        Ok(())
+    }
+    /// prints pc of the selected thread and instruction that it points to
+    fn print_location(&self, machine: &Machine, output: &mut impl Write) -> Result<()> {
+        let thread = &machine.threads[self.selected_thread];
+        let pc = thread.read_register(CURRENT_ADDR_REGISTER);
+        let state = if thread.is_halting() {
+            "halted"
+        } else if thread.is_sleeping() {
+            "sleeping"
+        } else {
+            "running"
+        };
+        write!(
+            output,
+            "cycle {} thread {} ({state}) pc {}",
+            machine.cycle(),
+            self.selected_thread,
+            self.describe_address(pc)
+        )?;
+        match thread.mmu.translate(
+            &machine.memory,
+            pc,
+            crate::chips::thread::mmu::MemoryAccessKind::Execute,
+        ) {
+            Some(physical_addr) => {
+                let data = machine.memory.read(physical_addr);
+                writeln!(output, ": {:#010x} {}", data.0, disassemble(data))?;
+            }
+            None => writeln!(output, ": not mapped")?,
+        }
+        Ok(())
+    }
+
+    fn parse_watchpoint(&self, args: &[&str]) -> Result<Watchpoint> {
+        let start = self.parse_address(argument(args, 0)?)?;
+        let len = args.get(1).map(|arg| parse_number(arg)).transpose()?;
+        Ok(Watchpoint {
+            start,
+            len: len.unwrap_or(1),
+        })
+    }
+    /// number or label, label can start with ':' like in assembly
+    fn parse_address(&self, word: &str) -> Result<B32> {
+        if let Ok(number) = parse_number(word) {
+            return Ok(B32(number));
+        }
+        let name = word.strip_prefix(':').unwrap_or(word);
+        self.labels
+            .get(name)
+            .map(|addr| B32(*addr))
+            .with_context(|| format!("'{word}' is not a number or a known label"))
+    }
+    /// address with the closest label before it, eg. `0x00000010 <main+2>`
+    fn describe_address(&self, addr: B32) -> String {
+        match self.labels_by_addr.range(..=addr.0).next_back() {
+            Some((label_addr, name)) if *label_addr == addr.0 => {
+                format!("{:#010x} <{name}>", addr.0)
+            }
+            Some((label_addr, name)) => {
+                format!("{:#010x} <{name}+{}>", addr.0, addr.0 - label_addr)
+            }
+            None => format!("{:#010x}", addr.0),
+        }
+    }
+}
+
+/// runs the machine until the thread runs `count` instructions, see `Debugger::step_thread`
+pub(crate) fn step_thread(machine: &mut Machine, thread: usize, count: u64) -> Result<StopReason> {
+    let target = machine.threads[thread].instructions_run() + count;
+    while machine.threads[thread].instructions_run() < target {
+        if machine.threads[thread].is_halting() {
+            return Ok(StopReason::Halted { thread });
+        }
+        machine.step()?;
+
+        let hits = machine.take_watchpoint_hits();
+        if !hits.is_empty() {
+            return Ok(StopReason::Watchpoint(hits));
+        }
+    }
+    Ok(StopReason::StepsDone)
+}
+/// first running thread whose pc is at one of the breakpoints
+pub(crate) fn thread_at_breakpoint(
+    machine: &Machine,
+    breakpoints: &BTreeSet<u32>,
+) -> Option<(usize, B32)> {
+    if breakpoints.is_empty() {
+        return None;
+    }
+    machine
+        .threads
+        .iter()
+        .enumerate()
+        .filter(|(_, thread)| !thread.is_halting() && !thread.is_sleeping())
+        .map(|(index, thread)| (index, thread.read_register(CURRENT_ADDR_REGISTER)))
+        .find(|(_, pc)| breakpoints.contains(&pc.0))
+}
+fn argument<'a>(args: &[&'a str], index: usize) -> Result<&'a str> {
+    args.get(index)
+        .copied()
+        .with_context(|| format!("missing argument nr: {}", index + 1))
+}
+fn parse_number(word: &str) -> Result<u32> {
+    let result = if let Some(hex) = word.strip_prefix("0x") {
+        u32::from_str_radix(hex, 16)
+    } else if let Some(bin) = word.strip_prefix("0b") {
+        u32::from_str_radix(bin, 2)
+    } else {
+        word.parse::<u32>()
+    };
+    result.with_context(|| format!("'{word}' is not a valid number"))
+}
+fn disassemble(data: B32) -> String {
+    // not every word is code
+    match Instruction::try_from(data) {
+        Ok(instruction) => format!("{instruction:?}"),
+        Err(_) => "(data)".to_string(),
+    }
+}
@@ -1,7 +1,10 @@
-use anyhow::{Context, Error, Result};
+pub mod headless;
+
+use anyhow::{Context, Error, Result, bail};
 use log::info;
 use pixels::{Pixels, SurfaceTexture};
-use std::sync::OnceLock;
+use std::path::PathBuf;
+use std::str::FromStr;
 use winit::dpi::LogicalSize;
 use winit::event::{Event, WindowEvent};
 use winit::event_loop::{ControlFlow, EventLoop};
@@ -9,36 +12,96 @@ use winit::keyboard::KeyCode;
 use winit::window::{self, Window, WindowBuilder, WindowId};
 use winit_input_helper::WinitInputHelper;
 
-use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
-
 use crate::chips::thread::InterruptKind::Keyboard;
-use crate::chips::thread::THREADS;
+use crate::machine::InterruptSender;
+use crate::peripherals::gpu::SharedDisplay;
 
-#[derive(Clone, Copy, Debug)]
-pub struct Command {
-    pub color: u32,
-    pub pos: u32,
+/// size of the display in pixels
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Resolution {
+    pub width: u32,
+    pub height: u32,
 }
+impl Resolution {
+    /// smallest one still fits a cell of the console
+    pub const MIN: u32 = 8;
+    pub const MAX: u32 = 4096;
 
-pub async fn push_new_command(command: Command) -> Result<()> {
-    COMMAND_SENDER.get().context("")?.send(command)?;
-    //info!("push command-  {command:?}");
-    Ok(())
+    pub fn pixel_count(&self) -> u32 {
+        self.width * self.height
+    }
+    /// size of rgba frame
+    pub fn frame_bytes(&self) -> usize {
+        self.pixel_count() as usize * 4
+    }
+    pub fn validate(&self) -> Result<()> {
+        let range = Self::MIN..=Self::MAX;
+        if !range.contains(&self.width) || !range.contains(&self.height) {
+            bail!(
+                "resolution: {self} has to be between {0}x{0} and {1}x{1}",
+                Self::MIN,
+                Self::MAX
+            );
+        }
+        Ok(())
+    }
 }
+impl Default for Resolution {
+    fn default() -> Self {
+        Self {
+            width: 300,
+            height: 100,
+        }
+    }
+}
+impl std::fmt::Display for Resolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+/// `WIDTHxHEIGHT`, eg. `300x100`
+impl FromStr for Resolution {
+    type Err = Error;
 
-// could be more efficient by giving one copy to all threads, TODO
-static COMMAND_SENDER: OnceLock<UnboundedSender<Command>> = OnceLock::new();
-const WINDOW_WIDTH: u32 = 300;
-const WINDOW_HEIGHT: u32 = 100;
+    fn from_str(text: &str) -> Result<Self> {
+        let Some((width, height)) = text.split_once('x') else {
+            bail!("resolution: '{text}' should look like WIDTHxHEIGHT");
+        };
+        let resolution = Self {
+            width: width
+                .parse()
+                .with_context(|| format!("width of resolution: '{text}'"))?,
+            height: height
+                .parse()
+                .with_context(|| format!("height of resolution: '{text}'"))?,
+        };
+        resolution.validate()?;
+        Ok(resolution)
+    }
+}
+
+/// where the display is shown
+#[derive(Clone, Debug)]
+pub enum FrameBufferMode {
+    Window,
+    /// no window, frame is kept in memory and saved to `screenshot` (.png or .ppm) when the
+    /// machine stops. The debugger's `shot` command saves it at any time.
+    Headless {
+        screenshot: Option<PathBuf>,
+    },
+}
 
-pub(crate) fn run() -> Result<()> {
+/// shows frames presented by the gpu in a window
+pub(crate) fn run(display: SharedDisplay, interrupt_sender: InterruptSender) -> Result<()> {
     let event_loop = EventLoop::new().unwrap();
     let mut input = WinitInputHelper::new();
 
     let keyboard_interrupt_thread = 0;
+    let resolution = display.lock().unwrap().resolution();
+    let (width, height) = (resolution.width as f64, resolution.height as f64);
     let window = {
-        let size = LogicalSize::new(WINDOW_WIDTH as f64, WINDOW_HEIGHT as f64);
-        let scaled_size = LogicalSize::new(WINDOW_WIDTH as f64 * 3.0, WINDOW_HEIGHT as f64 * 3.0);
+        let size = LogicalSize::new(width, height);
+        let scaled_size = LogicalSize::new(width * 3.0, height * 3.0);
         WindowBuilder::new()
             .with_title("Trash can os")
             .with_inner_size(scaled_size)
@@ -50,16 +113,22 @@ pub(crate) fn run() -> Result<()> {
     let mut pixels = {
         let window_size = window.inner_size();
         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
-        Pixels::new(WINDOW_WIDTH, WINDOW_HEIGHT, surface_texture)?
+        Pixels::new(resolution.width, resolution.height, surface_texture)?
     };
 
     let mut draw_state: Option<bool> = None;
 
-    let (sender, mut receiver) = mpsc::unbounded_channel::<Command>();
-    COMMAND_SENDER.get_or_init(|| sender);
-
     let res = event_loop.run(|event, elwt| {
-        event_loop_function(event, elwt, &mut pixels, &mut input, &window, &mut receiver)
+        event_loop_function(
+            event,
+            elwt,
+            &mut pixels,
+            &mut input,
+            &window,
+            &display,
+            &interrupt_sender,
+            keyboard_interrupt_thread,
+        )
     });
     res.context("frame_buffer")
 }
@@ -70,7 +139,9 @@ fn event_loop_function(
     pixels: &mut Pixels<'_>,
     input: &mut WinitInputHelper,
     window: &Window,
-    recever: &mut UnboundedReceiver<Command>,
+    display: &SharedDisplay,
+    interrupt_sender: &InterruptSender,
+    keyboard_interrupt_thread: usize,
 ) {
     // The one and only event that winit_input_helper doesn't have for us...
     if let Event::WindowEvent { event, .. } = &event {
@@ -90,10 +161,16 @@ fn event_loop_function(
                             KeyCode::Undo
                         }
                     };
-                    THREADS.get().unwrap()[0].interrupt(crate::chips::thread::Interrupt {
-                        kind: Keyboard,
-                        data: keycode_to_u32(&keycode),
-                    });
+                    interrupt_sender
+                        .send(
+                            keyboard_interrupt_thread,
+                            crate::chips::thread::Interrupt {
+                                kind: Keyboard,
+                                data: keycode_to_u32(&keycode),
+                            },
+                        )
+                        .context("keyboard interrupt")
+                        .unwrap();
                 }
             }
             _ => {}
@@ -120,14 +197,9 @@ fn event_loop_function(
         //
         //
 
-        let frame = pixels.frame_mut();
-
-        while let Ok(command) = recever.try_recv() {
-            let color = command.color;
-            let pos = command.pos as usize;
-
-            frame[pos..pos + 4].copy_from_slice(&color.to_le_bytes()[0..4]);
-        }
+        pixels
+            .frame_mut()
+            .copy_from_slice(display.lock().unwrap().frame());
 
         // Resize the window
         if let Some(size) = input.window_resized() {
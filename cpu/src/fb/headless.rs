@@ -0,0 +1,153 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use anyhow::{Context, Result, bail};
+use log::{error, info};
+
+use super::Resolution;
+use crate::{Controller, machine::Machine, peripherals::gpu::SharedDisplay};
+
+/// how often the frame is copied from the display and ctrl-c is checked
+const UPDATE_CYCLES: u64 = 10_000;
+
+/// runs the machine without a window until the clock reaches `cycles` or ctrl-c is pressed, then
+/// saves the frame to `screenshot` and state of the machine to `snapshot`. Both are saved even
+/// if the machine stopped with an error.
+/// with a controller (debugger, gdb) the machine is driven by it instead and `cycles` is ignored.
+pub async fn run(
+    mut machine: Machine,
+    cycles: Option<u64>,
+    screenshot: Option<PathBuf>,
+    controller: Option<Controller>,
+    snapshot: Option<PathBuf>,
+) -> Result<()> {
+    let mut frame_buffer = HeadlessFrameBuffer::new(machine.display());
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_on_ctrl_c = stop.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            stop_on_ctrl_c.store(true, Ordering::Relaxed);
+        }
+    });
+
+    let (mut machine, mut frame_buffer, result) = tokio::task::spawn_blocking(move || {
+        let result = match controller {
+            Some(controller) => controller.run(&mut machine),
+            None => run_loop(&mut machine, &mut frame_buffer, cycles, &stop),
+        };
+        (machine, frame_buffer, result)
+    })
+    .await?;
+
+    // everything is saved before any error is returned, error of the run goes first
+    let results = [
+        result.context("encountered error while running CPU:"),
+        screenshot.map_or(Ok(()), |path| frame_buffer.save_image(&path)),
+        machine.stop_trace(),
+        snapshot.map_or(Ok(()), |path| machine.save_snapshot(&path)),
+    ];
+    let mut errors = results.into_iter().filter_map(Result::err);
+    let Some(first_error) = errors.next() else {
+        return Ok(());
+    };
+    for err in errors {
+        error!("{err:?}");
+    }
+    Err(first_error)
+}
+fn run_loop(
+    machine: &mut Machine,
+    frame_buffer: &mut HeadlessFrameBuffer,
+    cycles: Option<u64>,
+    stop: &AtomicBool,
+) -> Result<()> {
+    info!("run machine headless");
+    while !stop.load(Ordering::Relaxed) {
+        let cycles_to_run = match cycles {
+            Some(cycles) => cycles.saturating_sub(machine.cycle()).min(UPDATE_CYCLES),
+            None => UPDATE_CYCLES,
+        };
+        if cycles_to_run == 0 {
+            break;
+        }
+        machine.run_cycles(cycles_to_run)?;
+        frame_buffer.update()?;
+    }
+    info!("machine stopped after {} cycles", machine.cycle());
+    Ok(())
+}
+
+/// frame buffer without a window, pixels are only kept in memory.
+/// useful for running programs on machines without a display (CI).
+pub struct HeadlessFrameBuffer {
+    /// rgba, same layout as frame of the window
+    frame: Vec<u8>,
+    resolution: Resolution,
+    display: SharedDisplay,
+}
+impl HeadlessFrameBuffer {
+    pub fn new(display: SharedDisplay) -> Self {
+        let resolution = display.lock().unwrap().resolution();
+        Self {
+            frame: vec![0; resolution.frame_bytes()],
+            resolution,
+            display,
+        }
+    }
+    /// copies the frame that the display shows now
+    pub fn update(&mut self) -> Result<()> {
+        self.frame
+            .copy_from_slice(self.display.lock().unwrap().frame());
+        Ok(())
+    }
+    pub fn frame(&self) -> &[u8] {
+        &self.frame
+    }
+
+    /// updates and saves the frame. format is picked from the extension of the
+    /// file: `.png` or `.ppm`.
+    pub fn save_image(&mut self, path: &Path) -> Result<()> {
+        self.update()?;
+        let file = File::create(path)
+            .with_context(|| format!("could not create image file: {}", path.display()))?;
+        let writer = BufWriter::new(file);
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("png") => self.write_png(writer)?,
+            Some("ppm") => self.write_ppm(writer)?,
+            _ => bail!(
+                "unknown image format of file: {}, use .png or .ppm",
+                path.display()
+            ),
+        }
+        info!("saved frame buffer to: {}", path.display());
+        Ok(())
+    }
+
+    fn write_png(&self, writer: impl Write) -> Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.resolution.width, self.resolution.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.frame)?;
+        writer.finish()?;
+        Ok(())
+    }
+    /// binary ppm, alpha is dropped
+    fn write_ppm(&self, mut writer: impl Write) -> Result<()> {
+        let Resolution { width, height } = self.resolution;
+        write!(writer, "P6\n{width} {height}\n255\n")?;
+        for pixel in self.frame.chunks_exact(4) {
+            writer.write_all(&pixel[0..3])?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,562 @@
+use std::{
+    collections::BTreeSet,
+    io::{ErrorKind, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+};
+
+use anyhow::{Context, Result, bail};
+use log::{error, info, warn};
+
+use crate::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{
+            CURRENT_ADDR_REGISTER, STACK_FRAME_REGISTER, STACK_HEAD_REGISTER, Thread,
+            mmu::MemoryAccessKind,
+        },
+    },
+    debugger::{StopReason, step_thread, thread_at_breakpoint},
+    machine::{Machine, Watchpoint, WatchpointHit},
+};
+
+const REGISTER_COUNT: usize = 256;
+/// biggest packet gdb can send or receive, told to it in qSupported
+const PACKET_SIZE: usize = 0x4000;
+/// bytes that fit hex encoded into a packet with its framing
+const MAX_MEMORY_READ: u32 = (PACKET_SIZE as u32 - 4) / 2;
+/// how often a running machine checks if gdb sent ctrl-c
+const INTERRUPT_CHECK_CYCLES: u64 = 10_000;
+const SIGINT: u8 = 2;
+const SIGILL: u8 = 4;
+const SIGTRAP: u8 = 5;
+
+/// GDB remote serial protocol server. Every `Thread` is a gdb thread with id = index + 1.
+///
+/// gdb addresses bytes, memory of the machine addresses 32 bit words, so gdb address is
+/// word address * 4 and words are little endian. pc, sp and fp are shown to gdb the same way, so
+/// `$pc` matches breakpoints and memory, other registers are shown as they are.
+/// Memory packets use virtual addresses of the current thread, like its pc, watchpoints use
+/// physical ones. Breakpoints are kept by the server, memory is not patched. Single step runs
+/// the machine until the current thread runs one instruction, other threads run alongside it.
+pub struct GdbServer {
+    listener: TcpListener,
+    /// word addresses
+    breakpoints: BTreeSet<u32>,
+    /// thread used for register access and reported in stop replies
+    current_thread: usize,
+}
+impl GdbServer {
+    pub fn bind(addr: impl ToSocketAddrs) -> Result<Self> {
+        let listener = TcpListener::bind(addr).context("could not bind gdb server")?;
+        Ok(Self {
+            listener,
+            breakpoints: BTreeSet::new(),
+            current_thread: 0,
+        })
+    }
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// waits for gdb to connect and serves it until it detaches or kills the machine.
+    pub fn run(&mut self, machine: &mut Machine) -> Result<()> {
+        info!("waiting for gdb on: {}", self.local_addr()?);
+        let (stream, addr) = self.listener.accept()?;
+        info!("gdb connected from: {addr}");
+        let mut connection = Connection::new(stream);
+
+        loop {
+            let Some(packet) = connection.read_packet()? else {
+                info!("gdb disconnected");
+                return Ok(());
+            };
+            match self.handle_packet(&packet, machine, &mut connection) {
+                Ok(Some(reply)) => connection.write_packet(&reply)?,
+                // malformed packet shouldn't end the session
+                Err(err) => {
+                    warn!("{err:?}");
+                    connection.write_packet("E01")?;
+                }
+                Ok(None) => {
+                    // gdb doesn't wait for reply to 'k' and could be already gone
+                    if let Err(err) = connection.write_packet("OK") {
+                        warn!("{err:?}");
+                    }
+                    info!("gdb session ended");
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// returns reply, None ends the session
+    fn handle_packet(
+        &mut self,
+        packet: &[u8],
+        machine: &mut Machine,
+        connection: &mut Connection,
+    ) -> Result<Option<String>> {
+        let packet = String::from_utf8_lossy(packet);
+        let Some(command) = packet.chars().next() else {
+            return Ok(Some(String::new()));
+        };
+        let args = &packet[command.len_utf8()..];
+        let reply = match command {
+            '?' => self.stop_reply(SIGTRAP, ""),
+            'g' => {
+                let thread = &machine.threads[self.current_thread];
+                (0..REGISTER_COUNT)
+                    .map(|index| encode_word(read_register(thread, index)))
+                    .collect()
+            }
+            'G' => {
+                let thread = &machine.threads[self.current_thread];
+                let bytes = decode_hex(args)?;
+                for (index, word) in bytes.chunks_exact(4).take(REGISTER_COUNT).enumerate() {
+                    write_register(thread, index, decode_word(word));
+                }
+                "OK".to_string()
+            }
+            'p' => {
+                let index = parse_hex(args)? as usize;
+                if index >= REGISTER_COUNT {
+                    "E01".to_string()
+                } else {
+                    encode_word(read_register(&machine.threads[self.current_thread], index))
+                }
+            }
+            'P' => {
+                let (index, value) = args.split_once('=').context("invalid P packet")?;
+                let index = parse_hex(index)? as usize;
+                if index >= REGISTER_COUNT {
+                    "E01".to_string()
+                } else {
+                    write_register(
+                        &machine.threads[self.current_thread],
+                        index,
+                        decode_word(&decode_hex(value)?),
+                    );
+                    "OK".to_string()
+                }
+            }
+            'm' => {
+                let (addr, len) = args.split_once(',').context("invalid m packet")?;
+                let (addr, len) = (parse_hex(addr)?, parse_hex(len)?.min(MAX_MEMORY_READ));
+                let thread = &machine.threads[self.current_thread];
+                // reply stops at the first byte that isn't mapped
+                let bytes: Vec<u8> = (addr..addr.saturating_add(len))
+                    .map_while(|addr| read_byte(machine, thread, addr))
+                    .collect();
+                if bytes.is_empty() && len > 0 {
+                    "E01".to_string()
+                } else {
+                    encode_hex(&bytes)
+                }
+            }
+            'M' => {
+                let (location, data) = args.split_once(':').context("invalid M packet")?;
+                let (addr, _) = location.split_once(',').context("invalid M packet")?;
+                let addr = parse_hex(addr)?;
+                let thread = &machine.threads[self.current_thread];
+                let bytes = decode_hex(data)?;
+                let addrs: Option<Vec<(B32, usize)>> = (0..bytes.len() as u32)
+                    .map(|offset| byte_addr(machine, thread, addr.wrapping_add(offset)))
+                    .collect();
+                match addrs {
+                    Some(addrs) => {
+                        for (addr, byte) in addrs.into_iter().zip(bytes) {
+                            write_byte(machine, addr, byte);
+                        }
+                        "OK".to_string()
+                    }
+                    None => "E01".to_string(),
+                }
+            }
+            'Z' | 'z' => self.handle_breakpoint_packet(command == 'Z', args, machine)?,
+            'H' => {
+                // 'Hg' and 'Hc' select thread for following commands, 0 and -1 mean any
+                let thread_id = args.get(1..).unwrap_or("0");
+                if let Some(index) = thread_index(thread_id, machine) {
+                    self.current_thread = index;
+                }
+                "OK".to_string()
+            }
+            'T' => match thread_index(args, machine) {
+                Some(_) => "OK".to_string(),
+                None => "E01".to_string(),
+            },
+            'c' => self.resume(machine, connection, false)?,
+            's' => self.resume(machine, connection, true)?,
+            'v' => self.handle_v_packet(args, machine, connection)?,
+            'q' => self.handle_query(args, machine),
+            'D' => return Ok(None),
+            'k' => {
+                info!("gdb killed the machine");
+                return Ok(None);
+            }
+            _ => String::new(),
+        };
+        Ok(Some(reply))
+    }
+
+    fn handle_query(&self, query: &str, machine: &Machine) -> String {
+        if query.starts_with("Supported") {
+            format!(
+                "PacketSize={PACKET_SIZE:x};qXfer:features:read+;swbreak+;hwbreak+;vContSupported+"
+            )
+        } else if query == "Attached" {
+            "1".to_string()
+        } else if query == "C" {
+            format!("QC{:x}", self.current_thread + 1)
+        } else if query == "fThreadInfo" {
+            let ids: Vec<String> = (1..=machine.threads.len())
+                .map(|id| format!("{id:x}"))
+                .collect();
+            format!("m{}", ids.join(","))
+        } else if query == "sThreadInfo" {
+            "l".to_string()
+        } else if let Some(thread_id) = query.strip_prefix("ThreadExtraInfo,") {
+            let state = match thread_index(thread_id, machine).map(|index| &machine.threads[index])
+            {
+                Some(thread) if thread.is_halting() => "halted",
+                Some(thread) if thread.is_sleeping() => "sleeping",
+                Some(_) => "running",
+                None => "unknown",
+            };
+            encode_hex(state.as_bytes())
+        } else if let Some(range) = query.strip_prefix("Xfer:features:read:target.xml:") {
+            read_chunk(&target_xml(), range)
+        } else {
+            String::new()
+        }
+    }
+    fn handle_v_packet(
+        &mut self,
+        packet: &str,
+        machine: &mut Machine,
+        connection: &mut Connection,
+    ) -> Result<String> {
+        if packet == "Cont?" {
+            return Ok("vCont;c;C;s;S".to_string());
+        }
+        let Some(actions) = packet.strip_prefix("Cont;") else {
+            return Ok(String::new());
+        };
+        // only the first stepping thread is stepped, the others run alongside it
+        for action in actions.split(';') {
+            let (action, thread_id) = action.split_once(':').unwrap_or((action, "-1"));
+            if action.starts_with('s') || action.starts_with('S') {
+                if let Some(index) = thread_index(thread_id, machine) {
+                    self.current_thread = index;
+                }
+                return self.resume(machine, connection, true);
+            }
+        }
+        self.resume(machine, connection, false)
+    }
+    fn handle_breakpoint_packet(
+        &mut self,
+        insert: bool,
+        args: &str,
+        machine: &mut Machine,
+    ) -> Result<String> {
+        let mut parts = args.split(',');
+        let kind = parts.next().context("invalid breakpoint packet")?;
+        let addr = parse_hex(parts.next().context("invalid breakpoint packet")?)?;
+        let len = parts.next().map(parse_hex).transpose()?.unwrap_or(4);
+        match kind {
+            // software and hardware breakpoints work the same
+            "0" | "1" => {
+                if insert {
+                    self.breakpoints.insert(addr / 4);
+                } else {
+                    self.breakpoints.remove(&(addr / 4));
+                }
+            }
+            // write watchpoint
+            "2" => {
+                let watchpoint = Watchpoint {
+                    start: B32(addr / 4),
+                    len: len.div_ceil(4).max(1),
+                };
+                if insert {
+                    machine.add_watchpoint(watchpoint);
+                } else {
+                    machine.remove_watchpoint(watchpoint);
+                }
+            }
+            _ => return Ok(String::new()),
+        }
+        Ok("OK".to_string())
+    }
+
+    /// runs the machine until breakpoint, watchpoint, ctrl-c or error, or until the current thread
+    /// runs one instruction when stepping. returns stop reply
+    fn resume(
+        &mut self,
+        machine: &mut Machine,
+        connection: &mut Connection,
+        step: bool,
+    ) -> Result<String> {
+        if step {
+            return Ok(match step_thread(machine, self.current_thread, 1) {
+                Ok(StopReason::Watchpoint(hits)) => self.watchpoint_reply(machine, &hits[0]),
+                Ok(_) => self.stop_reply(SIGTRAP, ""),
+                Err(err) => {
+                    error!("{err:?}");
+                    self.stop_reply(SIGILL, "")
+                }
+            });
+        }
+        let mut cycles = 0;
+        loop {
+            if cycles > 0 {
+                if let Some((thread, _)) = thread_at_breakpoint(machine, &self.breakpoints) {
+                    self.current_thread = thread;
+                    return Ok(self.stop_reply(SIGTRAP, "swbreak:;"));
+                }
+                if cycles % INTERRUPT_CHECK_CYCLES == 0 && connection.interrupt_requested()? {
+                    return Ok(self.stop_reply(SIGINT, ""));
+                }
+            }
+            if let Err(err) = machine.step() {
+                // machine state stays as it was when the instruction failed, so gdb can inspect it
+                error!("{err:?}");
+                return Ok(self.stop_reply(SIGILL, ""));
+            }
+            cycles += 1;
+
+            if let Some(hit) = machine.take_watchpoint_hits().first() {
+                return Ok(self.watchpoint_reply(machine, hit));
+            }
+        }
+    }
+    fn watchpoint_reply(&mut self, machine: &Machine, hit: &WatchpointHit) -> String {
+        if hit.thread < machine.threads.len() {
+            self.current_thread = hit.thread;
+        }
+        self.stop_reply(SIGTRAP, &format!("watch:{:x};", hit.addr.0 * 4))
+    }
+    fn stop_reply(&self, signal: u8, reason: &str) -> String {
+        format!("T{signal:02x}{reason}thread:{:x};", self.current_thread + 1)
+    }
+}
+
+struct Connection {
+    stream: TcpStream,
+}
+impl Connection {
+    fn new(stream: TcpStream) -> Self {
+        Self { stream }
+    }
+    fn read_byte(&mut self) -> Result<Option<u8>> {
+        let mut byte = [0];
+        match self.stream.read(&mut byte)? {
+            0 => Ok(None),
+            _ => Ok(Some(byte[0])),
+        }
+    }
+    /// returns packet data without framing, None when the connection was closed
+    fn read_packet(&mut self) -> Result<Option<Vec<u8>>> {
+        loop {
+            // skip acks and ctrl-c that came while the machine was already stopped
+            match self.read_byte()? {
+                None => return Ok(None),
+                Some(b'$') => break,
+                Some(_) => continue,
+            }
+        }
+        let mut data = Vec::new();
+        loop {
+            match self.read_byte()? {
+                None => return Ok(None),
+                Some(b'#') => break,
+                Some(byte) => data.push(byte),
+            }
+        }
+        let mut checksum = [0; 2];
+        self.stream.read_exact(&mut checksum)?;
+        let expected = u8::from_str_radix(std::str::from_utf8(&checksum)?, 16)?;
+        if expected != checksum_of(&data) {
+            warn!("gdb packet with invalid checksum");
+            self.stream.write_all(b"-")?;
+            return self.read_packet();
+        }
+        self.stream.write_all(b"+")?;
+        Ok(Some(unescape(data)))
+    }
+    fn write_packet(&mut self, data: &str) -> Result<()> {
+        let data = escape(data.as_bytes());
+        let mut packet = Vec::with_capacity(data.len() + 4);
+        packet.push(b'$');
+        packet.extend_from_slice(&data);
+        packet.extend_from_slice(format!("#{:02x}", checksum_of(&data)).as_bytes());
+        self.stream.write_all(&packet)?;
+        self.stream.flush()?;
+        Ok(())
+    }
+    /// checks without blocking if gdb sent ctrl-c (0x03)
+    fn interrupt_requested(&mut self) -> Result<bool> {
+        self.stream.set_nonblocking(true)?;
+        let mut byte = [0];
+        let result = match self.stream.peek(&mut byte) {
+            Ok(0) => bail!("gdb disconnected while machine was running"),
+            Ok(_) if byte[0] == 0x03 => {
+                self.stream.read_exact(&mut byte)?;
+                Ok(true)
+            }
+            Ok(_) => Ok(false),
+            Err(err) if err.kind() == ErrorKind::WouldBlock => Ok(false),
+            Err(err) => Err(err.into()),
+        };
+        self.stream.set_nonblocking(false)?;
+        result
+    }
+}
+
+fn checksum_of(data: &[u8]) -> u8 {
+    data.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))
+}
+fn escape(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    for byte in data {
+        match byte {
+            b'#' | b'$' | b'}' | b'*' => out.extend_from_slice(&[b'}', byte ^ 0x20]),
+            _ => out.push(*byte),
+        }
+    }
+    out
+}
+fn unescape(data: Vec<u8>) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut bytes = data.into_iter();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'}' => out.extend(bytes.next().map(|byte| byte ^ 0x20)),
+            _ => out.push(byte),
+        }
+    }
+    out
+}
+
+fn parse_hex(text: &str) -> Result<u32> {
+    u32::from_str_radix(text, 16).with_context(|| format!("'{text}' is not a valid hex number"))
+}
+fn decode_hex(text: &str) -> Result<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        bail!("hex data has odd length");
+    }
+    // text comes from gdb, so it can have non ascii characters that can't be sliced at any byte
+    (0..text.len())
+        .step_by(2)
+        .map(|i| {
+            text.get(i..i + 2)
+                .filter(|byte| byte.bytes().all(|digit| digit.is_ascii_hexdigit()))
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .context("invalid hex data")
+        })
+        .collect()
+}
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+fn encode_word(word: B32) -> String {
+    encode_hex(&word.0.to_le_bytes())
+}
+fn decode_word(bytes: &[u8]) -> B32 {
+    let mut word = [0; 4];
+    for (i, byte) in bytes.iter().take(4).enumerate() {
+        word[i] = *byte;
+    }
+    B32(u32::from_le_bytes(word))
+}
+
+/// registers that hold word addresses, gdb gets them as byte addresses
+fn is_address_register(index: usize) -> bool {
+    [
+        CURRENT_ADDR_REGISTER,
+        STACK_HEAD_REGISTER,
+        STACK_FRAME_REGISTER,
+    ]
+    .iter()
+    .any(|register| register.0 as usize == index)
+}
+fn read_register(thread: &Thread, index: usize) -> B32 {
+    let value = thread.read_register(B8(index as u8));
+    if is_address_register(index) {
+        B32(value.0.wrapping_mul(4))
+    } else {
+        value
+    }
+}
+fn write_register(thread: &Thread, index: usize, value: B32) {
+    let value = if is_address_register(index) {
+        B32(value.0 / 4)
+    } else {
+        value
+    };
+    thread.write_register(B8(index as u8), value);
+}
+
+/// physical word and index of the byte in it for gdb address, translated by the thread's MMU.
+/// None if the page isn't mapped. Writes only need a present page, gdb can patch read only memory
+fn byte_addr(machine: &Machine, thread: &Thread, addr: u32) -> Option<(B32, usize)> {
+    let word = thread
+        .mmu
+        .translate(&machine.memory, B32(addr / 4), MemoryAccessKind::Read)?;
+    Some((word, (addr % 4) as usize))
+}
+fn read_byte(machine: &Machine, thread: &Thread, addr: u32) -> Option<u8> {
+    let (word, index) = byte_addr(machine, thread, addr)?;
+    Some(machine.memory.read(word).0.to_le_bytes()[index])
+}
+fn write_byte(machine: &Machine, (addr, index): (B32, usize), byte: u8) {
+    let mut word = machine.memory.read(addr).0.to_le_bytes();
+    word[index] = byte;
+    machine
+        .memory
+        .write(B32(u32::from_le_bytes(word)), addr, true);
+}
+
+/// gdb thread id (hex, 1 based) into index, None for 'any' (0, -1) or not existing thread
+fn thread_index(thread_id: &str, machine: &Machine) -> Option<usize> {
+    let id = usize::from_str_radix(thread_id, 16).ok()?;
+    (id >= 1 && id <= machine.threads.len()).then(|| id - 1)
+}
+
+fn target_xml() -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\"?><!DOCTYPE target SYSTEM \"gdb-target.dtd\"><target version=\"1.0\"><feature name=\"org.trashcan.cpu\">",
+    );
+    for index in 0..REGISTER_COUNT {
+        // other registers can hold anything, so they are not marked as pointers
+        let (name, reg_type) = if index == CURRENT_ADDR_REGISTER.0 as usize {
+            ("pc".to_string(), "code_ptr")
+        } else if index == STACK_HEAD_REGISTER.0 as usize {
+            ("sp".to_string(), "data_ptr")
+        } else if index == STACK_FRAME_REGISTER.0 as usize {
+            ("fp".to_string(), "data_ptr")
+        } else {
+            (format!("r{index}"), "uint32")
+        };
+        xml.push_str(&format!(
+            "<reg name=\"{name}\" bitsize=\"32\" type=\"{reg_type}\" regnum=\"{index}\"/>"
+        ));
+    }
+    xml.push_str("</feature></target>");
+    xml
+}
+/// reply to qXfer read of `offset,length`
+fn read_chunk(data: &str, range: &str) -> String {
+    let Some((offset, length)) = range.split_once(',') else {
+        return "E01".to_string();
+    };
+    let (Ok(offset), Ok(length)) = (parse_hex(offset), parse_hex(length)) else {
+        return "E01".to_string();
+    };
+    let offset = (offset as usize).min(data.len());
+    let end = (offset + length as usize).min(data.len());
+    let prefix = if end == data.len() { 'l' } else { 'm' };
+    format!("{prefix}{}", &data[offset..end])
+}
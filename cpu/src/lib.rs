@@ -1,5 +1,6 @@
 #![allow(warnings)]
 use std::{
+    path::{Path, PathBuf},
     sync::{OnceLock, atomic::AtomicBool},
     time::{Duration, Instant},
 };
@@ -8,70 +9,315 @@ use chips::{
     b8::B8,
     b32::B32,
     bit::{mux_8, nand},
-    memory::RAM::{ram8::RAM8, ram32k::RAM32k, ram256k::RAM256k, ram512::RAM512},
-    thread::{self, THREADS, clock_cycle, instructions::Instruction},
+    memory::RAM::{ram8::RAM8, ram32k::RAM32k, ram512::RAM512},
+    thread::{self, instructions::Instruction},
+};
+use debugger::Debugger;
+use gdb::GdbServer;
+use machine::Machine;
+use memory_map::{Layout, Memory, MemoryMap, Region, RegionKind};
+use peripherals::{
+    EXTRA_SERIAL_INDEX, Peripherals,
+    serial::{Serial, SerialBackend},
 };
 
 pub mod chips;
+pub mod debugger;
 mod error;
 pub mod fb;
-pub(crate) mod peripherals;
+pub mod gdb;
+pub mod machine;
+pub mod memory_map;
+pub mod mmio;
+pub mod peripherals;
 pub mod program_loader;
+pub mod rng;
+pub mod snapshot;
+pub mod trace;
 
-use anyhow::{Context, Ok, Result};
-use clap::Parser;
+use anyhow::{Context, Ok, Result, bail};
+use fb::{FrameBufferMode, Resolution};
 use log::*;
-use tokio::task;
 
 pub const SHOW_INSTRUCTION_FETCHING_DEBUG: bool = false;
-#[derive(clap::Parser)]
-struct Args {
+#[derive(clap::Args, Clone, Debug)]
+pub struct Args {
     /// Number of threads to use
     #[arg(short, long, default_value_t = 4)]
-    threads: usize,
+    pub threads: usize,
+    /// Stop the machine when its virtual clock reaches this cycle, runs forever if not set
+    #[arg(long)]
+    pub cycles: Option<u64>,
+    /// Boot from this snapshot instead of loading the programs
+    #[arg(long)]
+    pub snapshot: Option<PathBuf>,
+    /// Save a snapshot of the machine to this file when it stops
+    #[arg(long)]
+    pub save_snapshot: Option<PathBuf>,
+    /// Write binary trace of every executed instruction to this file
+    #[arg(long)]
+    pub trace: Option<PathBuf>,
+    /// Start the debugger REPL on stdin instead of running the machine
+    #[arg(long, conflicts_with = "gdb")]
+    pub debug: bool,
+    /// Wait for gdb on this localhost tcp port and let it control the machine
+    #[arg(long)]
+    pub gdb: Option<u16>,
+    /// Toml file with the memory map, see `memory_map::MemoryMap`. A snapshot brings its own memory
+    #[arg(long)]
+    pub memory_map: Option<PathBuf>,
+    /// Memory region as kind:start:size (kind is ram, rom or mmio), replaces regions of the memory
+    /// map. Can be used more than once. Without `--memory-map` devices are only mapped if their
+    /// default addresses are in a given mmio region
+    #[arg(long = "region")]
+    pub regions: Vec<Region>,
+    /// File or pipe the first serial port receives, `-` is stdin
+    #[arg(long)]
+    pub serial_input: Option<PathBuf>,
+    /// Backend of a serial port: stdout, tcp:PORT or unix:PATH. First one is for the serial the
+    /// os uses (stdout if not given), every next one adds a port named serial1, serial2, ...
+    #[arg(long = "serial")]
+    pub serials: Vec<SerialBackend>,
+    /// Size of the display as WIDTHxHEIGHT, a snapshot brings its own
+    #[arg(long, default_value_t = Resolution::default(), conflicts_with = "snapshot")]
+    pub resolution: Resolution,
+    /// Seed of the generator used by the `Rng` instruction, a snapshot brings its own
+    #[arg(long, default_value_t = rng::DEFAULT_SEED, conflicts_with = "snapshot")]
+    pub seed: u64,
+}
+
+/// drives the machine instead of letting it run freely
+pub enum Controller {
+    Debugger(Debugger),
+    Gdb(GdbServer),
+}
+impl Controller {
+    pub fn run(self, machine: &mut Machine) -> Result<()> {
+        match self {
+            Controller::Debugger(mut debugger) => {
+                debugger.run_repl(machine, std::io::stdin().lock(), std::io::stdout())
+            }
+            Controller::Gdb(mut server) => server.run(machine),
+        }
+    }
 }
 
-pub async fn main(binary_file_to_load_addr: &str, command_line_file_addr: &str) -> Result<()> {
-    if let Err(err) = init(binary_file_to_load_addr, command_line_file_addr)
+pub async fn main(
+    args: &Args,
+    frame_buffer_mode: FrameBufferMode,
+    binary_file_to_load_addr: &str,
+    command_line_file_addr: &str,
+) -> Result<()> {
+    match init(args, binary_file_to_load_addr, command_line_file_addr)
         .await
-        .context("encountered error while running CPU:")
+        .context("encountered error while initializing CPU:")
     {
-        error::handle_error(err);
+        std::result::Result::Ok(mut machine) => {
+            let controller = if args.debug {
+                Some(Controller::Debugger(create_debugger(
+                    binary_file_to_load_addr,
+                    command_line_file_addr,
+                    memory_map(args)?.layout(args.threads)?,
+                )))
+            } else if let Some(port) = args.gdb {
+                Some(Controller::Gdb(GdbServer::bind(("127.0.0.1", port))?))
+            } else {
+                None
+            };
+            match frame_buffer_mode {
+                FrameBufferMode::Window => {
+                    let display = machine.display();
+                    let interrupt_sender = machine.interrupt_sender();
+                    let cycles = args.cycles;
+                    let snapshot = args.save_snapshot.clone();
+                    std::thread::spawn(move || {
+                        let result = match (controller, cycles) {
+                            (Some(controller), _) => controller.run(&mut machine),
+                            (None, Some(cycles)) => {
+                                machine.run_cycles(cycles.saturating_sub(machine.cycle()))
+                            }
+                            (None, None) => machine.run(),
+                        };
+                        let result = result
+                            .context("encountered error while running CPU:")
+                            .and_then(|_| machine.stop_trace())
+                            .and_then(|_| match snapshot {
+                                Some(path) => machine.save_snapshot(&path),
+                                None => Ok(()),
+                            });
+                        if let Err(err) = result {
+                            error::handle_error(err);
+                        }
+                    });
+                    fb::run(display, interrupt_sender)?;
+                }
+                FrameBufferMode::Headless { screenshot } => {
+                    fb::headless::run(
+                        machine,
+                        args.cycles,
+                        screenshot,
+                        controller,
+                        args.save_snapshot.clone(),
+                    )
+                    .await?;
+                }
+            }
+        }
+        Err(err) => error::handle_error(err),
     }
-    fb::run();
 
     Ok(())
 }
 
-pub async fn init(binary_file_to_load_addr: &str, command_line_file_addr: &str) -> Result<()> {
-    let args = Args::parse();
+/// labels of both programs are loaded if the assembler wrote `.sym` files next to them
+fn create_debugger(
+    binary_file_to_load_addr: &str,
+    command_line_file_addr: &str,
+    layout: Layout,
+) -> Debugger {
+    let mut debugger = Debugger::new();
+    for (path, base) in [
+        (binary_file_to_load_addr, B32(layout.os_addr)),
+        (command_line_file_addr, B32(layout.command_line_addr)),
+    ] {
+        let path = std::path::Path::new(path).with_extension("sym");
+        if path.exists()
+            && let Err(err) = debugger.load_symbols(&path, base)
+        {
+            warn!("{err:?}");
+        }
+    }
+    debugger
+}
+
+/// memory map file with regions from the command line on top of it
+pub fn memory_map(args: &Args) -> Result<MemoryMap> {
+    let mut memory_map = match &args.memory_map {
+        Some(path) => MemoryMap::load(path)?,
+        None => MemoryMap::for_resolution(args.resolution),
+    };
+    if !args.regions.is_empty() {
+        memory_map.regions = args.regions.clone();
+        if args.memory_map.is_none() {
+            let regions = &memory_map.regions;
+            memory_map.devices.retain(|device| {
+                regions.iter().any(|region| {
+                    region.kind == RegionKind::Mmio && region.contains(B32(device.start))
+                })
+            });
+        }
+    }
+    Ok(memory_map)
+}
 
-    info!("init memory");
+/// with `args.snapshot` set the machine is restored from it and the programs are not loaded
+pub async fn init(
+    args: &Args,
+    binary_file_to_load_addr: &str,
+    command_line_file_addr: &str,
+) -> Result<Machine> {
+    let peripherals = peripherals(args)?;
+    let mut machine = match &args.snapshot {
+        Some(snapshot) => Machine::load_snapshot_with_peripherals(snapshot, peripherals)?,
+        None => {
+            load_programs(
+                args,
+                peripherals,
+                binary_file_to_load_addr,
+                command_line_file_addr,
+            )
+            .await?
+        }
+    };
+    if let Some(trace) = &args.trace {
+        machine.start_trace(trace)?;
+    }
+    if let Some(path) = &args.serial_input {
+        attach_serial_input(&mut machine, path, args.debug)?;
+    }
+    Ok(machine)
+}
+/// built in peripherals and serial ports from `args.serials`
+fn peripherals(args: &Args) -> Result<Peripherals> {
+    let mut peripherals = Peripherals::with_resolution(args.resolution);
+    for (port, backend) in args.serials.iter().enumerate() {
+        if port == 0 {
+            peripherals
+                .get_mut::<Serial>()
+                .context("machine has no serial")?
+                .connect(backend)?;
+            continue;
+        }
+        let mut serial = Serial::named(&format!("serial{port}"));
+        serial.connect(backend)?;
+        peripherals.register(EXTRA_SERIAL_INDEX + port as u32 - 1, Box::new(serial))?;
+    }
+    Ok(peripherals)
+}
+fn attach_serial_input(machine: &mut Machine, path: &Path, debug: bool) -> Result<()> {
+    let serial = machine
+        .peripheral_mut::<Serial>()
+        .context("machine has no serial")?;
+    if path == Path::new("-") {
+        if debug {
+            bail!("serial can't read stdin, the debugger uses it");
+        }
+        serial.attach_input(std::io::stdin());
+    } else {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("could not open serial input: {}", path.display()))?;
+        serial.attach_input(file);
+    }
+    Ok(())
+}
+async fn load_programs(
+    args: &Args,
+    peripherals: Peripherals,
+    binary_file_to_load_addr: &str,
+    command_line_file_addr: &str,
+) -> Result<Machine> {
+    info!("init machine");
     let start = Instant::now();
-    MEMORY.get_or_init(|| Box::new(RAM256k::new()));
+    let memory_map = memory_map(args)?;
+    let layout = memory_map.layout(args.threads)?;
+    let mut machine = Machine::with_peripherals(args.threads, &memory_map, peripherals)?;
+    machine.seed_rng(args.seed);
     let elapsed = start.elapsed();
-    info!("initialized memory: {:?}", elapsed);
+    info!("initialized machine: {:?}", elapsed);
 
-    load_memory_from_file(&binary_file_to_load_addr, B32(0)).await?;
-    load_memory_from_file(&command_line_file_addr, B32(5000)).await?;
-
-    thread::spawn_threads(args.threads);
-    {
-        let boot_thread = &thread::THREADS.get().unwrap()[0];
-        tokio::spawn(boot_thread.run_loop());
-        tokio::spawn(clock_cycle(boot_thread));
+    let os_len = load_memory_from_file(
+        &machine.memory,
+        &binary_file_to_load_addr,
+        B32(layout.os_addr),
+    )
+    .await?;
+    if os_len > layout.os_size {
+        bail!(
+            "os: '{binary_file_to_load_addr}' has {os_len} words and doesn't fit before command line at: {}",
+            layout.command_line_addr
+        );
     }
-
-    let mut iter = thread::THREADS.get().unwrap().iter();
-    iter.next();
-    for thread in iter {
-        thread.Halt(true);
-        tokio::spawn(thread.run_loop());
+    let command_line_len = load_memory_from_file(
+        &machine.memory,
+        &command_line_file_addr,
+        B32(layout.command_line_addr),
+    )
+    .await?;
+    if command_line_len > layout.heap_start - layout.command_line_addr {
+        bail!(
+            "command line: '{command_line_file_addr}' has {command_line_len} words and doesn't fit before heap at: {}",
+            layout.heap_start
+        );
     }
+    machine.threads[0].set_boot_registers(&layout);
 
-    Ok(())
+    Ok(machine)
 }
-pub async fn load_memory_from_file(path: &str, memory_load_base_addr: B32) -> Result<()> {
+pub async fn load_memory_from_file(
+    memory: &Memory,
+    path: &str,
+    memory_load_base_addr: B32,
+) -> Result<u32> {
     let buf = tokio::fs::read(path).await.with_context(|| {
         format!("encountered error while opening a file to load it's contents to a memory: {path}")
     })?;
@@ -82,20 +328,15 @@ pub async fn load_memory_from_file(path: &str, memory_load_base_addr: B32) -> Re
     for (i, chunk) in buf.chunks_exact(4).enumerate() {
         let b32 = B32(u32::from_le_bytes(chunk.try_into().unwrap()));
 
-        MEMORY
-            .get()
-            .unwrap()
-            .write(b32, B32(i as u32) + memory_load_base_addr, true);
+        memory.write(b32, B32(i as u32) + memory_load_base_addr, true);
     }
 
     info!("loaded file: '{path}' into memory!");
 
-    Ok(())
+    Ok((buf.len() / 4) as u32)
 }
-pub fn test_load_memory(data: Vec<B32>) {
+pub fn test_load_memory(memory: &Memory, data: Vec<B32>) {
     for (addr, data) in data.iter().enumerate() {
-        MEMORY.get().unwrap().write(*data, B32(addr as u32), true);
+        memory.write(*data, B32(addr as u32), true);
     }
 }
-
-pub static MEMORY: OnceLock<Box<RAM256k>> = OnceLock::new();
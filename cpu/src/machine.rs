@@ -0,0 +1,403 @@
+use std::{path::Path, sync::Mutex};
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{self, Interrupt, InterruptKind, Thread, instructions::Instruction},
+    },
+    memory_map::{Memory, MemoryMap, Region, RegionKind},
+    mmio::MmioBus,
+    peripherals::{Peripheral, Peripherals, gpu::SharedDisplay},
+    rng::{self, Rng},
+    snapshot::{SnapshotReader, SnapshotWriter},
+    trace::Tracer,
+};
+
+const ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::Relaxed;
+
+/// Runs all threads on one host thread. Every cycle each thread runs at most one instruction, in
+/// order of thread indexes. Everything time related (`Sleep`, timer) is counted in cycles of this
+/// virtual clock instead of the wall clock, so the same program always runs the same way.
+/// Machine owns all of its state, so more than one can exist in the same process.
+pub struct Machine {
+    pub memory: Memory,
+    pub(crate) mmio: MmioBus,
+    pub threads: Vec<Thread>,
+    pub(crate) peripherals: Peripherals,
+    pub(crate) rng: Rng,
+    external_interrupts: UnboundedReceiver<(usize, Interrupt)>,
+    external_interrupt_sender: UnboundedSender<(usize, Interrupt)>,
+    watchpoints: Vec<Watchpoint>,
+    watchpoint_hits: Mutex<Vec<WatchpointHit>>,
+    tracer: Option<Mutex<Tracer>>,
+    cycle: u64,
+}
+impl Machine {
+    /// only the boot thread (index 0) is running, the rest is halted until `Init` starts them.
+    /// Stacks of the threads are placed by the memory map and its devices are mapped.
+    pub fn new(thread_count: usize, memory_map: &MemoryMap) -> Result<Self> {
+        Self::with_peripherals(thread_count, memory_map, Peripherals::new())
+    }
+    /// same as `new`, but with peripherals registered before the devices of the memory map are
+    /// mapped
+    pub fn with_peripherals(
+        thread_count: usize,
+        memory_map: &MemoryMap,
+        peripherals: Peripherals,
+    ) -> Result<Self> {
+        let layout = memory_map.layout(thread_count)?;
+        let threads = thread::spawn_threads(thread_count, &layout);
+        for thread in threads.iter().skip(1) {
+            thread.Halt(true);
+        }
+        let (external_interrupt_sender, external_interrupts) = mpsc::unbounded_channel();
+
+        let mut machine = Self {
+            memory: Memory::new(&memory_map.regions)?,
+            mmio: MmioBus::new(),
+            threads,
+            peripherals,
+            rng: Rng::new(rng::DEFAULT_SEED),
+            external_interrupts,
+            external_interrupt_sender,
+            watchpoints: Vec::new(),
+            watchpoint_hits: Mutex::new(Vec::new()),
+            tracer: None,
+            cycle: 0,
+        };
+        for device in &memory_map.devices {
+            machine.map_peripheral(&device.name, device.start)?;
+        }
+        Ok(machine)
+    }
+    /// whole state of the machine, see `snapshot` module for the format.
+    /// external interrupts that were not delivered yet and watchpoints are not included.
+    pub fn snapshot(&self) -> Vec<u8> {
+        let mut writer = SnapshotWriter::new();
+        writer.u64(self.cycle);
+        writer.u64(self.rng.state());
+
+        let regions: Vec<&Region> = self.memory.regions().collect();
+        writer.u32(regions.len() as u32);
+        for region in &regions {
+            writer.u32(region.kind.into());
+            writer.u32(region.start);
+            writer.u32(region.size);
+        }
+        for region in regions
+            .iter()
+            .filter(|region| region.kind != RegionKind::Mmio)
+        {
+            for addr in region.addrs() {
+                writer.u32(self.memory.read(addr).0);
+            }
+        }
+
+        writer.u32(self.threads.len() as u32);
+        for thread in &self.threads {
+            thread.write_snapshot(&mut writer);
+        }
+        self.peripherals.write_snapshot(&mut writer);
+
+        let mappings: Vec<(&str, u32)> = self.mmio.mappings().collect();
+        writer.u32(mappings.len() as u32);
+        for (name, start) in mappings {
+            writer.bytes(name.as_bytes());
+            writer.u32(start);
+        }
+        writer.finish()
+    }
+    pub fn from_snapshot(data: &[u8]) -> Result<Self> {
+        Self::from_snapshot_with_peripherals(data, Peripherals::new())
+    }
+    /// restores state of the peripherals that are registered in `peripherals`, the rest of the
+    /// snapshot's peripherals is skipped
+    pub fn from_snapshot_with_peripherals(
+        data: &[u8],
+        mut peripherals: Peripherals,
+    ) -> Result<Self> {
+        let mut reader = SnapshotReader::new(data)?;
+        let cycle = reader.u64()?;
+        let rng = Rng::new(reader.u64()?);
+
+        let region_count = reader.u32()?;
+        let regions = (0..region_count)
+            .map(|_| {
+                Ok(Region {
+                    kind: reader.u32()?.try_into()?,
+                    start: reader.u32()?,
+                    size: reader.u32()?,
+                })
+            })
+            .collect::<Result<Vec<Region>>>()?;
+        let memory = Memory::new(&regions).context("snapshot has invalid memory regions")?;
+        for region in regions
+            .iter()
+            .filter(|region| region.kind != RegionKind::Mmio)
+        {
+            for addr in region.addrs() {
+                memory.write(B32(reader.u32()?), addr, true);
+            }
+        }
+
+        let thread_count = reader.u32()?;
+        let threads = (0..thread_count)
+            .map(|index| {
+                Thread::read_snapshot(&mut reader)
+                    .with_context(|| format!("invalid state of thread: {index}"))
+            })
+            .collect::<Result<Vec<Thread>>>()?;
+        peripherals.read_snapshot(&mut reader)?;
+        let mapping_count = reader.u32()?;
+        let mappings = (0..mapping_count)
+            .map(|_| {
+                let name = String::from_utf8(reader.bytes()?.to_vec())
+                    .context("snapshot has invalid name of mmio device")?;
+                Ok((name, reader.u32()?))
+            })
+            .collect::<Result<Vec<(String, u32)>>>()?;
+        reader.finish()?;
+
+        let (external_interrupt_sender, external_interrupts) = mpsc::unbounded_channel();
+        let mut machine = Self {
+            memory,
+            mmio: MmioBus::new(),
+            threads,
+            peripherals,
+            rng,
+            external_interrupts,
+            external_interrupt_sender,
+            watchpoints: Vec::new(),
+            watchpoint_hits: Mutex::new(Vec::new()),
+            tracer: None,
+            cycle,
+        };
+        for (name, start) in mappings {
+            if machine.peripherals.index_of(&name).is_none() {
+                warn!(
+                    "snapshot has mmio device: {name} at: {start:#x} that is not registered, it has to be mapped again"
+                );
+                continue;
+            }
+            machine.map_peripheral(&name, start)?;
+        }
+        Ok(machine)
+    }
+    /// Adds device reachable by `Phrp` with this index. Devices that are not built in are not
+    /// restored by `from_snapshot`, their state in the snapshot is skipped. Register them in
+    /// `Peripherals` given to `from_snapshot_with_peripherals` instead.
+    pub fn register_peripheral(&mut self, index: u32, device: Box<dyn Peripheral>) -> Result<()> {
+        self.peripherals.register(index, device)
+    }
+    /// `Read` and `Write` of threads in the range of the peripheral go to it. Address has to be in
+    /// a mmio region of the memory map.
+    pub fn map_peripheral(&mut self, name: &str, start: u32) -> Result<()> {
+        let index = self
+            .peripherals
+            .index_of(name)
+            .with_context(|| format!("there is no peripheral: {name}"))?;
+        let size = self.peripherals.size(index).context("peripheral index")?;
+        self.mmio.map(&self.memory, name, start, size, index)
+    }
+    /// first registered peripheral of type T, eg. `Serial` to give it input
+    pub fn peripheral_mut<T: Peripheral>(&mut self) -> Option<&mut T> {
+        self.peripherals.get_mut::<T>()
+    }
+    /// puts all peripherals back to the state they had when they were created, memory and
+    /// threads are not changed
+    pub fn reset_peripherals(&self) {
+        self.peripherals.reset();
+    }
+    /// `addr` has to be mapped, see `MmioBus::contains`
+    pub(crate) fn mmio_read(&self, thread: &Thread, addr: B32) -> Result<B32> {
+        let (index, offset) = self.mmio.route(addr).context("no mmio device")?;
+        self.peripherals
+            .read(index, self.thread_index(thread), offset)
+    }
+    pub(crate) fn mmio_write(&self, thread: &Thread, addr: B32, data: B32) -> Result<()> {
+        let (index, offset) = self.mmio.route(addr).context("no mmio device")?;
+        self.peripherals
+            .write(index, self.thread_index(thread), offset, data)
+    }
+
+    /// starts the `Rng` instruction sequence over from this seed
+    pub fn seed_rng(&mut self, seed: u64) {
+        self.rng = Rng::new(seed);
+    }
+    /// frames the programs on this machine draw into, frame buffer should show its front one
+    pub fn display(&self) -> SharedDisplay {
+        self.peripherals.display()
+    }
+    /// handle for raising interrupts from outside of the machine (for example keyboard in
+    /// window). They are delivered at the start of the next cycle.
+    pub fn interrupt_sender(&self) -> InterruptSender {
+        InterruptSender(self.external_interrupt_sender.clone())
+    }
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) {
+        self.watchpoints.push(watchpoint);
+    }
+    /// returns false if there was no such watchpoint
+    pub fn remove_watchpoint(&mut self, watchpoint: Watchpoint) -> bool {
+        let len = self.watchpoints.len();
+        self.watchpoints.retain(|other| *other != watchpoint);
+        len != self.watchpoints.len()
+    }
+    pub fn watchpoints(&self) -> &[Watchpoint] {
+        &self.watchpoints
+    }
+    /// writes that hit watchpoints since the last call
+    pub fn take_watchpoint_hits(&self) -> Vec<WatchpointHit> {
+        std::mem::take(&mut self.watchpoint_hits.lock().unwrap())
+    }
+    /// called by threads before every memory write
+    pub(crate) fn check_watchpoints(&self, thread: &Thread, addr: B32, data: B32) {
+        if !self.watchpoints.iter().any(|w| w.contains(addr)) {
+            return;
+        }
+        self.watchpoint_hits.lock().unwrap().push(WatchpointHit {
+            thread: self.thread_index(thread),
+            cycle: self.cycle,
+            addr,
+            old: self.memory.read(addr),
+            new: data,
+        });
+    }
+    /// index of the thread in this machine, usize::MAX if it belongs to other one
+    pub(crate) fn thread_index(&self, thread: &Thread) -> usize {
+        self.threads
+            .iter()
+            .position(|other| std::ptr::eq(other, thread))
+            .unwrap_or(usize::MAX)
+    }
+    /// every instruction that runs from now on is written into a binary trace, see `trace` module.
+    pub fn start_trace(&mut self, path: &Path) -> Result<()> {
+        self.stop_trace()?;
+        self.tracer = Some(Mutex::new(Tracer::create(path)?));
+        Ok(())
+    }
+    pub fn stop_trace(&mut self) -> Result<()> {
+        match self.tracer.take() {
+            Some(tracer) => tracer.into_inner().unwrap().flush(),
+            None => Ok(()),
+        }
+    }
+    /// called by threads after fetching an instruction
+    pub(crate) fn trace_instruction(
+        &self,
+        pc: B32,
+        instruction: Instruction,
+        interrupt_entry: bool,
+    ) {
+        if let Some(tracer) = &self.tracer {
+            tracer
+                .lock()
+                .unwrap()
+                .instruction(pc, instruction, interrupt_entry);
+        }
+    }
+    /// called by threads before every memory write
+    pub(crate) fn trace_memory_write(&self, addr: B32, data: B32) {
+        if let Some(tracer) = &self.tracer {
+            tracer.lock().unwrap().memory_write(addr, data);
+        }
+    }
+    /// current time of the virtual clock
+    pub fn cycle(&self) -> u64 {
+        self.cycle
+    }
+
+    pub fn step(&mut self) -> Result<()> {
+        while let Ok((thread_index, interrupt)) = self.external_interrupts.try_recv() {
+            let thread = self.threads.get(thread_index).with_context(|| {
+                format!("interrupt send to not existing thread: {thread_index}")
+            })?;
+            thread.interrupt(interrupt);
+        }
+        for (index, thread) in self.threads.iter().enumerate() {
+            let Some(tracer) = &self.tracer else {
+                thread.step(self).with_context(|| {
+                    format!("encountered error while running instruction on thread: {index}")
+                })?;
+                continue;
+            };
+            let registers_before: [B32; 256] =
+                std::array::from_fn(|register| thread.read_register(B8(register as u8)));
+            let result = thread.step(self);
+            // failed instruction is still written, it's usually the interesting one
+            tracer
+                .lock()
+                .unwrap()
+                .finish_step(self.cycle, index, thread, &registers_before)?;
+            result.with_context(|| {
+                format!("encountered error while running instruction on thread: {index}")
+            })?;
+        }
+        self.cycle += 1;
+        self.clock_cycle();
+        Ok(())
+    }
+    pub fn run_cycles(&mut self, cycles: u64) -> Result<()> {
+        for _ in 0..cycles {
+            self.step()?;
+        }
+        Ok(())
+    }
+    pub fn run(&mut self) -> Result<()> {
+        info!("run machine");
+        loop {
+            self.step()?;
+        }
+    }
+
+    /// interrupt raised by a peripheral is not queued again while the previous one of the same kind
+    /// is still pending, eg. timer handler can tell how many ticks it missed from the tick count
+    /// in the data.
+    fn clock_cycle(&mut self) {
+        let threads = &self.threads;
+        self.peripherals
+            .tick(&self.memory, |thread_index, interrupt| {
+                let Some(thread) = threads.get(thread_index) else {
+                    return;
+                };
+                if thread.interrupt_controller.is_pending(interrupt.kind) {
+                    return;
+                }
+                thread.interrupt(interrupt);
+            });
+    }
+}
+
+#[derive(Clone)]
+pub struct InterruptSender(UnboundedSender<(usize, Interrupt)>);
+impl InterruptSender {
+    pub fn send(&self, thread_index: usize, interrupt: Interrupt) -> Result<()> {
+        self.0
+            .send((thread_index, interrupt))
+            .context("machine was already dropped")
+    }
+}
+
+/// range of physical memory, writes into it are recorded
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Watchpoint {
+    pub start: B32,
+    pub len: u32,
+}
+impl Watchpoint {
+    pub fn contains(&self, addr: B32) -> bool {
+        addr.0 >= self.start.0 && addr.0 - self.start.0 < self.len
+    }
+}
+#[derive(Clone, Copy, Debug)]
+pub struct WatchpointHit {
+    pub thread: usize,
+    pub cycle: u64,
+    pub addr: B32,
+    pub old: B32,
+    pub new: B32,
+}
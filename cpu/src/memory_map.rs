@@ -0,0 +1,352 @@
+use std::{path::Path, str::FromStr};
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::Deserialize;
+
+use crate::{
+    chips::{b32::B32, memory::RAM::ram4k::RAM4k, thread::mmu::MemoryAccessKind},
+    fb::Resolution,
+    peripherals::console::Console,
+};
+
+/// regions are build from banks of this many words, so their start and size have to be multiples
+/// of it
+pub const BANK_SIZE: u32 = 4096;
+
+/// without a memory map devices are right after 256k words of ram
+pub const DEFAULT_MMIO_ADDR: u32 = 256 * 1024;
+pub const DEFAULT_SERIAL_ADDR: u32 = DEFAULT_MMIO_ADDR;
+pub const DEFAULT_MAILBOX_ADDR: u32 = DEFAULT_MMIO_ADDR + 8;
+pub const DEFAULT_GPU_ADDR: u32 = DEFAULT_MMIO_ADDR + 16;
+pub const DEFAULT_CONSOLE_ADDR: u32 = DEFAULT_MMIO_ADDR + 256;
+/// framebuffer has its own mmio region, after the one of other devices. Sizes of both regions
+/// depend on the resolution, see `MemoryMap::for_resolution`.
+pub const DEFAULT_FRAMEBUFFER_ADDR: u32 = 1024 * 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RegionKind {
+    Ram,
+    /// programs can't write into it, only the loader can
+    Rom,
+    /// reserved for devices, there is no memory behind it
+    Mmio,
+}
+impl From<RegionKind> for u32 {
+    fn from(kind: RegionKind) -> Self {
+        match kind {
+            RegionKind::Ram => 0,
+            RegionKind::Rom => 1,
+            RegionKind::Mmio => 2,
+        }
+    }
+}
+impl TryFrom<u32> for RegionKind {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u32) -> Result<Self> {
+        match value {
+            0 => Ok(RegionKind::Ram),
+            1 => Ok(RegionKind::Rom),
+            2 => Ok(RegionKind::Mmio),
+            value => Err(anyhow!("{value} is not a valid memory region kind")),
+        }
+    }
+}
+
+/// range of physical addresses, in words
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Region {
+    pub kind: RegionKind,
+    pub start: u32,
+    pub size: u32,
+}
+impl Region {
+    /// first address after the region, u64 so a region can end at the end of address space
+    pub fn end(&self) -> u64 {
+        self.start as u64 + self.size as u64
+    }
+    pub fn contains(&self, addr: B32) -> bool {
+        addr.0 >= self.start && (addr.0 as u64) < self.end()
+    }
+    /// every address of the region, in order
+    pub fn addrs(&self) -> impl Iterator<Item = B32> {
+        let start = self.start;
+        (0..self.size).map(move |offset| B32(start.wrapping_add(offset)))
+    }
+}
+/// `kind:start:size`, numbers can be decimal or hex with `0x`. eg. `ram:0:0x40000`
+impl FromStr for Region {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let parts: Vec<&str> = text.split(':').collect();
+        let [kind, start, size] = parts[..] else {
+            bail!("memory region: '{text}' should look like kind:start:size");
+        };
+        let kind = match kind {
+            "ram" => RegionKind::Ram,
+            "rom" => RegionKind::Rom,
+            "mmio" => RegionKind::Mmio,
+            kind => bail!("memory region kind: '{kind}' should be one of: ram, rom, mmio"),
+        };
+        Ok(Self {
+            kind,
+            start: parse_number(start).with_context(|| format!("start of region: '{text}'"))?,
+            size: parse_number(size).with_context(|| format!("size of region: '{text}'"))?,
+        })
+    }
+}
+fn parse_number(text: &str) -> Result<u32> {
+    Ok(match text.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16)?,
+        None => text.parse()?,
+    })
+}
+
+/// peripheral of the machine mapped at an address of a mmio region, by its `Peripheral::name`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeviceMapping {
+    pub name: String,
+    pub start: u32,
+}
+
+/// Where memory is and how programs are placed in it. Read from a toml file, eg:
+/// ```toml
+/// stack_size = 20000
+///
+/// [[region]]
+/// kind = "ram"
+/// start = 0
+/// size = 262144
+///
+/// [[region]]
+/// kind = "mmio"
+/// start = 0x100000
+/// size = 4096
+///
+/// [[device]]
+/// name = "serial"
+/// start = 0x100000
+/// ```
+/// Programs go to the start of the first ram region one after another: os, command line, heap,
+/// and then stacks of all threads. Every part gets the size from the map.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MemoryMap {
+    #[serde(rename = "region")]
+    pub regions: Vec<Region>,
+    #[serde(rename = "device")]
+    pub devices: Vec<DeviceMapping>,
+    pub os_size: u32,
+    pub command_line_size: u32,
+    pub heap_size: u32,
+    pub stack_size: u32,
+}
+impl Default for MemoryMap {
+    fn default() -> Self {
+        Self::for_resolution(Resolution::default())
+    }
+}
+impl MemoryMap {
+    /// default map with mmio regions big enough for the console and the framebuffer of a display
+    /// of this resolution
+    pub fn for_resolution(resolution: Resolution) -> Self {
+        let devices_size = DEFAULT_CONSOLE_ADDR - DEFAULT_MMIO_ADDR + Console::size_for(resolution);
+        Self {
+            regions: vec![
+                Region {
+                    kind: RegionKind::Ram,
+                    start: 0,
+                    size: DEFAULT_MMIO_ADDR,
+                },
+                Region {
+                    kind: RegionKind::Mmio,
+                    start: DEFAULT_MMIO_ADDR,
+                    size: devices_size.next_multiple_of(BANK_SIZE),
+                },
+                Region {
+                    kind: RegionKind::Mmio,
+                    start: DEFAULT_FRAMEBUFFER_ADDR,
+                    size: resolution.pixel_count().next_multiple_of(BANK_SIZE),
+                },
+            ],
+            devices: vec![
+                DeviceMapping {
+                    name: "serial".to_string(),
+                    start: DEFAULT_SERIAL_ADDR,
+                },
+                DeviceMapping {
+                    name: "mailbox".to_string(),
+                    start: DEFAULT_MAILBOX_ADDR,
+                },
+                DeviceMapping {
+                    name: "gpu".to_string(),
+                    start: DEFAULT_GPU_ADDR,
+                },
+                DeviceMapping {
+                    name: "console".to_string(),
+                    start: DEFAULT_CONSOLE_ADDR,
+                },
+                DeviceMapping {
+                    name: "framebuffer".to_string(),
+                    start: DEFAULT_FRAMEBUFFER_ADDR,
+                },
+            ],
+            os_size: 12000,
+            command_line_size: 4000,
+            heap_size: 17000,
+            stack_size: 20000,
+        }
+    }
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read memory map: {}", path.display()))?;
+        let map: Self = toml::from_str(&text)
+            .with_context(|| format!("invalid memory map: {}", path.display()))?;
+        Ok(map)
+    }
+    /// regions have to be aligned to banks and can't overlap
+    pub fn validate_regions(regions: &[Region]) -> Result<()> {
+        for region in regions {
+            if region.size == 0 || region.start % BANK_SIZE != 0 || region.size % BANK_SIZE != 0 {
+                bail!(
+                    "memory region: {region:?} has to have start and non zero size that are multiples of {BANK_SIZE}"
+                );
+            }
+            if region.end() > 1 << 32 {
+                bail!("memory region: {region:?} ends past the end of address space");
+            }
+        }
+        for (index, region) in regions.iter().enumerate() {
+            if let Some(other) = regions[index + 1..].iter().find(|other| {
+                (region.start as u64) < other.end() && (other.start as u64) < region.end()
+            }) {
+                bail!("memory regions: {region:?} and {other:?} overlap");
+            }
+        }
+        Ok(())
+    }
+    /// address the device with this name is mapped at
+    pub fn device_addr(&self, name: &str) -> Option<u32> {
+        self.devices
+            .iter()
+            .find(|device| device.name == name)
+            .map(|device| device.start)
+    }
+    /// places programs, heap and stacks of thread_count threads in the first ram region
+    pub fn layout(&self, thread_count: usize) -> Result<Layout> {
+        Self::validate_regions(&self.regions)?;
+        let ram = self
+            .regions
+            .iter()
+            .find(|region| region.kind == RegionKind::Ram)
+            .context("memory map has no ram region, programs have nowhere to go")?;
+
+        let os_addr = ram.start as u64;
+        let command_line_addr = os_addr + self.os_size as u64;
+        let heap_start = command_line_addr + self.command_line_size as u64;
+        let heap_end = heap_start + self.heap_size as u64;
+        let stacks_end = heap_end + self.stack_size as u64 * thread_count as u64;
+        if stacks_end > ram.end() {
+            bail!(
+                "os, command line, heap and stacks of {thread_count} threads need {} words, ram region: {ram:?} has only {}",
+                stacks_end - os_addr,
+                ram.size
+            );
+        }
+        Ok(Layout {
+            os_addr: os_addr as u32,
+            os_size: self.os_size,
+            command_line_addr: command_line_addr as u32,
+            heap_start: heap_start as u32,
+            heap_end: heap_end as u32,
+            stacks_addr: heap_end as u32,
+            stack_size: self.stack_size,
+            serial_addr: self.device_addr("serial").unwrap_or(u32::MAX),
+            framebuffer_addr: self.device_addr("framebuffer").unwrap_or(u32::MAX),
+        })
+    }
+}
+
+/// addresses derived from the memory map, see `MemoryMap::layout`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Layout {
+    pub os_addr: u32,
+    pub os_size: u32,
+    pub command_line_addr: u32,
+    pub heap_start: u32,
+    pub heap_end: u32,
+    /// stack of thread i starts at stacks_addr + stack_size * i
+    pub stacks_addr: u32,
+    pub stack_size: u32,
+    /// u32::MAX if the memory map doesn't map the device
+    pub serial_addr: u32,
+    pub framebuffer_addr: u32,
+}
+impl Layout {
+    pub fn stack_base_addr(&self, thread_index: usize) -> B32 {
+        B32(self.stacks_addr + self.stack_size * thread_index as u32)
+    }
+}
+
+/// physical memory of a machine, made from the regions of its memory map.
+/// Addresses outside of ram and rom read as 0 and writes to them are ignored, threads check
+/// their accesses with `allows` first.
+pub struct Memory {
+    regions: Vec<(Region, Vec<RAM4k>)>,
+}
+impl Memory {
+    pub fn new(regions: &[Region]) -> Result<Self> {
+        MemoryMap::validate_regions(regions)?;
+        Ok(Self {
+            regions: regions
+                .iter()
+                .map(|region| {
+                    let bank_count = match region.kind {
+                        RegionKind::Ram | RegionKind::Rom => region.size / BANK_SIZE,
+                        RegionKind::Mmio => 0,
+                    };
+                    (*region, (0..bank_count).map(|_| RAM4k::new()).collect())
+                })
+                .collect(),
+        })
+    }
+    pub fn regions(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter().map(|(region, _)| region)
+    }
+    pub fn region(&self, addr: B32) -> Option<&Region> {
+        self.regions().find(|region| region.contains(addr))
+    }
+    /// whether a program can access this physical address this way
+    pub fn allows(&self, addr: B32, access: MemoryAccessKind) -> bool {
+        match self.region(addr).map(|region| region.kind) {
+            Some(RegionKind::Ram) => true,
+            Some(RegionKind::Rom) => access != MemoryAccessKind::Write,
+            Some(RegionKind::Mmio) | None => false,
+        }
+    }
+    fn bank(&self, addr: B32) -> Option<(&RAM4k, B32)> {
+        let (region, banks) = self
+            .regions
+            .iter()
+            .find(|(region, _)| region.contains(addr))?;
+        let offset = addr.0 - region.start;
+        let bank = banks.get((offset / BANK_SIZE) as usize)?;
+        Some((bank, B32(offset % BANK_SIZE)))
+    }
+    pub fn read(&self, addr: B32) -> B32 {
+        match self.bank(addr) {
+            Some((bank, offset)) => bank.read(offset),
+            None => B32(0),
+        }
+    }
+    /// rom is written too, it's how programs get into it
+    pub fn write(&self, data: B32, addr: B32, store: bool) {
+        if let Some((bank, offset)) = self.bank(addr) {
+            bank.write(data, offset, store);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use anyhow::{Context, Result, bail};
+
+use crate::{
+    chips::b32::B32,
+    memory_map::{Memory, RegionKind},
+};
+
+/// peripheral mapped to addresses start..start + size
+struct Mapping {
+    name: String,
+    start: u32,
+    size: u32,
+    peripheral_index: u32,
+}
+impl Mapping {
+    fn end(&self) -> u64 {
+        self.start as u64 + self.size as u64
+    }
+    fn contains(&self, addr: B32) -> bool {
+        addr.0 >= self.start && (addr.0 as u64) < self.end()
+    }
+}
+
+/// Finds the peripheral behind an address of a mmio region. `Read` and `Write` of these addresses
+/// go to the peripheral instead of memory, see `Peripheral`.
+pub struct MmioBus {
+    mappings: Vec<Mapping>,
+}
+impl MmioBus {
+    pub fn new() -> Self {
+        Self {
+            mappings: Vec::new(),
+        }
+    }
+    /// peripheral has to fit in one mmio region of memory and can't overlap other ones
+    pub fn map(
+        &mut self,
+        memory: &Memory,
+        name: &str,
+        start: u32,
+        size: u32,
+        peripheral_index: u32,
+    ) -> Result<()> {
+        let mapping = Mapping {
+            name: name.to_string(),
+            start,
+            size,
+            peripheral_index,
+        };
+        if size == 0 {
+            bail!("mmio device: {name} has size 0");
+        }
+        let region = memory
+            .region(B32(start))
+            .filter(|region| region.kind == RegionKind::Mmio)
+            .with_context(|| {
+                format!("mmio device: {name} at: {start:#x} is not in a mmio region")
+            })?;
+        if mapping.end() > region.end() {
+            bail!("mmio device: {name} at: {start:#x} with size: {size} doesn't fit in {region:?}");
+        }
+        if let Some(other) = self
+            .mappings
+            .iter()
+            .find(|other| (other.start as u64) < mapping.end() && (start as u64) < other.end())
+        {
+            bail!(
+                "mmio devices: {name} at: {start:#x} and {} at: {:#x} overlap",
+                other.name,
+                other.start
+            );
+        }
+        self.mappings.push(mapping);
+        Ok(())
+    }
+    /// name and start of every mapped peripheral
+    pub fn mappings(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.mappings
+            .iter()
+            .map(|mapping| (mapping.name.as_str(), mapping.start))
+    }
+    pub fn contains(&self, addr: B32) -> bool {
+        self.route(addr).is_some()
+    }
+    /// index of the peripheral and offset of the address from its start
+    pub fn route(&self, addr: B32) -> Option<(u32, u32)> {
+        self.mappings
+            .iter()
+            .find(|mapping| mapping.contains(addr))
+            .map(|mapping| (mapping.peripheral_index, addr.0 - mapping.start))
+    }
+}
@@ -1,73 +1,195 @@
+pub mod console;
+pub mod framebuffer;
+pub mod gpu;
+pub mod mailbox;
 pub mod serial;
+pub mod timer;
 
-use std::sync::{OnceLock, atomic::AtomicU32};
+use std::{any::Any, collections::BTreeMap, sync::Mutex};
 
-use anyhow::{Result, anyhow};
-use lazy_static::lazy_static;
-use log::{error, info};
+use anyhow::{Context, Result, bail};
+use console::Console;
+use framebuffer::Framebuffer;
+use gpu::{Display, Gpu, SharedDisplay};
+use log::{info, warn};
+use mailbox::Mailbox;
 use serial::Serial;
-use tokio::sync::Mutex;
+use timer::Timer;
 
-use crate::{chips::b32::B32, fb};
+use crate::{
+    chips::{b32::B32, thread::Interrupt},
+    fb::Resolution,
+    memory_map::Memory,
+    snapshot::{SnapshotReader, SnapshotWriter},
+};
 
-lazy_static! {
-    static ref SERIAL: Mutex<Serial> = Mutex::new(Serial { text: Vec::new() });
-}
-static LAST_GPU_COMMAND_PART: AtomicU32 = AtomicU32::new(u32::MAX);
-
-pub async fn call_peripheral(peripheral_index: B32, data: B32) -> Result<()> {
-    info!(
-        "call_peripheral {peripheral_index},data {} bits {:032b}",
-        data.0, data.0
-    );
+pub const SERIAL_INDEX: u32 = 0;
+pub const GPU_INDEX: u32 = 1;
+pub const TIMER_INDEX: u32 = 2;
+pub const CONSOLE_INDEX: u32 = 3;
+pub const FRAMEBUFFER_INDEX: u32 = 4;
+pub const MAILBOX_INDEX: u32 = 5;
+/// serial ports after the first one start at this index
+pub const EXTRA_SERIAL_INDEX: u32 = 16;
 
-    match peripheral_index.0 as u32 {
-        0 => {
-            SERIAL.lock().await.call_serial(data);
-            Ok(())
-        }
-        1 => {
-            let last = LAST_GPU_COMMAND_PART.load(std::sync::atomic::Ordering::Relaxed);
-            if last == u32::MAX {
-                LAST_GPU_COMMAND_PART.store(data.0, std::sync::atomic::Ordering::Relaxed);
-            } else {
-                fb::push_new_command(fb::Command {
-                    color: data.0,
-                    pos: last,
-                })
-                .await?;
+/// Device of a machine. `Phrp` writes its data at offset 0, when the device is mapped to memory
+/// `Read` and `Write` of its addresses go to offsets from the start of it.
+/// thread_index is index of the thread that accessed the device.
+pub trait Peripheral: Any + Send {
+    /// used by memory map and snapshots to find the device
+    fn name(&self) -> &str;
+    /// words of address space the device takes when it's mapped to memory
+    fn size(&self) -> u32 {
+        1
+    }
+    fn read(&mut self, thread_index: usize, offset: u32) -> Result<B32>;
+    fn write(&mut self, thread_index: usize, offset: u32, data: B32) -> Result<()>;
+    /// called once every cycle, `raise` queues interrupt on thread with the index. Device can read
+    /// physical memory then, like dma.
+    fn tick(&mut self, memory: &Memory, raise: &mut dyn FnMut(usize, Interrupt)) {}
+    /// back to the state it had when it was created
+    fn reset(&mut self);
+    fn write_snapshot(&self, writer: &mut SnapshotWriter);
+    fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()>;
+}
 
-                LAST_GPU_COMMAND_PART.store(u32::MAX, std::sync::atomic::Ordering::Relaxed);
-            }
-            Ok(())
+/// devices of a machine by their `Phrp` index. Every device is behind its own lock, because
+/// threads only get `&Machine`.
+pub struct Peripherals {
+    devices: BTreeMap<u32, Mutex<Box<dyn Peripheral>>>,
+    display: SharedDisplay,
+}
+impl Peripherals {
+    /// built in devices with the default resolution
+    pub fn new() -> Self {
+        Self::with_resolution(Resolution::default())
+    }
+    /// serial, gpu, timer, console, framebuffer and mailbox at their indexes, gpu, console and
+    /// framebuffer draw into the same display
+    pub fn with_resolution(resolution: Resolution) -> Self {
+        let display = Display::new(resolution);
+        let mut peripherals = Self {
+            devices: BTreeMap::new(),
+            display: display.clone(),
+        };
+        let built_in: [(u32, Box<dyn Peripheral>); 6] = [
+            (SERIAL_INDEX, Box::new(Serial::new())),
+            (GPU_INDEX, Box::new(Gpu::new(display.clone()))),
+            (TIMER_INDEX, Box::new(Timer::new())),
+            (CONSOLE_INDEX, Box::new(Console::new(display.clone()))),
+            (FRAMEBUFFER_INDEX, Box::new(Framebuffer::new(display))),
+            (MAILBOX_INDEX, Box::new(Mailbox::new())),
+        ];
+        for (index, device) in built_in {
+            peripherals.devices.insert(index, Mutex::new(device));
         }
-        250 => {
-            // dummy
-            Ok(())
+        peripherals
+    }
+    /// index and name have to be unused
+    pub fn register(&mut self, index: u32, device: Box<dyn Peripheral>) -> Result<()> {
+        if let Some(other) = self.devices.get_mut(&index) {
+            bail!(
+                "peripheral index: {index} is already used by: {}",
+                other.get_mut().unwrap().name()
+            );
         }
-
-        251 => {
-            // dummy
-            Ok(())
+        if self.index_of(device.name()).is_some() {
+            bail!("there already is a peripheral named: {}", device.name());
         }
-
-        252 => {
-            // dummy
-            Ok(())
+        self.devices.insert(index, Mutex::new(device));
+        Ok(())
+    }
+    pub fn index_of(&self, name: &str) -> Option<u32> {
+        self.devices
+            .iter()
+            .find(|(_, device)| device.lock().unwrap().name() == name)
+            .map(|(index, _)| *index)
+    }
+    pub fn size(&self, index: u32) -> Option<u32> {
+        Some(self.devices.get(&index)?.lock().unwrap().size())
+    }
+    /// first device of type T
+    pub fn get_mut<T: Peripheral>(&mut self) -> Option<&mut T> {
+        self.devices.values_mut().find_map(|device| {
+            let device: &mut dyn Any = &mut **device.get_mut().unwrap();
+            device.downcast_mut::<T>()
+        })
+    }
+    fn device(&self, index: u32) -> Result<&Mutex<Box<dyn Peripheral>>> {
+        self.devices
+            .get(&index)
+            .with_context(|| format!("peripheral with index: {index} was not mapped!"))
+    }
+    pub fn read(&self, index: u32, thread_index: usize, offset: u32) -> Result<B32> {
+        self.device(index)?
+            .lock()
+            .unwrap()
+            .read(thread_index, offset)
+    }
+    pub fn write(&self, index: u32, thread_index: usize, offset: u32, data: B32) -> Result<()> {
+        self.device(index)?
+            .lock()
+            .unwrap()
+            .write(thread_index, offset, data)
+    }
+    /// thread_index is index of the thread that runs `Phrp`
+    pub fn call(&self, thread_index: usize, peripheral_index: B32, data: B32) -> Result<()> {
+        info!(
+            "call_peripheral {peripheral_index},data {} bits {:032b}",
+            data.0, data.0
+        );
+        self.write(peripheral_index.0, thread_index, 0, data)
+    }
+    pub(crate) fn tick(&self, memory: &Memory, mut raise: impl FnMut(usize, Interrupt)) {
+        for device in self.devices.values() {
+            device.lock().unwrap().tick(memory, &mut raise);
         }
-        253 => {
-            // dummy
-            Ok(())
+    }
+    pub fn reset(&self) {
+        for device in self.devices.values() {
+            device.lock().unwrap().reset();
         }
-        254 => {
-            // dummy
-            Ok(())
+    }
+
+    /// frames the devices draw into, frame buffer shows its front one
+    pub fn display(&self) -> SharedDisplay {
+        self.display.clone()
+    }
+
+    pub(crate) fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        writer.u32(self.devices.len() as u32);
+        for (index, device) in &self.devices {
+            let device = device.lock().unwrap();
+            writer.u32(*index);
+            writer.bytes(device.name().as_bytes());
+            writer.section(|writer| device.write_snapshot(writer));
         }
-        255 => {
-            // dummy
-            Ok(())
+    }
+    /// devices that are not registered are skipped, their state is lost
+    pub(crate) fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()> {
+        let device_count = reader.u32()?;
+        for _ in 0..device_count {
+            let index = reader.u32()?;
+            let name = String::from_utf8(reader.bytes()?.to_vec())
+                .context("snapshot has invalid peripheral name")?;
+            let mut section = reader.section()?;
+            let Some(device) = self.devices.get_mut(&index) else {
+                warn!("snapshot has peripheral: {name} at index: {index} that is not registered");
+                continue;
+            };
+            let device = device.get_mut().unwrap();
+            if device.name() != name {
+                warn!(
+                    "snapshot has peripheral: {name} at index: {index}, but it's: {}",
+                    device.name()
+                );
+                continue;
+            }
+            device
+                .read_snapshot(&mut section)
+                .with_context(|| format!("invalid state of peripheral: {name}"))?;
+            section.finish()?;
         }
-
-        index => Err(anyhow!("peripheral with index: {index} was not mapped!")),
+        Ok(())
     }
 }
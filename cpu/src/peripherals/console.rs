@@ -0,0 +1,296 @@
+pub mod font;
+
+use anyhow::{Result, anyhow, bail};
+
+use crate::{
+    chips::b32::B32,
+    fb::Resolution,
+    peripherals::{Peripheral, gpu::SharedDisplay},
+    snapshot::{SnapshotReader, SnapshotWriter},
+};
+
+/// glyph and a column / row of space after it, in pixels
+pub const CELL_WIDTH: u32 = font::GLYPH_WIDTH + 1;
+pub const CELL_HEIGHT: u32 = font::GLYPH_HEIGHT + 1;
+
+/// writing prints the 4 characters of the word, zero bytes are skipped. `Phrp` writes here.
+pub const CONSOLE_DATA: u32 = 0;
+/// cell of the cursor: row * columns + column
+pub const CONSOLE_CURSOR: u32 = 1;
+/// colors of printed characters: foreground in bits 0..4, background in bits 4..8
+pub const CONSOLE_ATTRIBUTE: u32 = 2;
+/// bit 0 shows the cursor
+pub const CONSOLE_CONTROL: u32 = 3;
+/// read only: columns | rows << 16
+pub const CONSOLE_SIZE: u32 = 4;
+/// cells start here, one word per cell: character | attribute << 8
+pub const CONSOLE_CELLS: u32 = 16;
+
+/// light gray on black
+pub const DEFAULT_ATTRIBUTE: u8 = 0x07;
+/// colors of the attribute, same as cga
+pub const PALETTE: [u32; 16] = [
+    rgb(0x00, 0x00, 0x00),
+    rgb(0x00, 0x00, 0xAA),
+    rgb(0x00, 0xAA, 0x00),
+    rgb(0x00, 0xAA, 0xAA),
+    rgb(0xAA, 0x00, 0x00),
+    rgb(0xAA, 0x00, 0xAA),
+    rgb(0xAA, 0x55, 0x00),
+    rgb(0xAA, 0xAA, 0xAA),
+    rgb(0x55, 0x55, 0x55),
+    rgb(0x55, 0x55, 0xFF),
+    rgb(0x55, 0xFF, 0x55),
+    rgb(0x55, 0xFF, 0xFF),
+    rgb(0xFF, 0x55, 0x55),
+    rgb(0xFF, 0x55, 0xFF),
+    rgb(0xFF, 0xFF, 0x55),
+    rgb(0xFF, 0xFF, 0xFF),
+];
+/// color as the frame buffer takes it
+const fn rgb(red: u8, green: u8, blue: u8) -> u32 {
+    u32::from_le_bytes([red, green, blue, 0xFF])
+}
+
+const BACKSPACE: u8 = 8;
+const FORM_FEED: u8 = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Cell {
+    character: u8,
+    attribute: u8,
+}
+impl Cell {
+    fn blank(attribute: u8) -> Self {
+        Self {
+            character: b' ',
+            attribute,
+        }
+    }
+    fn word(self) -> u32 {
+        self.character as u32 | (self.attribute as u32) << 8
+    }
+}
+
+/// Text mode drawn into the display with the built in font, as many cells as fit in its
+/// resolution. Printed characters go to the cursor, which moves after them. When it moves past the
+/// last row everything scrolls up by a row.
+/// Control characters: '\n' goes to the start of next row, '\r' to the start of this one,
+/// backspace erases the character before the cursor and form feed clears the screen.
+pub struct Console {
+    columns: u32,
+    rows: u32,
+    cells: Vec<Cell>,
+    cursor: u32,
+    attribute: u8,
+    cursor_visible: bool,
+    display: SharedDisplay,
+}
+impl Console {
+    pub fn new(display: SharedDisplay) -> Self {
+        let (columns, rows) = Self::columns_and_rows(display.lock().unwrap().resolution());
+        Self {
+            columns,
+            rows,
+            cells: vec![Cell::blank(DEFAULT_ATTRIBUTE); (columns * rows) as usize],
+            cursor: 0,
+            attribute: DEFAULT_ATTRIBUTE,
+            cursor_visible: true,
+            display,
+        }
+    }
+    fn columns_and_rows(resolution: Resolution) -> (u32, u32) {
+        (
+            resolution.width / CELL_WIDTH,
+            resolution.height / CELL_HEIGHT,
+        )
+    }
+    /// size in words of a console on display of this resolution
+    pub fn size_for(resolution: Resolution) -> u32 {
+        let (columns, rows) = Self::columns_and_rows(resolution);
+        CONSOLE_CELLS + columns * rows
+    }
+    pub fn print(&mut self, character: u8) -> Result<()> {
+        match character {
+            b'\n' => self.move_cursor((self.cursor / self.columns + 1) * self.columns),
+            b'\r' => self.move_cursor(self.cursor / self.columns * self.columns),
+            BACKSPACE => {
+                if self.cursor % self.columns == 0 {
+                    return Ok(());
+                }
+                self.cells[self.cursor as usize - 1] = Cell::blank(self.attribute);
+                self.move_cursor(self.cursor - 1)
+            }
+            FORM_FEED => self.clear(),
+            character => {
+                self.cells[self.cursor as usize] = Cell {
+                    character,
+                    attribute: self.attribute,
+                };
+                self.move_cursor(self.cursor + 1)
+            }
+        }
+    }
+    fn cell_count(&self) -> u32 {
+        self.columns * self.rows
+    }
+    /// blank cells with the current attribute, cursor goes to the top left corner
+    pub fn clear(&mut self) -> Result<()> {
+        self.cells.fill(Cell::blank(self.attribute));
+        self.cursor = 0;
+        self.draw_all()
+    }
+    /// cursor one row past the screen scrolls it
+    fn move_cursor(&mut self, cursor: u32) -> Result<()> {
+        if cursor >= self.cell_count() {
+            self.cells.drain(..self.columns as usize);
+            self.cells
+                .resize(self.cell_count() as usize, Cell::blank(self.attribute));
+            self.cursor = cursor - self.columns;
+            return self.draw_all();
+        }
+        let old_cursor = self.cursor;
+        self.cursor = cursor;
+        self.draw_cell(old_cursor)?;
+        self.draw_cell(cursor)
+    }
+    fn draw_all(&self) -> Result<()> {
+        for index in 0..self.cell_count() {
+            self.draw_cell(index)?;
+        }
+        Ok(())
+    }
+    fn draw_cell(&self, index: u32) -> Result<()> {
+        let cell = self.cells[index as usize];
+        let glyph = font::glyph(cell.character);
+        let foreground = PALETTE[(cell.attribute & 0xF) as usize];
+        let background = PALETTE[(cell.attribute >> 4) as usize];
+        let has_cursor = self.cursor_visible && index == self.cursor;
+        let left = index % self.columns * CELL_WIDTH;
+        let top = index / self.columns * CELL_HEIGHT;
+
+        let mut display = self.display.lock().unwrap();
+        let width = display.resolution().width;
+        for y in 0..CELL_HEIGHT {
+            for x in 0..CELL_WIDTH {
+                let in_glyph = x < font::GLYPH_WIDTH
+                    && y < font::GLYPH_HEIGHT
+                    && glyph[x as usize] >> y & 1 == 1;
+                // cursor is a line under the glyph
+                let on = in_glyph || (has_cursor && y == CELL_HEIGHT - 1);
+                display.draw(
+                    ((top + y) * width + left + x) * 4,
+                    if on { foreground } else { background },
+                );
+            }
+        }
+        Ok(())
+    }
+}
+impl Peripheral for Console {
+    fn name(&self) -> &str {
+        "console"
+    }
+    fn size(&self) -> u32 {
+        CONSOLE_CELLS + self.cell_count()
+    }
+    fn read(&mut self, thread_index: usize, offset: u32) -> Result<B32> {
+        Ok(B32(match offset {
+            CONSOLE_CURSOR => self.cursor,
+            CONSOLE_ATTRIBUTE => self.attribute as u32,
+            CONSOLE_CONTROL => self.cursor_visible as u32,
+            CONSOLE_SIZE => self.columns | self.rows << 16,
+            CONSOLE_CELLS.. => self
+                .cells
+                .get((offset - CONSOLE_CELLS) as usize)
+                .map_or(0, |cell| cell.word()),
+            _ => 0,
+        }))
+    }
+    fn write(&mut self, thread_index: usize, offset: u32, data: B32) -> Result<()> {
+        match offset {
+            CONSOLE_DATA => {
+                for character in data.0.to_le_bytes() {
+                    if character != 0 {
+                        self.print(character)?;
+                    }
+                }
+                Ok(())
+            }
+            CONSOLE_CURSOR => {
+                if data.0 >= self.cell_count() {
+                    bail!("console cursor: {} is past the last cell", data.0);
+                }
+                self.move_cursor(data.0)
+            }
+            CONSOLE_ATTRIBUTE => {
+                self.attribute = u8::try_from(data.0).map_err(|_| {
+                    anyhow!("console attribute: {:#x} has more than 8 bits", data.0)
+                })?;
+                Ok(())
+            }
+            CONSOLE_CONTROL => {
+                self.cursor_visible = data.0 & 1 == 1;
+                self.draw_cell(self.cursor)
+            }
+            CONSOLE_CELLS.. => {
+                let index = offset - CONSOLE_CELLS;
+                if index >= self.cell_count() || data.0 > 0xFFFF {
+                    bail!("console cell: {index} can't be set to: {:#x}", data.0);
+                }
+                self.cells[index as usize] = Cell {
+                    character: data.0 as u8,
+                    attribute: (data.0 >> 8) as u8,
+                };
+                self.draw_cell(index)
+            }
+            _ => Ok(()),
+        }
+    }
+    /// text is cleared, but what was already drawn stays in the display
+    fn reset(&mut self) {
+        self.cells.fill(Cell::blank(DEFAULT_ATTRIBUTE));
+        self.cursor = 0;
+        self.attribute = DEFAULT_ATTRIBUTE;
+        self.cursor_visible = true;
+    }
+    /// pixels and resolution are saved by the gpu, only the text is here
+    fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        let cells: Vec<u8> = self
+            .cells
+            .iter()
+            .flat_map(|cell| [cell.character, cell.attribute])
+            .collect();
+        writer.bytes(&cells);
+        writer.u32(self.cursor);
+        writer.u32(self.attribute as u32);
+        writer.bool(self.cursor_visible);
+    }
+    /// gpu is restored first, cells have to fit its resolution
+    fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()> {
+        *self = Self::new(self.display.clone());
+        let cells = reader.bytes()?;
+        if cells.len() != self.cell_count() as usize * 2 {
+            bail!(
+                "snapshot has console with {} cells, expected: {}",
+                cells.len() / 2,
+                self.cell_count()
+            );
+        }
+        let cursor = reader.u32()?;
+        if cursor >= self.cell_count() {
+            bail!("snapshot has console cursor: {cursor} past the last cell");
+        }
+        self.cells = cells
+            .chunks_exact(2)
+            .map(|cell| Cell {
+                character: cell[0],
+                attribute: cell[1],
+            })
+            .collect();
+        self.cursor = cursor;
+        self.attribute = reader.u32()? as u8;
+        self.cursor_visible = reader.bool()?;
+        Ok(())
+    }
+}
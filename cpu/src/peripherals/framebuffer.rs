@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+use crate::{
+    chips::b32::B32,
+    peripherals::{Peripheral, gpu::SharedDisplay},
+    snapshot::{SnapshotReader, SnapshotWriter},
+};
+
+/// Back buffer of the display mapped to memory, one word per pixel, row after row. Word is the
+/// color as rgba bytes, red in the lowest one. What is written here is shown at the next vblank,
+/// see `Gpu`.
+pub struct Framebuffer {
+    display: SharedDisplay,
+}
+impl Framebuffer {
+    pub fn new(display: SharedDisplay) -> Self {
+        Self { display }
+    }
+}
+impl Peripheral for Framebuffer {
+    fn name(&self) -> &str {
+        "framebuffer"
+    }
+    fn size(&self) -> u32 {
+        self.display.lock().unwrap().resolution().pixel_count()
+    }
+    fn read(&mut self, thread_index: usize, offset: u32) -> Result<B32> {
+        Ok(self.display.lock().unwrap().pixel(offset * 4))
+    }
+    fn write(&mut self, thread_index: usize, offset: u32, data: B32) -> Result<()> {
+        self.display.lock().unwrap().draw(offset * 4, data.0);
+        Ok(())
+    }
+    /// pixels are reset and saved by the gpu
+    fn reset(&mut self) {}
+    fn write_snapshot(&self, writer: &mut SnapshotWriter) {}
+    fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()> {
+        Ok(())
+    }
+}
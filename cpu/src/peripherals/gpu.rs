@@ -0,0 +1,370 @@
+pub mod commands;
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Result, bail};
+use commands::PALETTE_SIZE;
+use log::{debug, info, warn};
+
+use crate::{
+    chips::{
+        b32::B32,
+        thread::{Interrupt, InterruptKind},
+    },
+    fb::Resolution,
+    memory_map::Memory,
+    peripherals::{Peripheral, console},
+    snapshot::{SnapshotReader, SnapshotWriter},
+};
+
+/// `Phrp` writes here, one pixel takes 2 writes: first position and then color
+pub const GPU_COMMAND: u32 = 0;
+/// position of the pixel (index of its first byte in rgba frame) for `GPU_COLOR`
+pub const GPU_POSITION: u32 = 1;
+/// writing it draws the pixel at `GPU_POSITION`, reading returns what was drawn there
+pub const GPU_COLOR: u32 = 2;
+/// writing 1 shows the back buffer at the next vblank, 0 takes it back. Reading returns 1 until
+/// it's shown.
+pub const GPU_PRESENT: u32 = 3;
+/// bit 0 turns on double buffering, bit 1 enables the vblank interrupt for the thread that wrote it
+pub const GPU_CONTROL: u32 = 4;
+/// read only: width | height << 16
+pub const GPU_RESOLUTION: u32 = 5;
+/// read only: number of vblanks since reset
+pub const GPU_FRAME_COUNT: u32 = 6;
+/// writing physical address of a command list runs it on the next cycle, see `commands`.
+/// Reading returns one of `GPU_LIST_*`, writing while a list waits fails both lists.
+pub const GPU_COMMAND_LIST: u32 = 7;
+
+pub const GPU_LIST_DONE: u32 = 0;
+pub const GPU_LIST_BUSY: u32 = 1;
+pub const GPU_LIST_FAILED: u32 = 2;
+
+pub const GPU_CONTROL_DOUBLE_BUFFERING: u32 = 1 << 0;
+pub const GPU_CONTROL_VBLANK_INTERRUPT: u32 = 1 << 1;
+
+/// cycles of the virtual clock between two vblanks
+pub const VBLANK_CYCLES: u32 = 100_000;
+
+/// Frames that devices draw into, shared by the gpu, the framebuffer and the console. They all
+/// draw into the back buffer, the frame buffer (window or headless) shows the front one. Gpu copies
+/// back to front at vblank, see `Gpu`.
+pub struct Display {
+    resolution: Resolution,
+    /// rgba
+    back: Vec<u8>,
+    /// rgba
+    front: Vec<u8>,
+}
+pub type SharedDisplay = Arc<Mutex<Display>>;
+impl Display {
+    pub fn new(resolution: Resolution) -> SharedDisplay {
+        Arc::new(Mutex::new(Self {
+            resolution,
+            back: vec![0; resolution.frame_bytes()],
+            front: vec![0; resolution.frame_bytes()],
+        }))
+    }
+    pub fn resolution(&self) -> Resolution {
+        self.resolution
+    }
+    /// rgba frame that is shown
+    pub fn frame(&self) -> &[u8] {
+        &self.front
+    }
+    /// pos is index of the first byte of the pixel in rgba frame. Position comes from the program,
+    /// pixels outside of the frame are skipped like in `set_pixel`
+    pub fn draw(&mut self, pos: u32, color: u32) {
+        let pos = pos as usize;
+        let Some(pixel) = self.back.get_mut(pos..pos + 4) else {
+            debug!("gpu position: {pos} is outside of the frame buffer");
+            return;
+        };
+        pixel.copy_from_slice(&color.to_le_bytes());
+    }
+    /// color of the pixel from the back buffer, 0 outside of the frame
+    pub fn pixel(&self, pos: u32) -> B32 {
+        let pos = pos as usize;
+        self.back.get(pos..pos + 4).map_or(B32(0), |pixel| {
+            B32(u32::from_le_bytes(pixel.try_into().unwrap()))
+        })
+    }
+    /// pixel at x, y of the back buffer, ones outside of the frame are skipped
+    pub fn set_pixel(&mut self, x: i32, y: i32, color: u32) {
+        if x < 0 || y < 0 || x >= self.resolution.width as i32 || y >= self.resolution.height as i32
+        {
+            return;
+        }
+        let pos = (x as usize + y as usize * self.resolution.width as usize) * 4;
+        self.back[pos..pos + 4].copy_from_slice(&color.to_le_bytes());
+    }
+    pub fn fill(&mut self, color: u32) {
+        for pixel in self.back.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&color.to_le_bytes());
+        }
+    }
+    /// part of the rectangle outside of the frame is skipped
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: u32) {
+        let left = x.max(0);
+        let top = y.max(0);
+        let right = (x + width).min(self.resolution.width as i32);
+        let bottom = (y + height).min(self.resolution.height as i32);
+        for y in top..bottom {
+            for x in left..right {
+                self.set_pixel(x, y, color);
+            }
+        }
+    }
+    fn present(&mut self) {
+        self.front.copy_from_slice(&self.back);
+    }
+    fn clear(&mut self) {
+        self.back.fill(0);
+        self.front.fill(0);
+    }
+    fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        writer.u32(self.resolution.width);
+        writer.u32(self.resolution.height);
+        writer.bytes(&self.back);
+        writer.bytes(&self.front);
+    }
+    /// resolution of the snapshot replaces the current one
+    fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()> {
+        let resolution = Resolution {
+            width: reader.u32()?,
+            height: reader.u32()?,
+        };
+        resolution.validate()?;
+        let back = reader.bytes()?;
+        let front = reader.bytes()?;
+        if back.len() != resolution.frame_bytes() || front.len() != resolution.frame_bytes() {
+            bail!(
+                "snapshot has frames of size: {} and {}, expected: {} for resolution: {resolution}",
+                back.len(),
+                front.len(),
+                resolution.frame_bytes()
+            );
+        }
+        self.resolution = resolution;
+        self.back = back.to_vec();
+        self.front = front.to_vec();
+        Ok(())
+    }
+}
+
+/// Draws pixels into the display and shows them. Every `VBLANK_CYCLES` comes a vblank, back buffer
+/// is copied to the front one then. Without double buffering it's copied at every vblank, with it
+/// only after the program asked for it with `GPU_PRESENT`, so half drawn frames are never shown.
+/// Bigger things are drawn with command lists, see `commands`.
+pub struct Gpu {
+    /// position from the first `Phrp` call, u32::MAX when next call is a position
+    last_command_part: u32,
+    position: u32,
+    double_buffered: bool,
+    present_pending: bool,
+    cycles_to_vblank: u32,
+    frame_count: u32,
+    vblank_thread: Option<usize>,
+    palette: [u32; PALETTE_SIZE],
+    /// physical address of the list that runs on the next cycle
+    pending_list: Option<u32>,
+    list_status: u32,
+    display: SharedDisplay,
+}
+impl Gpu {
+    pub fn new(display: SharedDisplay) -> Self {
+        let mut palette = [0; PALETTE_SIZE];
+        // same first 16 colors as the console
+        palette[..console::PALETTE.len()].copy_from_slice(&console::PALETTE);
+        Self {
+            last_command_part: u32::MAX,
+            position: 0,
+            double_buffered: false,
+            present_pending: false,
+            cycles_to_vblank: VBLANK_CYCLES,
+            frame_count: 0,
+            vblank_thread: None,
+            palette,
+            pending_list: None,
+            list_status: GPU_LIST_DONE,
+            display,
+        }
+    }
+    /// errors of the list don't stop the machine, they are logged and the status says it failed
+    fn run_list(&mut self, memory: &Memory, addr: u32) {
+        let mut display = self.display.lock().unwrap();
+        match commands::run_list(memory, &mut display, &mut self.palette, addr) {
+            Ok(command_count) => {
+                info!("gpu ran {command_count} commands of list at: {addr:#x}");
+                self.list_status = GPU_LIST_DONE;
+            }
+            Err(err) => {
+                warn!("gpu command list at: {addr:#x} failed: {err}");
+                self.list_status = GPU_LIST_FAILED;
+            }
+        }
+    }
+    pub fn call_gpu(&mut self, data: B32) {
+        if self.last_command_part == u32::MAX {
+            self.last_command_part = data.0;
+            return;
+        }
+        let pos = self.last_command_part;
+        self.last_command_part = u32::MAX;
+        self.display.lock().unwrap().draw(pos, data.0);
+    }
+    fn control(&self) -> u32 {
+        let mut control = 0;
+        if self.double_buffered {
+            control |= GPU_CONTROL_DOUBLE_BUFFERING;
+        }
+        if self.vblank_thread.is_some() {
+            control |= GPU_CONTROL_VBLANK_INTERRUPT;
+        }
+        control
+    }
+    fn set_control(&mut self, thread_index: usize, control: u32) -> Result<()> {
+        if control & !(GPU_CONTROL_DOUBLE_BUFFERING | GPU_CONTROL_VBLANK_INTERRUPT) != 0 {
+            bail!("gpu control: {control:#b} has unknown bits");
+        }
+        self.double_buffered = control & GPU_CONTROL_DOUBLE_BUFFERING != 0;
+        self.vblank_thread = (control & GPU_CONTROL_VBLANK_INTERRUPT != 0).then_some(thread_index);
+        Ok(())
+    }
+    fn vblank(&mut self, raise: &mut dyn FnMut(usize, Interrupt)) {
+        if !self.double_buffered || self.present_pending {
+            self.display.lock().unwrap().present();
+            self.present_pending = false;
+        }
+        self.frame_count = self.frame_count.wrapping_add(1);
+        if let Some(thread_index) = self.vblank_thread {
+            raise(
+                thread_index,
+                Interrupt {
+                    kind: InterruptKind::VBlank,
+                    data: self.frame_count,
+                },
+            );
+        }
+    }
+}
+impl Peripheral for Gpu {
+    fn name(&self) -> &str {
+        "gpu"
+    }
+    fn size(&self) -> u32 {
+        8
+    }
+    fn read(&mut self, thread_index: usize, offset: u32) -> Result<B32> {
+        match offset {
+            GPU_POSITION => Ok(B32(self.position)),
+            GPU_COLOR => Ok(self.display.lock().unwrap().pixel(self.position)),
+            GPU_PRESENT => Ok(B32(self.present_pending as u32)),
+            GPU_CONTROL => Ok(B32(self.control())),
+            GPU_RESOLUTION => {
+                let resolution = self.display.lock().unwrap().resolution();
+                Ok(B32(resolution.width | resolution.height << 16))
+            }
+            GPU_FRAME_COUNT => Ok(B32(self.frame_count)),
+            GPU_COMMAND_LIST => Ok(B32(self.list_status)),
+            _ => Ok(B32(0)),
+        }
+    }
+    fn write(&mut self, thread_index: usize, offset: u32, data: B32) -> Result<()> {
+        match offset {
+            GPU_COMMAND => {
+                self.call_gpu(data);
+                Ok(())
+            }
+            GPU_POSITION => {
+                self.position = data.0;
+                Ok(())
+            }
+            GPU_COLOR => {
+                self.display.lock().unwrap().draw(self.position, data.0);
+                Ok(())
+            }
+            GPU_PRESENT => {
+                self.present_pending = match data.0 {
+                    0 => false,
+                    1 => true,
+                    value => bail!("gpu present can only be 0 or 1, got: {value}"),
+                };
+                Ok(())
+            }
+            GPU_CONTROL => self.set_control(thread_index, data.0),
+            GPU_COMMAND_LIST => {
+                // neither list runs, so the failed status stays until the next write
+                if let Some(addr) = self.pending_list.take() {
+                    warn!(
+                        "gpu got command list at: {:#x} while list at: {addr:#x} was waiting, both are dropped",
+                        data.0
+                    );
+                    self.list_status = GPU_LIST_FAILED;
+                    return Ok(());
+                }
+                self.pending_list = Some(data.0);
+                self.list_status = GPU_LIST_BUSY;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+    fn tick(&mut self, memory: &Memory, raise: &mut dyn FnMut(usize, Interrupt)) {
+        if let Some(addr) = self.pending_list.take() {
+            self.run_list(memory, addr);
+        }
+        self.cycles_to_vblank -= 1;
+        if self.cycles_to_vblank == 0 {
+            self.cycles_to_vblank = VBLANK_CYCLES;
+            self.vblank(raise);
+        }
+    }
+    /// both buffers are cleared, palette is back to default, resolution stays
+    fn reset(&mut self) {
+        *self = Self::new(self.display.clone());
+        self.display.lock().unwrap().clear();
+    }
+    fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        writer.u32(self.last_command_part);
+        writer.u32(self.position);
+        writer.bool(self.double_buffered);
+        writer.bool(self.present_pending);
+        writer.u32(self.cycles_to_vblank);
+        writer.u32(self.frame_count);
+        writer.bool(self.vblank_thread.is_some());
+        writer.u32(self.vblank_thread.unwrap_or(0) as u32);
+        for color in self.palette {
+            writer.u32(color);
+        }
+        writer.bool(self.pending_list.is_some());
+        writer.u32(self.pending_list.unwrap_or(0));
+        writer.u32(self.list_status);
+        self.display.lock().unwrap().write_snapshot(writer);
+    }
+    fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()> {
+        self.last_command_part = reader.u32()?;
+        self.position = reader.u32()?;
+        self.double_buffered = reader.bool()?;
+        self.present_pending = reader.bool()?;
+        self.cycles_to_vblank = reader.u32()?;
+        if self.cycles_to_vblank == 0 || self.cycles_to_vblank > VBLANK_CYCLES {
+            bail!(
+                "snapshot has gpu with {} cycles to vblank, it has to be in 1..={VBLANK_CYCLES}",
+                self.cycles_to_vblank
+            );
+        }
+        self.frame_count = reader.u32()?;
+        let vblank_interrupt = reader.bool()?;
+        let thread_index = reader.u32()? as usize;
+        self.vblank_thread = vblank_interrupt.then_some(thread_index);
+        for color in &mut self.palette {
+            *color = reader.u32()?;
+        }
+        let list_pending = reader.bool()?;
+        let list_addr = reader.u32()?;
+        self.pending_list = list_pending.then_some(list_addr);
+        self.list_status = reader.u32()?;
+        self.display.lock().unwrap().read_snapshot(reader)
+    }
+}
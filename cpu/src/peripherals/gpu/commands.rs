@@ -0,0 +1,157 @@
+use anyhow::{Result, bail};
+
+use crate::{chips::b32::B32, memory_map::Memory, peripherals::gpu::Display};
+
+// Command list is a list of commands in physical memory, ended by `GPU_END`. Every command is a
+// header word (opcode in bits 0..8, flags above) and then its arguments. Coordinates are signed
+// and have to be in `COORDINATE_RANGE`, pixels outside of the frame are skipped.
+
+pub const GPU_END: u32 = 0;
+/// color: fills the whole back buffer
+pub const GPU_CLEAR: u32 = 1;
+/// x, y, width, height, color
+pub const GPU_FILL_RECT: u32 = 2;
+/// x0, y0, x1, y1, color: both ends are drawn
+pub const GPU_LINE: u32 = 3;
+/// address, x, y, width, height, color key: copies width * height words from memory at the
+/// address, row after row, to the rectangle at x, y
+pub const GPU_BLIT: u32 = 4;
+/// address, first, count: loads count colors from memory at the address into the palette, from
+/// entry first
+pub const GPU_SET_PALETTE: u32 = 5;
+
+/// colors of the command (and words of `GPU_BLIT`) are indexes into the palette
+pub const GPU_FLAG_PALETTE: u32 = 1 << 8;
+/// words of `GPU_BLIT` equal to its color key are not drawn, key is compared before the palette
+/// lookup
+pub const GPU_FLAG_COLOR_KEY: u32 = 1 << 9;
+
+pub const PALETTE_SIZE: usize = 256;
+pub const COORDINATE_RANGE: std::ops::RangeInclusive<i32> = -32768..=32767;
+
+/// reads the list word after word
+struct ListReader<'a> {
+    memory: &'a Memory,
+    addr: u32,
+}
+impl ListReader<'_> {
+    fn word(&mut self) -> u32 {
+        let word = self.memory.read(B32(self.addr)).0;
+        self.addr = self.addr.wrapping_add(1);
+        word
+    }
+    fn coordinate(&mut self) -> Result<i32> {
+        let coordinate = self.word() as i32;
+        if !COORDINATE_RANGE.contains(&coordinate) {
+            bail!("gpu coordinate: {coordinate} is outside of {COORDINATE_RANGE:?}");
+        }
+        Ok(coordinate)
+    }
+}
+
+/// runs the list at physical address addr, returns how many commands it had
+pub(super) fn run_list(
+    memory: &Memory,
+    display: &mut Display,
+    palette: &mut [u32; PALETTE_SIZE],
+    addr: u32,
+) -> Result<u32> {
+    let mut list = ListReader { memory, addr };
+    let mut command_count = 0;
+    loop {
+        let command_addr = list.addr;
+        let header = list.word();
+        let (opcode, flags) = (header & 0xFF, header & !0xFF);
+        let allowed_flags = match opcode {
+            GPU_END => return Ok(command_count),
+            GPU_BLIT => GPU_FLAG_PALETTE | GPU_FLAG_COLOR_KEY,
+            GPU_SET_PALETTE => 0,
+            _ => GPU_FLAG_PALETTE,
+        };
+        if flags & !allowed_flags != 0 {
+            bail!("gpu command: {header:#x} at: {command_addr:#x} has unknown flags");
+        }
+        let color = |palette: &[u32; PALETTE_SIZE], value: u32| -> Result<u32> {
+            if flags & GPU_FLAG_PALETTE == 0 {
+                return Ok(value);
+            }
+            match palette.get(value as usize) {
+                Some(color) => Ok(*color),
+                None => bail!("gpu palette index: {value} is past the last entry"),
+            }
+        };
+        match opcode {
+            GPU_CLEAR => display.fill(color(palette, list.word())?),
+            GPU_FILL_RECT => {
+                let (x, y) = (list.coordinate()?, list.coordinate()?);
+                let (width, height) = (list.coordinate()?, list.coordinate()?);
+                display.fill_rect(x, y, width, height, color(palette, list.word())?);
+            }
+            GPU_LINE => {
+                let start = (list.coordinate()?, list.coordinate()?);
+                let end = (list.coordinate()?, list.coordinate()?);
+                let color = color(palette, list.word())?;
+                draw_line(display, start, end, color);
+            }
+            GPU_BLIT => {
+                let source = list.word();
+                let (x, y) = (list.coordinate()?, list.coordinate()?);
+                let (width, height) = (list.coordinate()?, list.coordinate()?);
+                let color_key = list.word();
+                // only the part that is on the screen is read
+                let resolution = display.resolution();
+                let rows = (-y).max(0)..height.min(resolution.height as i32 - y);
+                let columns = (-x).max(0)..width.min(resolution.width as i32 - x);
+                for row in rows {
+                    for column in columns.clone() {
+                        let offset = (row * width + column) as u32;
+                        let word = memory.read(B32(source.wrapping_add(offset))).0;
+                        if flags & GPU_FLAG_COLOR_KEY != 0 && word == color_key {
+                            continue;
+                        }
+                        display.set_pixel(x + column, y + row, color(palette, word)?);
+                    }
+                }
+            }
+            GPU_SET_PALETTE => {
+                let source = list.word();
+                let (first, count) = (list.word() as usize, list.word() as usize);
+                if first.saturating_add(count) > PALETTE_SIZE {
+                    bail!(
+                        "gpu palette entries: {first}..{} are past the last entry",
+                        first.saturating_add(count)
+                    );
+                }
+                for (index, entry) in palette[first..first + count].iter_mut().enumerate() {
+                    *entry = memory.read(B32(source.wrapping_add(index as u32))).0;
+                }
+            }
+            _ => bail!("gpu command: {header:#x} at: {command_addr:#x} has unknown opcode"),
+        }
+        command_count += 1;
+    }
+}
+
+/// bresenham
+fn draw_line(display: &mut Display, (mut x, mut y): (i32, i32), (x1, y1): (i32, i32), color: u32) {
+    let dx = (x1 - x).abs();
+    let dy = -(y1 - y).abs();
+    let step_x = if x < x1 { 1 } else { -1 };
+    let step_y = if y < y1 { 1 } else { -1 };
+    let mut error = dx + dy;
+    loop {
+        display.set_pixel(x, y, color);
+        if x == x1 && y == y1 {
+            return;
+        }
+        let doubled_error = 2 * error;
+        if doubled_error >= dy {
+            error += dy;
+            x += step_x;
+        }
+        if doubled_error <= dx {
+            error += dx;
+            y += step_y;
+        }
+    }
+}
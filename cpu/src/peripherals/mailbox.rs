@@ -0,0 +1,189 @@
+use std::collections::VecDeque;
+
+use anyhow::{Result, bail};
+use log::warn;
+
+use crate::{
+    chips::{
+        b32::B32,
+        thread::{Interrupt, InterruptKind},
+    },
+    memory_map::Memory,
+    peripherals::Peripheral,
+    snapshot::{SnapshotReader, SnapshotWriter},
+};
+
+/// reading takes the oldest message of the thread's mailbox or `MAILBOX_EMPTY`, writing sets the
+/// message the next doorbell sends. `Phrp` writes here.
+pub const MAILBOX_DATA: u32 = 0;
+/// reading returns how many messages wait in the thread's mailbox
+pub const MAILBOX_STATUS: u32 = 1;
+/// writing index of a thread sends the message to its mailbox and rings it, reading returns
+/// `MAILBOX_SENT`, `MAILBOX_FULL` or `MAILBOX_NO_THREAD` for the last doorbell of the thread
+pub const MAILBOX_DOORBELL: u32 = 2;
+/// writing 1 enables the mailbox interrupt for the thread that wrote it, 0 disables it
+pub const MAILBOX_CONTROL: u32 = 3;
+pub const MAILBOX_EMPTY: u32 = u32::MAX;
+
+pub const MAILBOX_SENT: u32 = 0;
+/// the message stays set, so the same doorbell can be written again once the receiver read some
+pub const MAILBOX_FULL: u32 = 1;
+pub const MAILBOX_NO_THREAD: u32 = 2;
+
+/// how many messages wait in one mailbox before doorbells to it return `MAILBOX_FULL`
+pub const MAILBOX_LEN: usize = 64;
+/// threads with higher index don't get a mailbox, so the guest can't make the host allocate
+/// without limit
+pub const MAILBOX_MAX_THREADS: usize = 256;
+
+#[derive(Clone, Debug, Default)]
+struct ThreadMailbox {
+    messages: VecDeque<u32>,
+    /// what the next doorbell of this thread sends
+    outgoing: u32,
+    doorbell_status: u32,
+    interrupt_enabled: bool,
+    /// got a message since the last tick
+    rung: bool,
+}
+
+/// Every thread has its own mailbox, messages sent to it wait there in order in which they came
+/// until the thread reads them. Sending sets the message and then writes index of the receiver
+/// to the doorbell. A receiver that enabled the interrupt gets mailbox interrupt with the number
+/// of waiting messages as data.
+pub struct Mailbox {
+    mailboxes: Vec<ThreadMailbox>,
+}
+impl Mailbox {
+    pub fn new() -> Self {
+        Self {
+            mailboxes: Vec::new(),
+        }
+    }
+    fn mailbox(&mut self, thread_index: usize) -> Option<&mut ThreadMailbox> {
+        if thread_index >= MAILBOX_MAX_THREADS {
+            return None;
+        }
+        if self.mailboxes.len() <= thread_index {
+            self.mailboxes
+                .resize(thread_index + 1, ThreadMailbox::default());
+        }
+        Some(&mut self.mailboxes[thread_index])
+    }
+    fn ring(&mut self, thread_index: usize, receiver: u32) -> u32 {
+        let Some(message) = self.mailbox(thread_index).map(|mailbox| mailbox.outgoing) else {
+            return MAILBOX_NO_THREAD;
+        };
+        let Some(receiver) = self.mailbox(receiver as usize) else {
+            return MAILBOX_NO_THREAD;
+        };
+        if receiver.messages.len() >= MAILBOX_LEN {
+            return MAILBOX_FULL;
+        }
+        receiver.messages.push_back(message);
+        receiver.rung = true;
+        MAILBOX_SENT
+    }
+}
+impl Peripheral for Mailbox {
+    fn name(&self) -> &str {
+        "mailbox"
+    }
+    fn size(&self) -> u32 {
+        4
+    }
+    fn read(&mut self, thread_index: usize, offset: u32) -> Result<B32> {
+        let Some(mailbox) = self.mailbox(thread_index) else {
+            return Ok(B32(match offset {
+                MAILBOX_DATA => MAILBOX_EMPTY,
+                MAILBOX_DOORBELL => MAILBOX_NO_THREAD,
+                _ => 0,
+            }));
+        };
+        Ok(B32(match offset {
+            MAILBOX_DATA => mailbox.messages.pop_front().unwrap_or(MAILBOX_EMPTY),
+            MAILBOX_STATUS => mailbox.messages.len() as u32,
+            MAILBOX_DOORBELL => mailbox.doorbell_status,
+            _ => mailbox.interrupt_enabled as u32,
+        }))
+    }
+    fn write(&mut self, thread_index: usize, offset: u32, data: B32) -> Result<()> {
+        match offset {
+            MAILBOX_DATA => {
+                if let Some(mailbox) = self.mailbox(thread_index) {
+                    mailbox.outgoing = data.0;
+                }
+            }
+            MAILBOX_STATUS => {}
+            MAILBOX_DOORBELL => {
+                let status = self.ring(thread_index, data.0);
+                if let Some(mailbox) = self.mailbox(thread_index) {
+                    mailbox.doorbell_status = status;
+                }
+            }
+            _ => match (self.mailbox(thread_index), data.0) {
+                (Some(mailbox), value @ (0 | 1)) => mailbox.interrupt_enabled = value == 1,
+                (_, value) => warn!(
+                    "mailbox control of thread: {thread_index} can only be 0 or 1, got: {value}"
+                ),
+            },
+        }
+        Ok(())
+    }
+    fn tick(&mut self, memory: &Memory, raise: &mut dyn FnMut(usize, Interrupt)) {
+        for (thread_index, mailbox) in self.mailboxes.iter_mut().enumerate() {
+            if !std::mem::take(&mut mailbox.rung) || !mailbox.interrupt_enabled {
+                continue;
+            }
+            raise(
+                thread_index,
+                Interrupt {
+                    kind: InterruptKind::Mailbox,
+                    data: mailbox.messages.len() as u32,
+                },
+            );
+        }
+    }
+    fn reset(&mut self) {
+        self.mailboxes.clear();
+    }
+    fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        writer.u32(self.mailboxes.len() as u32);
+        for mailbox in &self.mailboxes {
+            writer.u32(mailbox.messages.len() as u32);
+            for message in &mailbox.messages {
+                writer.u32(*message);
+            }
+            writer.u32(mailbox.outgoing);
+            writer.u32(mailbox.doorbell_status);
+            writer.bool(mailbox.interrupt_enabled);
+            writer.bool(mailbox.rung);
+        }
+    }
+    fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()> {
+        let mailbox_count = reader.u32()? as usize;
+        if mailbox_count > MAILBOX_MAX_THREADS {
+            bail!("snapshot has {mailbox_count} mailboxes, at most {MAILBOX_MAX_THREADS} fit");
+        }
+        self.mailboxes.clear();
+        for _ in 0..mailbox_count {
+            let message_count = reader.u32()? as usize;
+            if message_count > MAILBOX_LEN {
+                bail!(
+                    "mailbox in snapshot has {message_count} messages, at most {MAILBOX_LEN} fit"
+                );
+            }
+            let messages = (0..message_count)
+                .map(|_| reader.u32())
+                .collect::<Result<_>>()?;
+            self.mailboxes.push(ThreadMailbox {
+                messages,
+                outgoing: reader.u32()?,
+                doorbell_status: reader.u32()?,
+                interrupt_enabled: reader.bool()?,
+                rung: reader.bool()?,
+            });
+        }
+        Ok(())
+    }
+}
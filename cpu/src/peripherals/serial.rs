@@ -1,37 +1,209 @@
-use clap::builder::Str;
-use log::info;
+pub mod socket;
+
+use std::{
+    collections::VecDeque,
+    io::{Read, Write},
+    path::PathBuf,
+    str::FromStr,
+    sync::mpsc::{self, Receiver, Sender},
+};
+
+use anyhow::{Context, Result, bail};
+use log::{info, warn};
 
 use crate::{
-    MEMORY,
-    chips::{b8::B8, b32::B32},
+    chips::{
+        b32::B32,
+        thread::{Interrupt, InterruptKind},
+    },
+    memory_map::Memory,
+    peripherals::Peripheral,
+    snapshot::{SnapshotReader, SnapshotWriter},
 };
+
+/// writing sends 4 bytes of the word (zero bytes are skipped), reading takes the oldest received
+/// byte or `SERIAL_EMPTY`. `Phrp` writes here.
+pub const SERIAL_DATA: u32 = 0;
+/// reading returns how many received bytes are waiting
+pub const SERIAL_STATUS: u32 = 1;
+/// writing 1 enables the serial input interrupt for the thread that wrote it, 0 disables it
+pub const SERIAL_CONTROL: u32 = 2;
+pub const SERIAL_EMPTY: u32 = u32::MAX;
+
+/// where a serial port sends its bytes and gets them from
+#[derive(Clone, Debug, PartialEq)]
+pub enum SerialBackend {
+    /// output goes to stdout, input has to be attached separately
+    Stdout,
+    /// listens on this localhost port, `nc localhost PORT` connects to the serial
+    Tcp(u16),
+    /// listens on unix socket at this path
+    Unix(PathBuf),
+}
+/// `stdout`, `tcp:PORT` or `unix:PATH`
+impl FromStr for SerialBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        match text.split_once(':') {
+            None if text == "stdout" => Ok(SerialBackend::Stdout),
+            Some(("tcp", port)) => Ok(SerialBackend::Tcp(
+                port.parse()
+                    .with_context(|| format!("serial tcp port: '{port}'"))?,
+            )),
+            Some(("unix", path)) if !path.is_empty() => Ok(SerialBackend::Unix(path.into())),
+            _ => bail!("serial backend: '{text}' should be one of: stdout, tcp:PORT, unix:PATH"),
+        }
+    }
+}
+
+/// Bidirectional serial port. Sent bytes go straight to the output (stdout by default), received
+/// bytes wait in a fifo until the program reads them. When new bytes arrive the thread that
+/// enabled the interrupt gets serial input interrupt with the number of waiting bytes as data.
 pub struct Serial {
-    pub text: Vec<u8>,
+    name: String,
+    received: VecDeque<u8>,
+    input: Receiver<Vec<u8>>,
+    input_sender: Sender<Vec<u8>>,
+    output: Box<dyn Write + Send>,
+    interrupt_thread: Option<usize>,
+    /// set while the serial listens on a socket, dropping it stops the listener
+    stop_listening: Option<socket::StopListening>,
 }
-/// writes 4 bytes of data into serial
 impl Serial {
-    pub fn call_serial(&mut self, data: B32) {
-        for i in 0..4 {
-            let value = data.byte(i);
-            if value == B8(b'\n' as u8) {
-                self.write_serial();
-                continue;
+    pub fn new() -> Self {
+        Self::named("serial")
+    }
+    /// serial ports after the first one need their own names, eg. `serial1`
+    pub fn named(name: &str) -> Self {
+        let (input_sender, input) = mpsc::channel();
+        Self {
+            name: name.to_string(),
+            received: VecDeque::new(),
+            input,
+            input_sender,
+            output: Box::new(std::io::stdout()),
+            interrupt_thread: None,
+            stop_listening: None,
+        }
+    }
+    /// bytes sent thru it are received by the serial on the next cycle
+    pub fn input_sender(&self) -> Sender<Vec<u8>> {
+        self.input_sender.clone()
+    }
+    /// reads the reader on its own host thread until it ends, eg. stdin or a file
+    pub fn attach_input(&self, reader: impl Read + Send + 'static) {
+        spawn_reader(reader, self.input_sender());
+    }
+    pub fn set_output(&mut self, output: Box<dyn Write + Send>) {
+        self.output = output;
+    }
+    pub fn connect(&mut self, backend: &SerialBackend) -> Result<()> {
+        match backend {
+            SerialBackend::Stdout => self.set_output(Box::new(std::io::stdout())),
+            SerialBackend::Tcp(port) => {
+                self.listen_tcp(*port)?;
+            }
+            SerialBackend::Unix(path) => self.listen_unix(path)?,
+        }
+        Ok(())
+    }
+    fn send(&mut self, data: B32) -> Result<()> {
+        let bytes: Vec<u8> = data
+            .0
+            .to_le_bytes()
+            .into_iter()
+            .filter(|byte| *byte != 0)
+            .collect();
+        self.output
+            .write_all(&bytes)
+            .and_then(|_| self.output.flush())
+            .context("could not write serial output")
+    }
+}
+/// sends what the reader reads to the serial from its own host thread, until the reader ends
+fn spawn_reader(mut reader: impl Read + Send + 'static, sender: Sender<Vec<u8>>) {
+    std::thread::spawn(move || {
+        let mut buf = [0; 1024];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(len) => {
+                    if sender.send(buf[..len].to_vec()).is_err() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    warn!("serial input stopped: {err}");
+                    break;
+                }
             }
-            self.text.push(value.0 as u8);
         }
+    });
+}
+impl Peripheral for Serial {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn size(&self) -> u32 {
+        3
     }
-    fn write_serial(&mut self) {
-        let string = match String::from_utf8(self.text.clone()) {
-            Ok(val) => val,
-            Err(_) => {
-                info!(
-                    "text: {:?} send thru serial was not a valid utf-8!",
-                    self.text
-                );
-                String::new()
+    fn read(&mut self, thread_index: usize, offset: u32) -> Result<B32> {
+        Ok(B32(match offset {
+            SERIAL_DATA => self
+                .received
+                .pop_front()
+                .map_or(SERIAL_EMPTY, |byte| byte as u32),
+            SERIAL_STATUS => self.received.len() as u32,
+            _ => self.interrupt_thread.is_some() as u32,
+        }))
+    }
+    fn write(&mut self, thread_index: usize, offset: u32, data: B32) -> Result<()> {
+        match offset {
+            SERIAL_DATA => self.send(data),
+            SERIAL_STATUS => Ok(()),
+            _ => {
+                self.interrupt_thread = match data.0 {
+                    0 => None,
+                    1 => Some(thread_index),
+                    value => bail!("serial control can only be 0 or 1, got: {value}"),
+                };
+                Ok(())
             }
-        };
-        self.text.clear();
-        info!("SERIAL: {string}");
+        }
+    }
+    fn tick(&mut self, memory: &Memory, raise: &mut dyn FnMut(usize, Interrupt)) {
+        let mut arrived = false;
+        while let Ok(bytes) = self.input.try_recv() {
+            info!("serial received {} bytes", bytes.len());
+            arrived |= !bytes.is_empty();
+            self.received.extend(bytes);
+        }
+        if arrived && let Some(thread_index) = self.interrupt_thread {
+            raise(
+                thread_index,
+                Interrupt {
+                    kind: InterruptKind::SerialInput,
+                    data: self.received.len() as u32,
+                },
+            );
+        }
+    }
+    /// input and output stay connected, bytes waiting in the fifo are dropped
+    fn reset(&mut self) {
+        self.received.clear();
+        self.interrupt_thread = None;
+    }
+    fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        writer.bytes(&self.received.iter().copied().collect::<Vec<u8>>());
+        writer.bool(self.interrupt_thread.is_some());
+        writer.u32(self.interrupt_thread.unwrap_or(0) as u32);
+    }
+    fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()> {
+        self.received = reader.bytes()?.iter().copied().collect();
+        let enabled = reader.bool()?;
+        let thread_index = reader.u32()? as usize;
+        self.interrupt_thread = enabled.then_some(thread_index);
+        Ok(())
     }
 }
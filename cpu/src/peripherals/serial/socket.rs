@@ -0,0 +1,229 @@
+use std::{
+    io::{self, Read, Write},
+    net::{Shutdown, SocketAddr, TcpListener, TcpStream},
+    path::Path,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Sender, SyncSender, TrySendError},
+    },
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+
+use super::{Serial, spawn_reader};
+
+/// how many writes can wait for a client before its output is dropped
+const CLIENT_BACKLOG: usize = 1024;
+/// how often the listener looks for new clients and whether it should stop
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(20);
+/// longest wait after accept fails, every failure in a row doubles the wait up to it
+const MAX_ACCEPT_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Stops the listener of a serial when dropped. Its host thread stops accepting and disconnects
+/// every client, so their reader and writer threads end too.
+pub(super) struct StopListening(Arc<AtomicBool>);
+impl Drop for StopListening {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// client connected to a serial, the stream is kept to disconnect it
+struct Client {
+    sender: SyncSender<Vec<u8>>,
+    stream: Box<dyn Stream>,
+}
+
+/// Output of a serial that listens on a socket. Bytes go to every connected client, ones sent
+/// while nobody is connected are dropped like on a real line. Every client is written from its
+/// own host thread, so one that stops reading loses its output instead of stopping the machine.
+/// Clients that fail are disconnected.
+#[derive(Clone, Default)]
+struct Clients(Arc<Mutex<Vec<Client>>>);
+impl Clients {
+    /// writer thread of a client ends when it fails or when the client is removed
+    fn add(&self, name: String, peer: String, stream: impl Stream) -> io::Result<()> {
+        let mut writer = stream.try_clone()?;
+        let (sender, receiver) = mpsc::sync_channel::<Vec<u8>>(CLIENT_BACKLOG);
+        std::thread::spawn(move || {
+            for bytes in receiver {
+                if let Err(err) = writer.write_all(&bytes).and_then(|_| writer.flush()) {
+                    info!("serial: {name} disconnected from {peer}: {err}");
+                    break;
+                }
+            }
+            writer.shutdown();
+        });
+        self.0.lock().unwrap().push(Client {
+            sender,
+            stream: Box::new(stream),
+        });
+        Ok(())
+    }
+    fn disconnect_all(&self) {
+        for client in self.0.lock().unwrap().drain(..) {
+            client.stream.shutdown();
+        }
+    }
+}
+impl Write for Clients {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .lock()
+            .unwrap()
+            .retain(|client| match client.sender.try_send(buf.to_vec()) {
+                Ok(()) | Err(TrySendError::Full(_)) => true,
+                Err(TrySendError::Disconnected(_)) => false,
+            });
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// connection to a client, cloned for its reader and writer thread
+trait Stream: Read + Write + Send + 'static {
+    fn try_clone(&self) -> io::Result<Self>
+    where
+        Self: Sized;
+    /// ends both directions, reads of every clone return 0 and writes fail
+    fn shutdown(&self);
+}
+impl Stream for TcpStream {
+    fn try_clone(&self) -> io::Result<Self> {
+        TcpStream::try_clone(self)
+    }
+    fn shutdown(&self) {
+        let _ = TcpStream::shutdown(self, Shutdown::Both);
+    }
+}
+#[cfg(unix)]
+impl Stream for std::os::unix::net::UnixStream {
+    fn try_clone(&self) -> io::Result<Self> {
+        std::os::unix::net::UnixStream::try_clone(self)
+    }
+    fn shutdown(&self) {
+        let _ = std::os::unix::net::UnixStream::shutdown(self, Shutdown::Both);
+    }
+}
+
+/// socket listener a serial can take clients from, it doesn't block so it can be stopped
+trait Listener: Send + 'static {
+    type Stream: Stream;
+    /// next waiting client and who it is, `WouldBlock` when there is none. The stream blocks.
+    fn accept_client(&self) -> io::Result<(Self::Stream, String)>;
+}
+impl Listener for TcpListener {
+    type Stream = TcpStream;
+    fn accept_client(&self) -> io::Result<(TcpStream, String)> {
+        let (stream, peer) = self.accept()?;
+        stream.set_nonblocking(false)?;
+        let _ = stream.set_nodelay(true);
+        Ok((stream, peer.to_string()))
+    }
+}
+#[cfg(unix)]
+impl Listener for std::os::unix::net::UnixListener {
+    type Stream = std::os::unix::net::UnixStream;
+    fn accept_client(&self) -> io::Result<(Self::Stream, String)> {
+        let (stream, _) = self.accept()?;
+        stream.set_nonblocking(false)?;
+        Ok((stream, "unix socket".to_string()))
+    }
+}
+
+impl Serial {
+    /// Accepts connections on localhost port from its own host thread, port 0 picks a free one.
+    /// Every client gets the output and what it sends is received by the serial.
+    pub fn listen_tcp(&mut self, port: u16) -> Result<SocketAddr> {
+        let listener = TcpListener::bind(("127.0.0.1", port))
+            .with_context(|| format!("serial: {} could not listen on port: {port}", self.name))?;
+        let addr = listener.local_addr()?;
+        listener.set_nonblocking(true)?;
+        info!("serial: {} listens on {addr}", self.name);
+        self.accept_clients(listener);
+        Ok(addr)
+    }
+    /// same as `listen_tcp`, but on unix socket at path. Socket left there by earlier run is
+    /// replaced.
+    #[cfg(unix)]
+    pub fn listen_unix(&mut self, path: &Path) -> Result<()> {
+        use std::os::unix::{fs::FileTypeExt, net::UnixListener};
+
+        if let Ok(metadata) = std::fs::symlink_metadata(path)
+            && metadata.file_type().is_socket()
+        {
+            std::fs::remove_file(path)?;
+        }
+        let listener = UnixListener::bind(path).with_context(|| {
+            format!(
+                "serial: {} could not listen on unix socket: {}",
+                self.name,
+                path.display()
+            )
+        })?;
+        listener.set_nonblocking(true)?;
+        info!("serial: {} listens on {}", self.name, path.display());
+        self.accept_clients(listener);
+        Ok(())
+    }
+    #[cfg(not(unix))]
+    pub fn listen_unix(&mut self, path: &Path) -> Result<()> {
+        anyhow::bail!("unix sockets are not supported on this platform");
+    }
+    /// Makes the serial output go to clients of the listener, accepted from its own host thread.
+    /// Listener the serial had before stops. Failing accepts are retried later and later, so a
+    /// listener that keeps failing doesn't flood the log.
+    fn accept_clients(&mut self, listener: impl Listener) {
+        let clients = Clients::default();
+        self.set_output(Box::new(clients.clone()));
+        let stop = Arc::new(AtomicBool::new(false));
+        self.stop_listening = Some(StopListening(stop.clone()));
+        let sender = self.input_sender();
+        let name = self.name.clone();
+        std::thread::spawn(move || {
+            let mut backoff = ACCEPT_INTERVAL;
+            while !stop.load(Ordering::Relaxed) {
+                let (stream, peer) = match listener.accept_client() {
+                    Ok(client) => client,
+                    Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                        std::thread::sleep(ACCEPT_INTERVAL);
+                        continue;
+                    }
+                    Err(err) => {
+                        warn!(
+                            "serial: {name} could not accept connection: {err}, trying again in {backoff:?}"
+                        );
+                        std::thread::sleep(backoff);
+                        backoff = (backoff * 2).min(MAX_ACCEPT_BACKOFF);
+                        continue;
+                    }
+                };
+                backoff = ACCEPT_INTERVAL;
+                info!("serial: {name} connected to {peer}");
+                if let Err(err) = connect(&clients, &name, peer, stream, &sender) {
+                    warn!("serial: {name} could not connect client: {err}");
+                }
+            }
+            info!("serial: {name} stopped listening");
+            clients.disconnect_all();
+        });
+    }
+}
+/// output to bytes the client sends has to reach it, so the writer is added before the reader
+fn connect(
+    clients: &Clients,
+    name: &str,
+    peer: String,
+    stream: impl Stream,
+    sender: &Sender<Vec<u8>>,
+) -> io::Result<()> {
+    let reader = stream.try_clone()?;
+    clients.add(name.to_string(), peer, stream)?;
+    spawn_reader(reader, sender.clone());
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use anyhow::{Result, bail};
+use log::warn;
+
+use crate::{
+    chips::{
+        b32::B32,
+        thread::{Interrupt, InterruptKind},
+    },
+    memory_map::Memory,
+    peripherals::Peripheral,
+    snapshot::{SnapshotReader, SnapshotWriter},
+};
+
+/// highest 4 bits of the data send to the timer select the command, the rest is the interval
+const COMMAND_SHIFT: u32 = 28;
+pub const TIMER_MAX_INTERVAL: u32 = (1 << COMMAND_SHIFT) - 1;
+
+pub const TIMER_STOP: u32 = 0;
+pub const TIMER_ONE_SHOT: u32 = 1;
+pub const TIMER_PERIODIC: u32 = 2;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum TimerMode {
+    #[default]
+    Stopped,
+    OneShot,
+    Periodic,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct TimerChannel {
+    mode: TimerMode,
+    /// in cycles of the virtual clock
+    interval: u32,
+    cycles_left: u32,
+    /// how many times the channel fired, it's the data of the timer interrupt
+    ticks: u32,
+}
+
+/// Every thread has its own channel, `Phrp` programs the channel of the thread that runs it.
+/// Reading returns tick count of the channel.
+pub struct Timer {
+    channels: Vec<TimerChannel>,
+}
+impl Timer {
+    pub fn new() -> Self {
+        Self {
+            channels: Vec::new(),
+        }
+    }
+    /// unknown command or zero interval is a bug of the guest, it's logged and ignored
+    pub fn call_timer(&mut self, thread_index: usize, data: B32) {
+        let command = data.0 >> COMMAND_SHIFT;
+        let interval = data.0 & TIMER_MAX_INTERVAL;
+        if self.channels.len() <= thread_index {
+            self.channels
+                .resize(thread_index + 1, TimerChannel::default());
+        }
+        let channel = &mut self.channels[thread_index];
+
+        let mode = match command {
+            TIMER_STOP => {
+                channel.mode = TimerMode::Stopped;
+                return;
+            }
+            TIMER_ONE_SHOT => TimerMode::OneShot,
+            TIMER_PERIODIC => TimerMode::Periodic,
+            command => {
+                warn!("timer of thread: {thread_index} has no command: {command}");
+                return;
+            }
+        };
+        if interval == 0 {
+            warn!("timer interval of thread: {thread_index} has to be at least 1 cycle");
+            return;
+        }
+        *channel = TimerChannel {
+            mode,
+            interval,
+            cycles_left: interval,
+            ticks: channel.ticks,
+        };
+    }
+}
+impl Peripheral for Timer {
+    fn name(&self) -> &str {
+        "timer"
+    }
+    fn read(&mut self, thread_index: usize, offset: u32) -> Result<B32> {
+        Ok(B32(self
+            .channels
+            .get(thread_index)
+            .map_or(0, |channel| channel.ticks)))
+    }
+    fn write(&mut self, thread_index: usize, offset: u32, data: B32) -> Result<()> {
+        self.call_timer(thread_index, data);
+        Ok(())
+    }
+    /// interrupt data is tick count of the channel
+    fn tick(&mut self, memory: &Memory, raise: &mut dyn FnMut(usize, Interrupt)) {
+        for (thread_index, channel) in self.channels.iter_mut().enumerate() {
+            if channel.mode == TimerMode::Stopped {
+                continue;
+            }
+            channel.cycles_left -= 1;
+            if channel.cycles_left != 0 {
+                continue;
+            }
+            channel.ticks = channel.ticks.wrapping_add(1);
+            match channel.mode {
+                TimerMode::Periodic => channel.cycles_left = channel.interval,
+                _ => channel.mode = TimerMode::Stopped,
+            }
+            raise(
+                thread_index,
+                Interrupt {
+                    kind: InterruptKind::Timer,
+                    data: channel.ticks,
+                },
+            );
+        }
+    }
+    fn reset(&mut self) {
+        self.channels.clear();
+    }
+    fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        writer.u32(self.channels.len() as u32);
+        for channel in &self.channels {
+            writer.u32(match channel.mode {
+                TimerMode::Stopped => TIMER_STOP,
+                TimerMode::OneShot => TIMER_ONE_SHOT,
+                TimerMode::Periodic => TIMER_PERIODIC,
+            });
+            writer.u32(channel.interval);
+            writer.u32(channel.cycles_left);
+            writer.u32(channel.ticks);
+        }
+    }
+    fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()> {
+        let channel_count = reader.u32()?;
+        self.channels.clear();
+        for _ in 0..channel_count {
+            let mode = match reader.u32()? {
+                TIMER_STOP => TimerMode::Stopped,
+                TIMER_ONE_SHOT => TimerMode::OneShot,
+                TIMER_PERIODIC => TimerMode::Periodic,
+                mode => bail!("invalid timer mode in snapshot: {mode}"),
+            };
+            let channel = TimerChannel {
+                mode,
+                interval: reader.u32()?,
+                cycles_left: reader.u32()?,
+                ticks: reader.u32()?,
+            };
+            if channel.mode != TimerMode::Stopped && channel.cycles_left == 0 {
+                bail!("running timer channel in snapshot has no cycles left");
+            }
+            self.channels.push(channel);
+        }
+        Ok(())
+    }
+}
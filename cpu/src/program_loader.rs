@@ -1,14 +1,14 @@
 use crate::{
-    MEMORY,
-    chips::{b8::B8, b32::B32, memory},
+    chips::{b8::B8, b32::B32},
+    memory_map::Memory,
 };
 
 use anyhow::Result;
-pub async fn load_program_into_memory_from_file(path: &str, load_base_addr: B32) -> Result<()> {
-    let memory = MEMORY
-        .get()
-        .expect("you need to initialize memory before loading programs into it!");
-
+pub async fn load_program_into_memory_from_file(
+    memory: &Memory,
+    path: &str,
+    load_base_addr: B32,
+) -> Result<()> {
     let file_contents_u8 = tokio::fs::read(path).await?;
 
     // iterate thru all 32 byte segments
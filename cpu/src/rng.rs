@@ -0,0 +1,39 @@
+use std::sync::atomic::AtomicU64;
+
+const ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::Relaxed;
+
+/// seed used when none is given, so runs without `--seed` are still the same every time
+pub const DEFAULT_SEED: u64 = 0;
+
+/// splitmix64 generator shared by all threads of a machine. Threads run in fixed order, so the
+/// same seed always gives the same numbers to the same instructions.
+pub struct Rng {
+    state: AtomicU64,
+}
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+    /// whole state of the generator, restoring it with `new` continues the same sequence
+    pub fn state(&self) -> u64 {
+        self.state.load(ORDERING)
+    }
+    pub fn next_u64(&self) -> u64 {
+        let mut value = self
+            .state
+            .fetch_add(0x9E37_79B9_7F4A_7C15, ORDERING)
+            .wrapping_add(0x9E37_79B9_7F4A_7C15);
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+    /// number from min to max, both included. Bounds are swapped if min is bigger.
+    pub fn range(&self, min: u32, max: u32) -> u32 {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        let span = (max - min) as u64 + 1;
+        // high bits of the multiplication spread the number over the span without modulo
+        min + ((self.next_u64() as u128 * span as u128) >> 64) as u32
+    }
+}
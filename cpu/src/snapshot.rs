@@ -0,0 +1,161 @@
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use log::info;
+
+use crate::{machine::Machine, peripherals::Peripherals};
+
+/// first bytes of every snapshot file
+pub const SNAPSHOT_MAGIC: &[u8; 8] = b"TCSNAPSH";
+/// bump when layout of the snapshot changes, old snapshots are then rejected
+pub const SNAPSHOT_VERSION: u32 = 11;
+
+// layout of version 11, all numbers are little endian:
+// magic, version: u32
+// machine: cycle: u64, rng state: u64
+// memory: region count: u32, (kind: u32, start: u32, size: u32) for every region,
+//     then words of every ram and rom region
+// threads: count: u32, then for every thread
+//     stack_base_addr: u32, stack_size: u32, 256 registers, is_halting: u8, sleep_cycles_left: u32,
+//     interrupts_enabled: u8, idt base: u32, first context register: u32,
+//     context register count: u32, interrupt mask: u32, interrupts in service: u32,
+//     dropped interrupts: u32, page_table_addr: u32, paging_enabled: u8,
+//     queued interrupt count: u32, (kind: u32, data: u32) for every interrupt
+// peripherals: count: u32, then for every peripheral
+//     index: u32, name len: u32, name, state len: u32, state written by the peripheral
+// serial state: received len: u32, received bytes, interrupt enabled: u8, interrupt thread: u32
+// gpu state: last command part: u32, position: u32, double buffered: u8, present pending: u8,
+//     cycles to vblank: u32, frame count: u32, vblank interrupt enabled: u8,
+//     vblank interrupt thread: u32, 256 palette colors: u32, command list pending: u8,
+//     command list addr: u32, command list status: u32, width: u32, height: u32,
+//     back buffer len: u32, back buffer, front buffer len: u32, front buffer
+// timer state: channel count: u32, (mode: u32, interval: u32, cycles left: u32, ticks: u32)
+//     for every channel
+// console state: cells len: u32, (character: u8, attribute: u8) for every cell, cursor: u32,
+//     attribute: u32, cursor visible: u8
+// framebuffer state: empty, its pixels are in gpu state
+// mailbox state: mailbox count: u32, then for every mailbox
+//     message count: u32, messages: u32, outgoing message: u32, doorbell status: u32,
+//     interrupt enabled: u8, rung: u8
+// mmio devices: count: u32, (name len: u32, name, start: u32) for every device
+
+pub struct SnapshotWriter {
+    data: Vec<u8>,
+}
+impl SnapshotWriter {
+    pub fn new() -> Self {
+        let mut writer = Self { data: Vec::new() };
+        writer.data.extend_from_slice(SNAPSHOT_MAGIC);
+        writer.u32(SNAPSHOT_VERSION);
+        writer
+    }
+    pub fn u32(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+    pub fn u64(&mut self, value: u64) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+    pub fn bool(&mut self, value: bool) {
+        self.data.push(value as u8);
+    }
+    /// length first, then the bytes
+    pub fn bytes(&mut self, bytes: &[u8]) {
+        self.u32(bytes.len() as u32);
+        self.data.extend_from_slice(bytes);
+    }
+    /// what `write` writes, with its length in front so a reader can skip it
+    pub fn section(&mut self, write: impl FnOnce(&mut SnapshotWriter)) {
+        let mut section = SnapshotWriter { data: Vec::new() };
+        write(&mut section);
+        self.bytes(&section.data);
+    }
+    pub fn finish(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+pub struct SnapshotReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+impl<'a> SnapshotReader<'a> {
+    /// checks magic and version
+    pub fn new(data: &'a [u8]) -> Result<Self> {
+        let mut reader = Self { data, pos: 0 };
+        if reader.take(SNAPSHOT_MAGIC.len())? != SNAPSHOT_MAGIC {
+            bail!("data is not a snapshot");
+        }
+        let version = reader.u32()?;
+        if version != SNAPSHOT_VERSION {
+            bail!("snapshot has version: {version}, only version: {SNAPSHOT_VERSION} is supported");
+        }
+        Ok(reader)
+    }
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + len)
+            .context("snapshot ended too early")?;
+        self.pos += len;
+        Ok(bytes)
+    }
+    pub fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+    pub fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+    pub fn bool(&mut self) -> Result<bool> {
+        match self.take(1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => bail!("invalid bool in snapshot: {value}"),
+        }
+    }
+    pub fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+    /// reader of data written by `SnapshotWriter::section`
+    pub fn section(&mut self) -> Result<SnapshotReader<'a>> {
+        Ok(SnapshotReader {
+            data: self.bytes()?,
+            pos: 0,
+        })
+    }
+    /// errors if there is data left, snapshot was written by something else
+    pub fn finish(self) -> Result<()> {
+        if self.pos != self.data.len() {
+            bail!("snapshot has {} unread bytes", self.data.len() - self.pos);
+        }
+        Ok(())
+    }
+}
+
+impl Machine {
+    pub fn save_snapshot(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.snapshot())
+            .with_context(|| format!("could not write snapshot: {}", path.display()))?;
+        info!(
+            "saved snapshot at cycle {} to: {}",
+            self.cycle(),
+            path.display()
+        );
+        Ok(())
+    }
+    pub fn load_snapshot(path: &Path) -> Result<Self> {
+        Self::load_snapshot_with_peripherals(path, Peripherals::new())
+    }
+    pub fn load_snapshot_with_peripherals(path: &Path, peripherals: Peripherals) -> Result<Self> {
+        let data = std::fs::read(path)
+            .with_context(|| format!("could not read snapshot: {}", path.display()))?;
+        let machine = Self::from_snapshot_with_peripherals(&data, peripherals)
+            .with_context(|| format!("invalid snapshot: {}", path.display()))?;
+        info!(
+            "loaded snapshot at cycle {} from: {}",
+            machine.cycle(),
+            path.display()
+        );
+        Ok(machine)
+    }
+}
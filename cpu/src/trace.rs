@@ -0,0 +1,242 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{BufReader, BufWriter, ErrorKind, Read, Write},
+    path::Path,
+};
+
+use anyhow::{Context, Result, bail};
+
+use crate::chips::{
+    b8::B8,
+    b32::B32,
+    thread::{CURRENT_ADDR_REGISTER, Thread, instructions::Instruction},
+};
+
+/// first bytes of every trace file
+pub const TRACE_MAGIC: &[u8; 8] = b"TCTRACE\0";
+pub const TRACE_VERSION: u32 = 2;
+
+// record layout, all numbers are little endian:
+// cycle: u64, thread: u16, flags: u8, pc: u32, instruction: u32 (encoded like in memory)
+// register write count: u8, (register: u8, value: u32) for every write
+// memory write count: u16, (physical addr: u32, value: u32) for every write
+const FLAG_INTERRUPT_ENTRY: u8 = 1;
+
+/// one executed instruction
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceRecord {
+    pub cycle: u64,
+    pub thread: u16,
+    /// instruction was made by interrupt entry, not fetched from memory
+    pub interrupt_entry: bool,
+    /// pc before the instruction ran
+    pub pc: B32,
+    pub instruction: Instruction,
+    /// registers whose value changed, pc is left out because it changes every time
+    pub register_writes: Vec<(B8, B32)>,
+    pub memory_writes: Vec<(B32, B32)>,
+}
+impl Display for TraceRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cycle {} thread {} pc {:#010x}{} {:?}",
+            self.cycle,
+            self.thread,
+            self.pc.0,
+            if self.interrupt_entry {
+                " (interrupt)"
+            } else {
+                ""
+            },
+            self.instruction
+        )?;
+        for (register, value) in &self.register_writes {
+            write!(f, " r{}={:#x}", register.0, value.0)?;
+        }
+        for (addr, value) in &self.memory_writes {
+            write!(f, " [{:#x}]={:#x}", addr.0, value.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// collects what a thread does during its step and writes it as a trace record
+pub struct Tracer {
+    writer: BufWriter<File>,
+    /// pc, instruction, interrupt entry
+    instruction: Option<(B32, Instruction, bool)>,
+    /// interrupt entry writes to the stack before its instruction is known, so writes are
+    /// collected separately
+    memory_writes: Vec<(B32, B32)>,
+}
+impl Tracer {
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("could not create trace file: {}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(TRACE_MAGIC)?;
+        writer.write_all(&TRACE_VERSION.to_le_bytes())?;
+        Ok(Self {
+            writer,
+            instruction: None,
+            memory_writes: Vec::new(),
+        })
+    }
+    pub(crate) fn instruction(&mut self, pc: B32, instruction: Instruction, interrupt_entry: bool) {
+        self.instruction = Some((pc, instruction, interrupt_entry));
+    }
+    pub(crate) fn memory_write(&mut self, addr: B32, data: B32) {
+        self.memory_writes.push((addr, data));
+    }
+    /// writes record of the instruction that the thread ran in this step, if it ran any
+    pub(crate) fn finish_step(
+        &mut self,
+        cycle: u64,
+        thread_index: usize,
+        thread: &Thread,
+        registers_before: &[B32; 256],
+    ) -> Result<()> {
+        let memory_writes = std::mem::take(&mut self.memory_writes);
+        let Some((pc, instruction, interrupt_entry)) = self.instruction.take() else {
+            return Ok(());
+        };
+        let register_writes = (0..=255)
+            .map(B8)
+            .filter(|register| register.0 != CURRENT_ADDR_REGISTER.0)
+            .map(|register| (register, thread.read_register(register)))
+            .filter(|(register, value)| registers_before[register.0 as usize].0 != value.0)
+            .collect();
+        write_record(
+            &mut self.writer,
+            &TraceRecord {
+                cycle,
+                thread: thread_index as u16,
+                interrupt_entry,
+                pc,
+                instruction,
+                register_writes,
+                memory_writes,
+            },
+        )
+    }
+    pub fn flush(&mut self) -> Result<()> {
+        self.writer.flush().context("could not write trace")
+    }
+}
+
+fn write_record(writer: &mut impl Write, record: &TraceRecord) -> Result<()> {
+    let instruction: B32 = record.instruction.into();
+    let flags = if record.interrupt_entry {
+        FLAG_INTERRUPT_ENTRY
+    } else {
+        0
+    };
+    writer.write_all(&record.cycle.to_le_bytes())?;
+    writer.write_all(&record.thread.to_le_bytes())?;
+    writer.write_all(&[flags])?;
+    writer.write_all(&record.pc.0.to_le_bytes())?;
+    writer.write_all(&instruction.0.to_le_bytes())?;
+    writer.write_all(&[record.register_writes.len() as u8])?;
+    for (register, value) in &record.register_writes {
+        writer.write_all(&[register.0])?;
+        writer.write_all(&value.0.to_le_bytes())?;
+    }
+    // interrupt entry that saves every register writes more than 255 words
+    writer.write_all(&(record.memory_writes.len() as u16).to_le_bytes())?;
+    for (addr, value) in &record.memory_writes {
+        writer.write_all(&addr.0.to_le_bytes())?;
+        writer.write_all(&value.0.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// reads records of a trace file one by one
+pub struct TraceReader {
+    reader: BufReader<File>,
+}
+impl TraceReader {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)
+            .with_context(|| format!("could not open trace file: {}", path.display()))?;
+        let mut reader = BufReader::new(file);
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != TRACE_MAGIC {
+            bail!("{} is not a trace file", path.display());
+        }
+        let version = read_u32(&mut reader)?;
+        if version != TRACE_VERSION {
+            bail!("trace has version: {version}, only version: {TRACE_VERSION} is supported");
+        }
+        Ok(Self { reader })
+    }
+    /// None at the end of the trace
+    pub fn read_record(&mut self) -> Result<Option<TraceRecord>> {
+        let mut cycle = [0; 8];
+        match self.reader.read_exact(&mut cycle) {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => return Err(err.into()),
+        }
+        let mut thread = [0; 2];
+        self.reader.read_exact(&mut thread)?;
+        let flags = read_u8(&mut self.reader)?;
+        let pc = B32(read_u32(&mut self.reader)?);
+        let instruction = B32(read_u32(&mut self.reader)?);
+
+        let register_write_count = read_u8(&mut self.reader)?;
+        let mut register_writes = Vec::with_capacity(register_write_count as usize);
+        for _ in 0..register_write_count {
+            let register = B8(read_u8(&mut self.reader)?);
+            register_writes.push((register, B32(read_u32(&mut self.reader)?)));
+        }
+        let memory_write_count = read_u16(&mut self.reader)?;
+        let mut memory_writes = Vec::with_capacity(memory_write_count as usize);
+        for _ in 0..memory_write_count {
+            let addr = B32(read_u32(&mut self.reader)?);
+            memory_writes.push((addr, B32(read_u32(&mut self.reader)?)));
+        }
+
+        Ok(Some(TraceRecord {
+            cycle: u64::from_le_bytes(cycle),
+            thread: u16::from_le_bytes(thread),
+            interrupt_entry: flags & FLAG_INTERRUPT_ENTRY != 0,
+            pc,
+            instruction: Instruction::try_from(instruction)
+                .context("trace contains invalid instruction")?,
+            register_writes,
+            memory_writes,
+        }))
+    }
+}
+impl Iterator for TraceReader {
+    type Item = Result<TraceRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+fn read_u8(reader: &mut impl Read) -> Result<u8> {
+    let mut bytes = [0; 1];
+    reader
+        .read_exact(&mut bytes)
+        .context("trace ended too early")?;
+    Ok(bytes[0])
+}
+fn read_u16(reader: &mut impl Read) -> Result<u16> {
+    let mut bytes = [0; 2];
+    reader
+        .read_exact(&mut bytes)
+        .context("trace ended too early")?;
+    Ok(u16::from_le_bytes(bytes))
+}
+fn read_u32(reader: &mut impl Read) -> Result<u32> {
+    let mut bytes = [0; 4];
+    reader
+        .read_exact(&mut bytes)
+        .context("trace ended too early")?;
+    Ok(u32::from_le_bytes(bytes))
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use common::{COUNTER_ADDR, program_machine};
+use cpu::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{Interrupt, InterruptKind, instructions::Instruction},
+    },
+    machine::Machine,
+    memory_map::MemoryMap,
+};
+
+fn set(register: u8, value: u32) -> Vec<B32> {
+    vec![Instruction::Set(B8(register)).into(), B32(value)]
+}
+fn counter(machine: &Machine) -> B32 {
+    machine.memory.read(B32(COUNTER_ADDR))
+}
+
+#[test]
+fn cas_xadd_and_xchg_return_the_old_word() {
+    let program = [
+        set(1, COUNTER_ADDR),
+        // swaps, the word is the expected one
+        set(2, 5),
+        set(3, 9),
+        vec![Instruction::Cas(B8(1), B8(2), B8(3)).into()],
+        // doesn't, the word is 9 now
+        set(4, 5),
+        set(5, 11),
+        vec![Instruction::Cas(B8(1), B8(4), B8(5)).into()],
+        set(6, 3),
+        vec![Instruction::Xadd(B8(1), B8(6)).into()],
+        set(7, 1),
+        vec![
+            Instruction::Xchg(B8(1), B8(7)).into(),
+            Instruction::Fence().into(),
+            Instruction::Halt().into(),
+        ],
+    ]
+    .concat();
+    let mut machine = program_machine(program);
+    machine.memory.write(B32(5), B32(COUNTER_ADDR), true);
+    machine.run_cycles(30).unwrap();
+    let thread = &machine.threads[0];
+    assert!(thread.is_halting());
+    assert_eq!(thread.read_register(B8(2)), B32(5));
+    assert_eq!(thread.read_register(B8(4)), B32(9));
+    assert_eq!(thread.read_register(B8(6)), B32(9));
+    assert_eq!(thread.read_register(B8(7)), B32(12));
+    assert_eq!(counter(&machine), B32(1));
+}
+
+/// sets r1 to the counter and adds one to it 10 times, r10 - r19 get the old words
+fn xadd_program() -> Vec<B32> {
+    let mut program = set(1, COUNTER_ADDR);
+    for register in 10..20 {
+        program.extend(set(register, 1));
+        program.push(Instruction::Xadd(B8(1), B8(register)).into());
+    }
+    program.push(Instruction::Halt().into());
+    program
+}
+
+#[test]
+fn threads_adding_to_one_word_see_every_old_value_once() {
+    const IDT_ADDR: u32 = 1000;
+    const THREAD_1_ADDR: u32 = 2000;
+    let mut machine = Machine::new(2, &MemoryMap::default()).unwrap();
+    cpu::test_load_memory(&machine.memory, xadd_program());
+    // thread 1 starts halted, an ipi wakes it into the same code at THREAD_1_ADDR + 1
+    for (offset, word) in xadd_program().into_iter().enumerate() {
+        machine
+            .memory
+            .write(word, B32(THREAD_1_ADDR + 1 + offset as u32), true);
+    }
+    machine.memory.write(
+        B32(THREAD_1_ADDR),
+        B32(IDT_ADDR + InterruptKind::Ipi as u32),
+        true,
+    );
+    let thread = &machine.threads[1];
+    thread.write_register(B8(200), B32(IDT_ADDR));
+    thread.Idt(B8(200), true);
+    thread.interrupt(Interrupt {
+        kind: InterruptKind::Ipi,
+        data: 0,
+    });
+
+    machine.run_cycles(60).unwrap();
+    assert_eq!(counter(&machine), B32(20));
+    let mut old_values: Vec<u32> = machine
+        .threads
+        .iter()
+        .flat_map(|thread| {
+            assert!(thread.is_halting());
+            (10..20).map(|register| thread.read_register(B8(register)).0)
+        })
+        .collect();
+    old_values.sort();
+    assert_eq!(old_values, (0..20).collect::<Vec<_>>());
+}
@@ -0,0 +1,29 @@
+mod common;
+
+use cpu::peripherals::serial::Serial;
+
+/// the command line waits for serial input well before this many cycles
+const BOOT_CYCLES: u64 = 20_000_000;
+/// plenty for the command line to wake up and echo one byte
+const ECHO_CYCLES: u64 = 20_000;
+
+#[tokio::test]
+async fn command_line_echoes_serial_input() {
+    let (mut machine, output) = common::boot(&common::args()).await;
+    let input = machine.peripheral_mut::<Serial>().unwrap().input_sender();
+    common::run_until_output(&mut machine, &output, "END!\n", BOOT_CYCLES);
+
+    input.send(b"hello\n".to_vec()).unwrap();
+    common::run_until_output(&mut machine, &output, "END!\nhello\n", ECHO_CYCLES);
+
+    // second byte comes at every point of the loop that echoes the first one, it has to wake the
+    // loop up even if it came right after the loop checked for bytes
+    let mut expected = output.text();
+    for delay in 0..300 {
+        input.send(b"a".to_vec()).unwrap();
+        machine.run_cycles(delay).unwrap();
+        input.send(b"b".to_vec()).unwrap();
+        expected += "ab";
+        common::run_until_output(&mut machine, &output, &expected, ECHO_CYCLES);
+    }
+}
@@ -0,0 +1,170 @@
+// every test binary uses only some of the helpers
+#![allow(dead_code)]
+
+use std::{
+    io::Write,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use cpu::{
+    Args,
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{CURRENT_ADDR_REGISTER, STACK_HEAD_REGISTER, instructions::Instruction},
+    },
+    fb::Resolution,
+    machine::Machine,
+    memory_map::MemoryMap,
+    peripherals::serial::Serial,
+    rng::DEFAULT_SEED,
+    test_load_memory,
+};
+
+pub const OS: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../programs/os/compiled/trash_can_os.bin"
+);
+pub const COMMAND_LINE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../programs/command_line/compiled/command_line.bin"
+);
+
+/// what `trash_can --headless` gets without any flags, but with one thread
+pub fn args() -> Args {
+    Args {
+        threads: 1,
+        cycles: None,
+        snapshot: None,
+        save_snapshot: None,
+        trace: None,
+        debug: false,
+        gdb: None,
+        memory_map: None,
+        regions: Vec::new(),
+        serial_input: None,
+        serials: Vec::new(),
+        resolution: Resolution::default(),
+        seed: DEFAULT_SEED,
+    }
+}
+
+/// machine with the os and the command line loaded, serial output goes to the returned buffer
+pub async fn boot(args: &Args) -> (Machine, SerialOutput) {
+    let mut machine = cpu::init(args, OS, COMMAND_LINE).await.unwrap();
+    let output = SerialOutput::default();
+    machine
+        .peripheral_mut::<Serial>()
+        .unwrap()
+        .set_output(Box::new(output.clone()));
+    (machine, output)
+}
+
+/// runs the machine until the serial printed text, at most max_cycles
+pub fn run_until_output(machine: &mut Machine, output: &SerialOutput, text: &str, max_cycles: u64) {
+    let end = machine.cycle() + max_cycles;
+    while !output.text().contains(text) {
+        let printed = output.text();
+        assert!(
+            machine.cycle() < end,
+            "serial didn't print {:?} in {max_cycles} cycles, ends with: {:?}",
+            tail(text),
+            tail(&printed),
+        );
+        machine.run_cycles(1000).unwrap();
+    }
+}
+
+/// last characters of a long output
+fn tail(text: &str) -> &str {
+    let start = text.len().saturating_sub(40);
+    text.get(start..).unwrap_or(text)
+}
+
+/// bytes written to a serial, shared with the test
+#[derive(Clone, Default)]
+pub struct SerialOutput(Arc<Mutex<Vec<u8>>>);
+impl SerialOutput {
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+    }
+}
+impl Write for SerialOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// path in the temp dir that no other test uses
+pub fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("trash_can_{}_{name}", std::process::id()))
+}
+
+pub const COUNTER_ADDR: u32 = 500;
+
+/// adds one to the word at `COUNTER_ADDR` forever, 3 cycles of setup and then 4 per loop
+pub fn counter_program() -> Vec<B32> {
+    vec![
+        Instruction::Set(B8(1)).into(),
+        B32(COUNTER_ADDR),
+        Instruction::Set(B8(2)).into(),
+        B32(1),
+        // pc is incremented after the jump, so it goes to the Read
+        Instruction::Set(B8(4)).into(),
+        B32(5),
+        Instruction::Read(B8(3), B8(1)).into(),
+        Instruction::Add(B8(3), B8(2)).into(),
+        Instruction::Write(B8(1), B8(3)).into(),
+        Instruction::Jmp(B8(4)).into(),
+    ]
+}
+/// machine with one thread that runs `counter_program`
+pub fn counter_machine() -> Machine {
+    program_machine(counter_program())
+}
+/// machine with one thread that runs program from address 0
+pub fn program_machine(program: Vec<B32>) -> Machine {
+    let machine = Machine::new(1, &MemoryMap::default()).unwrap();
+    test_load_memory(&machine.memory, program);
+    machine
+}
+
+pub const IDT_ADDR: u32 = 1000;
+/// every IDT entry of `set_fault_handlers` points here, jumps land one word past their target
+pub const HANDLER_ADDR: u32 = 1100;
+/// makes thread 0 handle every interrupt with `Halt`, see `entered_interrupt`
+pub fn set_fault_handlers(machine: &Machine) {
+    for index in 0..16 {
+        machine
+            .memory
+            .write(B32(HANDLER_ADDR), B32(IDT_ADDR + index), true);
+    }
+    machine
+        .memory
+        .write(Instruction::Halt().into(), B32(HANDLER_ADDR + 1), true);
+    let thread = &machine.threads[0];
+    let register = thread.read_register(B8(200));
+    thread.write_register(B8(200), B32(IDT_ADDR));
+    thread.Idt(B8(200), true);
+    thread.write_register(B8(200), register);
+}
+/// data and return address of the interrupt whose handler thread 0 halted in, read from the
+/// stack entry. Registers are not saved by default, so the stack head points at the return address.
+pub fn entered_interrupt(machine: &Machine) -> (u32, B32) {
+    let thread = &machine.threads[0];
+    let pc = thread.read_register(CURRENT_ADDR_REGISTER);
+    assert!(
+        thread.is_halting() && pc == B32(HANDLER_ADDR + 2),
+        "thread didn't halt in the handler, pc: {pc}"
+    );
+    let head = thread.read_register(STACK_HEAD_REGISTER);
+    (
+        machine.memory.read(head - B32(2)).0,
+        machine.memory.read(head),
+    )
+}
@@ -0,0 +1,109 @@
+use cpu::{
+    chips::b32::B32,
+    fb::Resolution,
+    peripherals::{
+        Peripheral,
+        console::{
+            CELL_HEIGHT, CELL_WIDTH, CONSOLE_CELLS, CONSOLE_CONTROL, CONSOLE_CURSOR, CONSOLE_DATA,
+            CONSOLE_SIZE, Console, DEFAULT_ATTRIBUTE, PALETTE,
+        },
+        gpu::{Display, SharedDisplay},
+    },
+};
+
+const COLUMNS: u32 = 3;
+const ROWS: u32 = 2;
+
+/// console of 3 columns and 2 rows
+fn console() -> (Console, SharedDisplay) {
+    let display = Display::new(Resolution {
+        width: COLUMNS * CELL_WIDTH,
+        height: ROWS * CELL_HEIGHT,
+    });
+    (Console::new(display.clone()), display)
+}
+/// 4 characters per word, like programs print
+fn print(console: &mut Console, text: &str) {
+    for chunk in text.as_bytes().chunks(4) {
+        let mut bytes = [0; 4];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        console
+            .write(0, CONSOLE_DATA, B32(u32::from_le_bytes(bytes)))
+            .unwrap();
+    }
+}
+/// characters of the cells, rows separated by '|'
+fn text(console: &mut Console) -> String {
+    (0..ROWS)
+        .map(|row| {
+            (0..COLUMNS)
+                .map(|column| {
+                    let cell = console.read(0, CONSOLE_CELLS + row * COLUMNS + column);
+                    cell.unwrap().0 as u8 as char
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("|")
+}
+fn cursor(console: &mut Console) -> u32 {
+    console.read(0, CONSOLE_CURSOR).unwrap().0
+}
+
+#[test]
+fn text_wraps_and_scrolls_up_past_the_last_row() {
+    let (mut console, _) = console();
+    assert_eq!(
+        console.read(0, CONSOLE_SIZE).unwrap(),
+        B32(COLUMNS | ROWS << 16)
+    );
+    print(&mut console, "abcd");
+    assert_eq!(text(&mut console), "abc|d  ");
+    assert_eq!(cursor(&mut console), 4);
+    print(&mut console, "ef");
+    // cursor went past the last cell, the first row is gone
+    assert_eq!(text(&mut console), "def|   ");
+    assert_eq!(cursor(&mut console), 3);
+    print(&mut console, "g\n");
+    assert_eq!(text(&mut console), "g  |   ");
+    assert_eq!(cursor(&mut console), 3);
+}
+
+#[test]
+fn control_characters_move_the_cursor() {
+    let (mut console, _) = console();
+    print(&mut console, "ab\rc");
+    assert_eq!(text(&mut console), "cb |   ");
+    print(&mut console, "\x08\x08\x08");
+    // backspace stops at the start of the row
+    assert_eq!(text(&mut console), " b |   ");
+    assert_eq!(cursor(&mut console), 0);
+    print(&mut console, "\nxy");
+    assert_eq!(text(&mut console), " b |xy ");
+    console.write(0, CONSOLE_CURSOR, B32(2)).unwrap();
+    print(&mut console, "z");
+    assert_eq!(text(&mut console), " bz|xy ");
+    print(&mut console, "\x0c");
+    assert_eq!(text(&mut console), "   |   ");
+    assert_eq!(cursor(&mut console), 0);
+}
+
+#[test]
+fn cursor_is_a_line_under_its_cell() {
+    let (mut console, display) = console();
+    print(&mut console, "\n");
+    // under the second row, first column
+    let pixel = |display: &SharedDisplay| {
+        let width = COLUMNS * CELL_WIDTH;
+        display
+            .lock()
+            .unwrap()
+            .pixel((((2 * CELL_HEIGHT) - 1) * width + 1) * 4)
+    };
+    let foreground = B32(PALETTE[(DEFAULT_ATTRIBUTE & 0xF) as usize]);
+    let background = B32(PALETTE[(DEFAULT_ATTRIBUTE >> 4) as usize]);
+    assert_eq!(pixel(&display), foreground);
+    console.write(0, CONSOLE_CONTROL, B32(0)).unwrap();
+    assert_eq!(pixel(&display), background);
+    assert_eq!(console.read(0, CONSOLE_CONTROL).unwrap(), B32(0));
+}
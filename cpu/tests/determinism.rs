@@ -0,0 +1,35 @@
+mod common;
+
+/// the os halts until a timer interrupt 1000000 cycles after boot, the trace covers its wake up
+const TRACE_START: u64 = 990_000;
+const TRACE_END: u64 = 1_060_000;
+
+/// two machines booted from the same programs run the same instructions and end in the same state
+#[tokio::test]
+async fn identical_runs_give_identical_traces_and_snapshots() {
+    let mut args = common::args();
+    args.threads = 4;
+    let mut runs = Vec::new();
+    for run in 0..2 {
+        let trace = common::temp_path(&format!("determinism_{run}.trace"));
+        let (mut machine, output) = common::boot(&args).await;
+        machine.run_cycles(TRACE_START).unwrap();
+        assert!(!output.text().contains("2555555"), "os woke up before the trace");
+        machine.start_trace(&trace).unwrap();
+        machine.run_cycles(TRACE_END - TRACE_START).unwrap();
+        machine.stop_trace().unwrap();
+        runs.push((
+            std::fs::read(&trace).unwrap(),
+            machine.snapshot(),
+            output.text(),
+        ));
+        std::fs::remove_file(&trace).unwrap();
+    }
+    let (first_trace, first_snapshot, first_output) = &runs[0];
+    let (second_trace, second_snapshot, second_output) = &runs[1];
+    assert!(first_output.contains("2555555"), "output: {first_output:?}");
+    assert_eq!(first_output, second_output);
+    // whole traces and snapshots would flood the output
+    assert!(first_trace == second_trace, "traces differ");
+    assert!(first_snapshot == second_snapshot, "snapshots differ");
+}
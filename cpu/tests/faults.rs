@@ -0,0 +1,71 @@
+mod common;
+
+use common::{entered_interrupt, program_machine, set_fault_handlers};
+use cpu::chips::{
+    b8::B8,
+    b32::B32,
+    thread::{FaultCause, instructions::Instruction},
+};
+
+/// sets r1 to 7 and r2 to 0, then runs the instruction at 4
+fn fault_machine(instruction: B32) -> cpu::machine::Machine {
+    let machine = program_machine(vec![
+        Instruction::Set(B8(1)).into(),
+        B32(7),
+        Instruction::Set(B8(2)).into(),
+        B32(0),
+        instruction,
+        Instruction::Halt().into(),
+    ]);
+    set_fault_handlers(&machine);
+    machine
+}
+
+#[test]
+fn word_that_is_not_an_instruction_raises_illegal_instruction() {
+    let word = B32(0xff);
+    let mut machine = fault_machine(word);
+    machine.run_cycles(10).unwrap();
+    // the handler gets the word and returns to it
+    assert_eq!(entered_interrupt(&machine), (word.0, B32(4)));
+}
+
+#[test]
+fn division_by_zero_raises_divide_by_zero_with_its_cause() {
+    for (instruction, cause) in [
+        (Instruction::Div(B8(1), B8(2)), FaultCause::Div),
+        (Instruction::Mod(B8(1), B8(2)), FaultCause::Mod),
+    ] {
+        let mut machine = fault_machine(instruction.into());
+        machine.run_cycles(10).unwrap();
+        assert_eq!(entered_interrupt(&machine), (cause as u32, B32(4)));
+        // the instruction didn't run
+        assert_eq!(machine.threads[0].read_register(B8(1)), B32(7));
+    }
+}
+
+#[test]
+fn read_outside_of_memory_raises_bad_address() {
+    let mut machine = fault_machine(Instruction::Set(B8(3)).into());
+    // r3 = address in the hole between the mmio regions, then read it
+    machine.memory.write(B32(0x80000), B32(5), true);
+    machine
+        .memory
+        .write(Instruction::Read(B8(4), B8(3)).into(), B32(6), true);
+    machine.run_cycles(10).unwrap();
+    assert_eq!(
+        entered_interrupt(&machine),
+        (FaultCause::Read as u32, B32(6))
+    );
+}
+
+#[test]
+fn fault_without_handler_stops_the_machine() {
+    let mut machine = program_machine(vec![
+        Instruction::Set(B8(2)).into(),
+        B32(0),
+        Instruction::Div(B8(1), B8(2)).into(),
+    ]);
+    let err = machine.run_cycles(10).unwrap_err();
+    assert!(format!("{err:?}").contains("DivideByZero"), "{err:?}");
+}
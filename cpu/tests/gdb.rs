@@ -0,0 +1,165 @@
+mod common;
+
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+};
+
+use common::{COUNTER_ADDR, counter_machine, counter_program, program_machine};
+use cpu::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{
+            instructions::Instruction,
+            mmu::{PAGE_PRESENT_BIT, PAGE_SIZE},
+        },
+    },
+    gdb::GdbServer,
+    machine::Machine,
+};
+
+/// gdb side of the remote serial protocol, with acks
+struct Client(TcpStream);
+impl Client {
+    fn send(&mut self, packet: &str) -> String {
+        let checksum = packet.bytes().fold(0u8, |sum, byte| sum.wrapping_add(byte));
+        write!(self.0, "${packet}#{checksum:02x}").unwrap();
+        let mut byte = [0];
+        self.0.read_exact(&mut byte).unwrap();
+        assert_eq!(byte[0], b'+', "server didn't ack {packet:?}");
+        if packet == "k" {
+            return String::new();
+        }
+        while byte[0] != b'$' {
+            self.0.read_exact(&mut byte).unwrap();
+        }
+        let mut reply = Vec::new();
+        loop {
+            self.0.read_exact(&mut byte).unwrap();
+            if byte[0] == b'#' {
+                break;
+            }
+            reply.push(byte[0]);
+        }
+        self.0.read_exact(&mut [0; 2]).unwrap();
+        self.0.write_all(b"+").unwrap();
+        String::from_utf8(reply).unwrap()
+    }
+}
+
+/// serves the machine to the session, which runs as gdb on its own thread
+fn serve(machine: &mut Machine, session: impl FnOnce(&mut Client) + Send + 'static) {
+    let mut server = GdbServer::bind("127.0.0.1:0").unwrap();
+    let addr = server.local_addr().unwrap();
+    let client = std::thread::spawn(move || {
+        let mut gdb = Client(TcpStream::connect(addr).unwrap());
+        session(&mut gdb);
+        gdb.send("k");
+    });
+    server.run(machine).unwrap();
+    client.join().unwrap();
+}
+
+#[test]
+fn gdb_session_over_tcp() {
+    let mut machine = counter_machine();
+    serve(&mut machine, |gdb| {
+        assert_eq!(gdb.send("?"), "T05thread:1;");
+        // pc (r251) starts at 0
+        assert_eq!(gdb.send("pfb"), "00000000");
+
+        // Read of the counter loop is word 6, gdb sees byte address 0x18
+        assert_eq!(gdb.send("Z0,18,4"), "OK");
+        assert_eq!(gdb.send("c"), "T05swbreak:;thread:1;");
+        assert_eq!(gdb.send("pfb"), "18000000");
+        let counter = format!("{:x}", COUNTER_ADDR * 4);
+        assert_eq!(gdb.send(&format!("m{counter},4")), "00000000");
+        assert_eq!(gdb.send("c"), "T05swbreak:;thread:1;");
+        assert_eq!(gdb.send(&format!("m{counter},4")), "01000000");
+
+        assert_eq!(gdb.send(&format!("M{counter},4:2a000000")), "OK");
+        assert_eq!(gdb.send(&format!("m{counter},4")), "2a000000");
+        // reply to a read bigger than a packet is cut to fit
+        let reply = gdb.send("m0,100000");
+        assert!(reply.len() <= 0x4000, "reply of {} bytes", reply.len());
+        let program: String = counter_program()
+            .iter()
+            .flat_map(|word| word.0.to_le_bytes())
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        assert!(reply.starts_with(&program), "reply: {reply:.64}");
+
+        // non ascii bytes are an error reply, not a crash of the server
+        assert_eq!(gdb.send("\u{e9}"), "");
+        assert_eq!(gdb.send("G\u{e9}"), "E01");
+        assert_eq!(gdb.send(&format!("M{counter},4:0\u{e9}0")), "E01");
+        assert_eq!(gdb.send("Pfb=0\u{e9}00000"), "E01");
+    });
+
+    // gdb wrote 42 and the machine hasn't run since
+    assert_eq!(machine.memory.read(B32(COUNTER_ADDR)), B32(42));
+}
+
+#[test]
+fn step_runs_one_instruction_of_the_thread() {
+    let mut machine = program_machine(vec![
+        Instruction::Set(B8(1)).into(),
+        B32(20),
+        Instruction::Sleep(B8(1)).into(),
+        Instruction::Set(B8(2)).into(),
+        B32(9),
+        Instruction::Halt().into(),
+    ]);
+    serve(&mut machine, |gdb| {
+        assert_eq!(gdb.send("s"), "T05thread:1;");
+        assert_eq!(gdb.send("pfb"), "08000000");
+        assert_eq!(gdb.send("vCont;s:1"), "T05thread:1;");
+        assert_eq!(gdb.send("pfb"), "0c000000");
+        // the thread sleeps for 20 cycles before it runs the next instruction
+        assert_eq!(gdb.send("s"), "T05thread:1;");
+        assert_eq!(gdb.send("pfb"), "14000000");
+        assert_eq!(gdb.send("p2"), "09000000");
+    });
+}
+
+#[test]
+fn memory_packets_use_virtual_addresses() {
+    const PAGE_TABLE_ADDR: u32 = 8 * PAGE_SIZE;
+    const FRAME: u32 = 5;
+    let mut machine = counter_machine();
+    // page 0 is the program, page 1 is frame 5, the rest isn't mapped
+    let present = 1 << PAGE_PRESENT_BIT;
+    machine
+        .memory
+        .write(B32(present), B32(PAGE_TABLE_ADDR), true);
+    machine
+        .memory
+        .write(B32(present | FRAME), B32(PAGE_TABLE_ADDR + 1), true);
+    machine
+        .memory
+        .write(B32(0x12345678), B32(FRAME * PAGE_SIZE + 1), true);
+    machine.threads[0].mmu.set_page_table(B32(PAGE_TABLE_ADDR));
+
+    serve(&mut machine, |gdb| {
+        let page_1 = PAGE_SIZE * 4;
+        assert_eq!(gdb.send(&format!("m{:x},4", page_1 + 4)), "78563412");
+        // read only page can be written by the debugger
+        assert_eq!(gdb.send(&format!("M{:x},2:cdab", page_1 + 4)), "OK");
+        // read stops at the first byte that isn't mapped
+        assert_eq!(gdb.send(&format!("m{:x},8", 2 * page_1 - 4)), "00000000");
+        assert_eq!(gdb.send(&format!("m{:x},4", 2 * page_1)), "E01");
+        assert_eq!(
+            gdb.send(&format!("M{:x},8:0102030405060708", 2 * page_1 - 4)),
+            "E01"
+        );
+    });
+    assert_eq!(
+        machine.memory.read(B32(FRAME * PAGE_SIZE + 1)),
+        B32(0x1234abcd)
+    );
+    assert_eq!(
+        machine.memory.read(B32(FRAME * PAGE_SIZE + PAGE_SIZE - 1)),
+        B32(0)
+    );
+}
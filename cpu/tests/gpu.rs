@@ -0,0 +1,258 @@
+use cpu::{
+    chips::{b32::B32, thread::InterruptKind},
+    fb::Resolution,
+    memory_map::{Memory, Region, RegionKind},
+    peripherals::{
+        Peripheral,
+        framebuffer::Framebuffer,
+        gpu::{
+            Display, GPU_COLOR, GPU_COMMAND, GPU_COMMAND_LIST, GPU_CONTROL,
+            GPU_CONTROL_DOUBLE_BUFFERING, GPU_CONTROL_VBLANK_INTERRUPT, GPU_FRAME_COUNT,
+            GPU_LIST_BUSY, GPU_LIST_DONE, GPU_LIST_FAILED, GPU_POSITION, GPU_PRESENT, Gpu,
+            SharedDisplay, VBLANK_CYCLES,
+            commands::{
+                GPU_BLIT, GPU_CLEAR, GPU_END, GPU_FILL_RECT, GPU_FLAG_COLOR_KEY, GPU_FLAG_PALETTE,
+                GPU_LINE, GPU_SET_PALETTE,
+            },
+        },
+    },
+};
+
+const RED: u32 = 0xff0000ff;
+
+fn display() -> SharedDisplay {
+    Display::new(Resolution {
+        width: 8,
+        height: 8,
+    })
+}
+
+#[test]
+fn pixels_outside_of_the_frame_are_skipped() {
+    let display = display();
+    let mut gpu = Gpu::new(display.clone());
+    let last = 8 * 8 * 4 - 4;
+    for position in [last, last + 4, u32::MAX] {
+        gpu.write(0, GPU_POSITION, B32(position)).unwrap();
+        gpu.write(0, GPU_COLOR, B32(RED)).unwrap();
+        gpu.write(0, GPU_COMMAND, B32(position)).unwrap();
+        gpu.write(0, GPU_COMMAND, B32(RED)).unwrap();
+    }
+    gpu.write(0, GPU_POSITION, B32(last + 4)).unwrap();
+    assert_eq!(gpu.read(0, GPU_COLOR).unwrap(), B32(0));
+    gpu.write(0, GPU_POSITION, B32(last)).unwrap();
+    assert_eq!(gpu.read(0, GPU_COLOR).unwrap(), B32(RED));
+
+    let mut framebuffer = Framebuffer::new(display);
+    framebuffer.write(0, 8 * 8, B32(RED)).unwrap();
+    assert_eq!(framebuffer.read(0, 8 * 8).unwrap(), B32(0));
+    assert_eq!(framebuffer.read(0, 8 * 8 - 1).unwrap(), B32(RED));
+}
+
+/// ticks the gpu until the next vblank, returns thread and data of the interrupts it raised
+fn run_to_vblank(gpu: &mut Gpu) -> Vec<(usize, u32)> {
+    let memory = Memory::new(&[]).unwrap();
+    let mut raised = Vec::new();
+    for _ in 0..VBLANK_CYCLES {
+        gpu.tick(&memory, &mut |thread, interrupt| {
+            assert_eq!(interrupt.kind, InterruptKind::VBlank);
+            raised.push((thread, interrupt.data));
+        });
+    }
+    raised
+}
+/// first pixel of the shown frame
+fn shown(display: &SharedDisplay) -> &'static str {
+    match display.lock().unwrap().frame()[..4] {
+        [0, 0, 0, 0] => "nothing",
+        _ => "pixel",
+    }
+}
+
+#[test]
+fn vblank_shows_back_buffer_without_double_buffering() {
+    let display = display();
+    let mut gpu = Gpu::new(display.clone());
+    gpu.write(0, GPU_COLOR, B32(RED)).unwrap();
+    assert_eq!(shown(&display), "nothing");
+    assert_eq!(run_to_vblank(&mut gpu), []);
+    assert_eq!(shown(&display), "pixel");
+    assert_eq!(gpu.read(0, GPU_FRAME_COUNT).unwrap(), B32(1));
+}
+
+#[test]
+fn double_buffered_frame_is_shown_after_present() {
+    let display = display();
+    let mut gpu = Gpu::new(display.clone());
+    gpu.write(0, GPU_CONTROL, B32(GPU_CONTROL_DOUBLE_BUFFERING))
+        .unwrap();
+    gpu.write(0, GPU_COLOR, B32(RED)).unwrap();
+    run_to_vblank(&mut gpu);
+    assert_eq!(shown(&display), "nothing");
+
+    gpu.write(0, GPU_PRESENT, B32(1)).unwrap();
+    assert_eq!(gpu.read(0, GPU_PRESENT).unwrap(), B32(1));
+    run_to_vblank(&mut gpu);
+    assert_eq!(shown(&display), "pixel");
+    assert_eq!(gpu.read(0, GPU_PRESENT).unwrap(), B32(0));
+    assert_eq!(gpu.read(0, GPU_FRAME_COUNT).unwrap(), B32(2));
+
+    // taken back before the vblank
+    gpu.write(0, GPU_COLOR, B32(0)).unwrap();
+    gpu.write(0, GPU_PRESENT, B32(1)).unwrap();
+    gpu.write(0, GPU_PRESENT, B32(0)).unwrap();
+    run_to_vblank(&mut gpu);
+    assert_eq!(shown(&display), "pixel");
+}
+
+#[test]
+fn vblank_interrupt_goes_to_the_thread_that_enabled_it() {
+    let mut gpu = Gpu::new(display());
+    gpu.write(2, GPU_CONTROL, B32(GPU_CONTROL_VBLANK_INTERRUPT))
+        .unwrap();
+    assert_eq!(
+        gpu.read(0, GPU_CONTROL).unwrap(),
+        B32(GPU_CONTROL_VBLANK_INTERRUPT)
+    );
+    // data is the frame count
+    for frame in 1..=2 {
+        assert_eq!(run_to_vblank(&mut gpu), [(2, frame)]);
+    }
+    gpu.write(2, GPU_CONTROL, B32(0)).unwrap();
+    assert_eq!(run_to_vblank(&mut gpu), []);
+}
+
+const BLUE: u32 = 0xffff0000;
+const LIST_ADDR: u32 = 100;
+const DATA_ADDR: u32 = 200;
+
+/// memory with the list at `LIST_ADDR` and data for blits and palettes at `DATA_ADDR`
+fn list_memory(list: &[u32], data: &[u32]) -> Memory {
+    let memory = Memory::new(&[Region {
+        kind: RegionKind::Ram,
+        start: 0,
+        size: 4096,
+    }])
+    .unwrap();
+    for (addr, words) in [(LIST_ADDR, list), (DATA_ADDR, data)] {
+        for (offset, word) in words.iter().enumerate() {
+            memory.write(B32(*word), B32(addr + offset as u32), true);
+        }
+    }
+    memory
+}
+/// runs the list on the next cycle, like a program that wrote its address
+fn run_list(gpu: &mut Gpu, memory: &Memory) -> B32 {
+    gpu.write(0, GPU_COMMAND_LIST, B32(LIST_ADDR)).unwrap();
+    assert_eq!(gpu.read(0, GPU_COMMAND_LIST).unwrap(), B32(GPU_LIST_BUSY));
+    gpu.tick(memory, &mut |_, _| panic!("no interrupt before vblank"));
+    gpu.read(0, GPU_COMMAND_LIST).unwrap()
+}
+/// color of the pixel at x, y of the back buffer
+fn at(display: &SharedDisplay, x: u32, y: u32) -> u32 {
+    display.lock().unwrap().pixel((y * 8 + x) * 4).0
+}
+
+#[test]
+fn command_list_clears_fills_and_draws_lines() {
+    let display = display();
+    let mut gpu = Gpu::new(display.clone());
+    let memory = list_memory(
+        &[
+            GPU_CLEAR,
+            BLUE,
+            // half of it is left of the frame
+            GPU_FILL_RECT,
+            -1i32 as u32,
+            1,
+            2,
+            2,
+            RED,
+            GPU_LINE,
+            7,
+            0,
+            7,
+            7,
+            RED,
+            GPU_END,
+        ],
+        &[],
+    );
+    assert_eq!(run_list(&mut gpu, &memory), B32(GPU_LIST_DONE));
+    assert_eq!(at(&display, 0, 0), BLUE);
+    assert_eq!(at(&display, 0, 1), RED);
+    assert_eq!(at(&display, 0, 2), RED);
+    assert_eq!(at(&display, 1, 1), BLUE);
+    for y in 0..8 {
+        assert_eq!(at(&display, 7, y), RED, "y: {y}");
+    }
+}
+
+#[test]
+fn command_list_blits_with_palette_and_color_key() {
+    let display = display();
+    let mut gpu = Gpu::new(display.clone());
+    let memory = list_memory(
+        &[
+            GPU_SET_PALETTE,
+            DATA_ADDR,
+            20,
+            2,
+            // 2x2 sprite of palette entries, 0 is transparent
+            GPU_BLIT | GPU_FLAG_PALETTE | GPU_FLAG_COLOR_KEY,
+            DATA_ADDR + 2,
+            3,
+            4,
+            2,
+            2,
+            0,
+            GPU_FILL_RECT | GPU_FLAG_PALETTE,
+            0,
+            0,
+            1,
+            1,
+            21,
+            GPU_END,
+        ],
+        &[RED, BLUE, 20, 0, 21, 20],
+    );
+    assert_eq!(run_list(&mut gpu, &memory), B32(GPU_LIST_DONE));
+    assert_eq!(at(&display, 3, 4), RED);
+    assert_eq!(at(&display, 4, 4), 0);
+    assert_eq!(at(&display, 3, 5), BLUE);
+    assert_eq!(at(&display, 4, 5), RED);
+    assert_eq!(at(&display, 0, 0), BLUE);
+}
+
+#[test]
+fn invalid_command_fails_the_list() {
+    let display = display();
+    let mut gpu = Gpu::new(display.clone());
+    // commands before the bad one were already drawn
+    let memory = list_memory(&[GPU_CLEAR, BLUE, 99, GPU_CLEAR, RED, GPU_END], &[]);
+    assert_eq!(run_list(&mut gpu, &memory), B32(GPU_LIST_FAILED));
+    assert_eq!(at(&display, 0, 0), BLUE);
+
+    for list in [
+        vec![GPU_CLEAR | GPU_FLAG_COLOR_KEY, RED, GPU_END],
+        vec![GPU_CLEAR | GPU_FLAG_PALETTE, 256, GPU_END],
+        vec![GPU_FILL_RECT, 0, 40000, 1, 1, RED, GPU_END],
+        vec![GPU_SET_PALETTE, DATA_ADDR, 255, 2, GPU_END],
+    ] {
+        let memory = list_memory(&list, &[]);
+        assert_eq!(
+            run_list(&mut gpu, &memory),
+            B32(GPU_LIST_FAILED),
+            "{list:?}"
+        );
+    }
+    assert_eq!(at(&display, 0, 0), BLUE);
+
+    // second list while the first one waits drops both
+    let memory = list_memory(&[GPU_CLEAR, RED, GPU_END], &[]);
+    gpu.write(0, GPU_COMMAND_LIST, B32(LIST_ADDR)).unwrap();
+    gpu.write(0, GPU_COMMAND_LIST, B32(LIST_ADDR)).unwrap();
+    gpu.tick(&memory, &mut |_, _| {});
+    assert_eq!(gpu.read(0, GPU_COMMAND_LIST).unwrap(), B32(GPU_LIST_FAILED));
+    assert_eq!(at(&display, 0, 0), BLUE);
+}
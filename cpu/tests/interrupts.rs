@@ -0,0 +1,132 @@
+mod common;
+
+use common::{IDT_ADDR, entered_interrupt, program_machine, set_fault_handlers};
+use cpu::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{
+            CPU_REGISTER_1, CPU_REGISTER_2, CURRENT_ADDR_REGISTER, INTERRUPT_MASK_NESTING_BIT,
+            InterruptKind, instructions::Instruction,
+        },
+    },
+    machine::Machine,
+};
+
+/// `Set` of the register to value
+fn set(register: u8, value: u32) -> Vec<B32> {
+    vec![Instruction::Set(B8(register)).into(), B32(value)]
+}
+/// interrupt of kind with data to thread 0, thru r20 - r22
+fn intr(kind: InterruptKind, data: u32) -> Vec<B32> {
+    [
+        set(20, 0),
+        set(21, kind as u32),
+        set(22, data),
+        vec![Instruction::Intr(B8(20), B8(21), B8(22)).into()],
+    ]
+    .concat()
+}
+fn set_mask(mask: u32) -> Vec<B32> {
+    [set(23, mask), vec![Instruction::Smsk(B8(23)).into()]].concat()
+}
+
+#[test]
+fn entry_keeps_registers_of_the_interrupted_code() {
+    let mut machine = program_machine(vec![
+        Instruction::Set(CPU_REGISTER_1).into(),
+        B32(77),
+        Instruction::Set(CPU_REGISTER_2).into(),
+        B32(66),
+        Instruction::Set(B8(1)).into(),
+        B32(0),
+        Instruction::Set(B8(2)).into(),
+        B32(InterruptKind::Ipi as u32),
+        Instruction::Intr(B8(1), B8(2), B8(1)).into(),
+        Instruction::Halt().into(),
+    ]);
+    set_fault_handlers(&machine);
+    machine.run_cycles(7).unwrap();
+    assert_eq!(entered_interrupt(&machine), (0, B32(9)));
+    let thread = &machine.threads[0];
+    assert_eq!(thread.read_register(CPU_REGISTER_1), B32(77));
+    assert_eq!(thread.read_register(CPU_REGISTER_2), B32(66));
+}
+
+#[test]
+fn masked_interrupt_waits_for_the_mask() {
+    let program = [
+        set_mask(1 << InterruptKind::Ipi as u32),
+        intr(InterruptKind::Ipi, 5),
+        set(1, 1),
+        set_mask(0),
+        vec![Instruction::Halt().into()],
+    ]
+    .concat();
+    let return_addr = program.len() as u32 - 1;
+    let mut machine = program_machine(program);
+    set_fault_handlers(&machine);
+    machine.run_cycles(40).unwrap();
+    // code after the masked interrupt ran, it entered once the mask was cleared
+    assert_eq!(machine.threads[0].read_register(B8(1)), B32(1));
+    assert_eq!(entered_interrupt(&machine), (5, B32(return_addr)));
+}
+
+/// handler of the ipi at this address sends timer interrupt to its thread and then loops at
+/// `IPI_LOOP_ADDR` forever
+const IPI_HANDLER_ADDR: u32 = 1200;
+const IPI_LOOP_ADDR: u32 = IPI_HANDLER_ADDR + 10;
+/// runs ipi whose handler raises the timer interrupt, with the mask
+fn nested_machine(mask: u32) -> Machine {
+    let program = [
+        set_mask(mask),
+        intr(InterruptKind::Ipi, 5),
+        vec![Instruction::Halt().into()],
+    ]
+    .concat();
+    let machine = program_machine(program);
+    set_fault_handlers(&machine);
+    let handler = [
+        intr(InterruptKind::Timer, 77),
+        set(24, IPI_LOOP_ADDR - 1),
+        vec![Instruction::Jmp(B8(24)).into()],
+    ]
+    .concat();
+    assert_eq!(IPI_HANDLER_ADDR + handler.len() as u32, IPI_LOOP_ADDR);
+    for (offset, word) in handler.into_iter().enumerate() {
+        machine
+            .memory
+            .write(word, B32(IPI_HANDLER_ADDR + 1 + offset as u32), true);
+    }
+    machine.memory.write(
+        B32(IPI_HANDLER_ADDR),
+        B32(IDT_ADDR + InterruptKind::Ipi as u32),
+        true,
+    );
+    machine
+}
+
+#[test]
+fn nesting_lets_timer_preempt_ipi_handler() {
+    let mut machine = nested_machine(1 << INTERRUPT_MASK_NESTING_BIT);
+    machine.run_cycles(60).unwrap();
+    // timer entered right after the intr of the ipi handler
+    let intr_len = intr(InterruptKind::Timer, 0).len() as u32;
+    assert_eq!(
+        entered_interrupt(&machine),
+        (77, B32(IPI_HANDLER_ADDR + 1 + intr_len))
+    );
+}
+
+#[test]
+fn without_nesting_timer_waits_for_the_ipi_handler() {
+    let mut machine = nested_machine(0);
+    machine.run_cycles(60).unwrap();
+    // the timer waits until the ipi handler returns, which it never does
+    let thread = &machine.threads[0];
+    assert_eq!(thread.read_register(B8(22)), B32(77));
+    assert_eq!(
+        thread.read_register(CURRENT_ADDR_REGISTER),
+        B32(IPI_LOOP_ADDR)
+    );
+}
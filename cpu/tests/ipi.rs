@@ -0,0 +1,48 @@
+mod common;
+
+use common::{entered_interrupt, program_machine, set_fault_handlers};
+use cpu::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{InterruptKind, instructions::Instruction},
+    },
+    machine::Machine,
+};
+
+/// thread 0 sends interrupt of this kind with data 1234 to itself
+fn intr_machine(kind: u32) -> Machine {
+    let machine = program_machine(vec![
+        Instruction::Set(B8(1)).into(),
+        B32(0),
+        Instruction::Set(B8(2)).into(),
+        B32(kind),
+        Instruction::Set(B8(3)).into(),
+        B32(1234),
+        Instruction::Intr(B8(1), B8(2), B8(3)).into(),
+        Instruction::Halt().into(),
+    ]);
+    set_fault_handlers(&machine);
+    machine
+}
+
+#[test]
+fn ipi_carries_its_message() {
+    let mut machine = intr_machine(InterruptKind::Ipi as u32);
+    machine.run_cycles(6).unwrap();
+    assert_eq!(entered_interrupt(&machine), (1234, B32(7)));
+}
+
+#[test]
+fn intr_with_invalid_kind_raises_illegal_instruction() {
+    for kind in [InterruptKind::Syscall as u32, 99] {
+        let mut machine = intr_machine(kind);
+        machine.run_cycles(6).unwrap();
+        let instruction = B32::from(Instruction::Intr(B8(1), B8(2), B8(3)));
+        assert_eq!(
+            entered_interrupt(&machine),
+            (instruction.0, B32(6)),
+            "kind: {kind}"
+        );
+    }
+}
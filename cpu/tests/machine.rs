@@ -0,0 +1,34 @@
+mod common;
+
+use common::{COUNTER_ADDR, counter_machine};
+use cpu::chips::{b8::B8, b32::B32};
+
+#[test]
+fn machines_do_not_share_state() {
+    let mut running = counter_machine();
+    let idle = counter_machine();
+    running.run_cycles(3 + 4 * 10).unwrap();
+
+    assert_eq!(running.memory.read(B32(COUNTER_ADDR)), B32(10));
+    assert_eq!(running.cycle(), 43);
+    assert_eq!(idle.memory.read(B32(COUNTER_ADDR)), B32(0));
+    assert_eq!(idle.cycle(), 0);
+    assert_eq!(idle.threads[0].read_register(B8(3)), B32(0));
+}
+
+#[test]
+fn machines_run_on_separate_host_threads() {
+    let counters: Vec<B32> = (0..4)
+        .map(|_| {
+            std::thread::spawn(|| {
+                let mut machine = counter_machine();
+                machine.run_cycles(3 + 4 * 1000).unwrap();
+                machine.memory.read(B32(COUNTER_ADDR))
+            })
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    assert_eq!(counters, vec![B32(1000); 4]);
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use common::{entered_interrupt, program_machine, set_fault_handlers};
+use cpu::{
+    chips::{b8::B8, b32::B32, thread::instructions::Instruction},
+    machine::Machine,
+    memory_map::{DEFAULT_MAILBOX_ADDR, MemoryMap},
+    peripherals::{
+        Peripheral,
+        mailbox::{
+            MAILBOX_CONTROL, MAILBOX_DATA, MAILBOX_DOORBELL, MAILBOX_EMPTY, MAILBOX_FULL,
+            MAILBOX_LEN, MAILBOX_NO_THREAD, MAILBOX_SENT, MAILBOX_STATUS, Mailbox,
+        },
+    },
+};
+
+/// writes value to the mailbox register at offset, thru r1 and r2
+fn write_register(offset: u32, value: u32) -> Vec<B32> {
+    vec![
+        Instruction::Set(B8(1)).into(),
+        B32(DEFAULT_MAILBOX_ADDR + offset),
+        Instruction::Set(B8(2)).into(),
+        B32(value),
+        Instruction::Write(B8(1), B8(2)).into(),
+    ]
+}
+/// reads the mailbox register at offset into the register, thru r1
+fn read_register(offset: u32, register: u8) -> Vec<B32> {
+    vec![
+        Instruction::Set(B8(1)).into(),
+        B32(DEFAULT_MAILBOX_ADDR + offset),
+        Instruction::Read(B8(register), B8(1)).into(),
+    ]
+}
+/// message of thread 0 to the mailbox of the receiver
+fn send(message: u32, receiver: u32) -> Vec<B32> {
+    [
+        write_register(MAILBOX_DATA, message),
+        write_register(MAILBOX_DOORBELL, receiver),
+    ]
+    .concat()
+}
+
+#[test]
+fn messages_wait_in_order_until_read() {
+    let program = [
+        send(41, 0),
+        send(42, 0),
+        read_register(MAILBOX_DOORBELL, 10),
+        read_register(MAILBOX_STATUS, 11),
+        read_register(MAILBOX_DATA, 12),
+        read_register(MAILBOX_DATA, 13),
+        read_register(MAILBOX_DATA, 14),
+        vec![Instruction::Halt().into()],
+    ]
+    .concat();
+    let mut machine = program_machine(program);
+    machine.run_cycles(100).unwrap();
+    let thread = &machine.threads[0];
+    assert!(thread.is_halting());
+    assert_eq!(thread.read_register(B8(10)), B32(MAILBOX_SENT));
+    assert_eq!(thread.read_register(B8(11)), B32(2));
+    assert_eq!(thread.read_register(B8(12)), B32(41));
+    assert_eq!(thread.read_register(B8(13)), B32(42));
+    assert_eq!(thread.read_register(B8(14)), B32(MAILBOX_EMPTY));
+}
+
+#[test]
+fn full_mailbox_keeps_the_message_for_the_next_doorbell() {
+    let mut mailbox = Mailbox::new();
+    for message in 0..MAILBOX_LEN as u32 {
+        mailbox.write(0, MAILBOX_DATA, B32(message)).unwrap();
+        mailbox.write(0, MAILBOX_DOORBELL, B32(1)).unwrap();
+        assert_eq!(
+            mailbox.read(0, MAILBOX_DOORBELL).unwrap(),
+            B32(MAILBOX_SENT)
+        );
+    }
+    mailbox.write(0, MAILBOX_DATA, B32(1000)).unwrap();
+    mailbox.write(0, MAILBOX_DOORBELL, B32(1)).unwrap();
+    assert_eq!(
+        mailbox.read(0, MAILBOX_DOORBELL).unwrap(),
+        B32(MAILBOX_FULL)
+    );
+    assert_eq!(
+        mailbox.read(1, MAILBOX_STATUS).unwrap(),
+        B32(MAILBOX_LEN as u32)
+    );
+
+    assert_eq!(mailbox.read(1, MAILBOX_DATA).unwrap(), B32(0));
+    mailbox.write(0, MAILBOX_DOORBELL, B32(1)).unwrap();
+    assert_eq!(
+        mailbox.read(0, MAILBOX_DOORBELL).unwrap(),
+        B32(MAILBOX_SENT)
+    );
+    let messages: Vec<u32> = (0..MAILBOX_LEN)
+        .map(|_| mailbox.read(1, MAILBOX_DATA).unwrap().0)
+        .collect();
+    assert_eq!(messages.last(), Some(&1000));
+
+    mailbox.write(0, MAILBOX_DOORBELL, B32(u32::MAX)).unwrap();
+    assert_eq!(
+        mailbox.read(0, MAILBOX_DOORBELL).unwrap(),
+        B32(MAILBOX_NO_THREAD)
+    );
+}
+
+#[test]
+fn doorbell_raises_mailbox_interrupt_when_enabled() {
+    let program = [
+        write_register(MAILBOX_CONTROL, 1),
+        send(7, 0),
+        vec![Instruction::Halt().into()],
+    ]
+    .concat();
+    let mut machine = program_machine(program);
+    set_fault_handlers(&machine);
+    machine.run_cycles(100).unwrap();
+    let (count, _) = entered_interrupt(&machine);
+    assert_eq!(count, 1);
+    let mailbox = machine.peripheral_mut::<Mailbox>().unwrap();
+    assert_eq!(mailbox.read(0, MAILBOX_DATA).unwrap(), B32(7));
+}
+
+#[test]
+fn messages_survive_snapshot() {
+    let mut machine = Machine::new(2, &MemoryMap::default()).unwrap();
+    let mailbox = machine.peripheral_mut::<Mailbox>().unwrap();
+    mailbox.write(1, MAILBOX_CONTROL, B32(1)).unwrap();
+    for message in [5, 6] {
+        mailbox.write(0, MAILBOX_DATA, B32(message)).unwrap();
+        mailbox.write(0, MAILBOX_DOORBELL, B32(1)).unwrap();
+    }
+    let path = common::temp_path("mailbox.snapshot");
+    machine.save_snapshot(&path).unwrap();
+    let mut loaded = Machine::load_snapshot(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let mailbox = loaded.peripheral_mut::<Mailbox>().unwrap();
+    assert_eq!(mailbox.read(1, MAILBOX_CONTROL).unwrap(), B32(1));
+    assert_eq!(mailbox.read(1, MAILBOX_STATUS).unwrap(), B32(2));
+    assert_eq!(mailbox.read(1, MAILBOX_DATA).unwrap(), B32(5));
+    assert_eq!(mailbox.read(1, MAILBOX_DATA).unwrap(), B32(6));
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use common::{entered_interrupt, set_fault_handlers};
+use cpu::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{FaultCause, STACK_HEAD_REGISTER, instructions::Instruction},
+    },
+    machine::Machine,
+    memory_map::{MemoryMap, Region, RegionKind},
+    test_load_memory,
+};
+
+const ROM_ADDR: u32 = 0x20000;
+
+fn region(kind: RegionKind, start: u32, size: u32) -> Region {
+    Region { kind, start, size }
+}
+
+#[test]
+fn region_parses_from_the_command_line_form() {
+    assert_eq!(
+        "ram:0:0x40000".parse::<Region>().unwrap(),
+        region(RegionKind::Ram, 0, 0x40000)
+    );
+    assert_eq!(
+        "rom:4096:4096".parse::<Region>().unwrap(),
+        region(RegionKind::Rom, 4096, 4096)
+    );
+    for text in ["ram:0", "disk:0:4096", "ram:x:4096", "mmio:0:0x"] {
+        assert!(text.parse::<Region>().is_err(), "{text}");
+    }
+}
+
+#[test]
+fn regions_have_to_be_aligned_and_not_overlap() {
+    let ram = region(RegionKind::Ram, 0, 0x10000);
+    assert!(MemoryMap::validate_regions(&[ram, region(RegionKind::Rom, 0x10000, 4096)]).is_ok());
+    for other in [
+        region(RegionKind::Rom, 0x8000, 4096),
+        region(RegionKind::Rom, 0x10001, 4096),
+        region(RegionKind::Rom, 0x10000, 100),
+        region(RegionKind::Mmio, 0x10000, 0),
+        region(RegionKind::Mmio, 0xffff_f000, 0x2000),
+    ] {
+        assert!(
+            MemoryMap::validate_regions(&[ram, other]).is_err(),
+            "{other:?}"
+        );
+    }
+}
+
+#[test]
+fn map_file_sets_regions_devices_and_layout() {
+    let path = common::temp_path("memory_map.toml");
+    std::fs::write(
+        &path,
+        r#"
+os_size = 1000
+command_line_size = 500
+heap_size = 2000
+stack_size = 300
+
+[[region]]
+kind = "ram"
+start = 0x1000
+size = 0x2000
+
+[[region]]
+kind = "mmio"
+start = 0x100000
+size = 4096
+
+[[device]]
+name = "serial"
+start = 0x100000
+"#,
+    )
+    .unwrap();
+    let map = MemoryMap::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(map.regions[0], region(RegionKind::Ram, 0x1000, 0x2000));
+    assert_eq!(map.device_addr("serial"), Some(0x100000));
+    assert_eq!(map.device_addr("gpu"), None);
+    let layout = map.layout(2).unwrap();
+    assert_eq!(layout.os_addr, 0x1000);
+    assert_eq!(layout.command_line_addr, 0x1000 + 1000);
+    assert_eq!(layout.heap_start, 0x1000 + 1500);
+    assert_eq!(layout.heap_end, 0x1000 + 3500);
+    assert_eq!(layout.stack_base_addr(1), B32(0x1000 + 3800));
+    assert_eq!(layout.serial_addr, 0x100000);
+    assert_eq!(layout.framebuffer_addr, u32::MAX);
+    // 8192 words of ram fit 3500 words and 15 stacks, not 16
+    assert!(map.layout(15).is_ok());
+    assert!(map.layout(16).is_err());
+}
+
+/// machine with ram, rom after a hole and stacks placed by the layout
+fn rom_machine(program: Vec<B32>) -> Machine {
+    let map = MemoryMap {
+        regions: vec![
+            region(RegionKind::Ram, 0, 0x10000),
+            region(RegionKind::Rom, ROM_ADDR, 4096),
+        ],
+        devices: Vec::new(),
+        ..MemoryMap::default()
+    };
+    let machine = Machine::new(1, &map).unwrap();
+    let stack = map.layout(1).unwrap().stack_base_addr(0);
+    assert_eq!(machine.threads[0].read_register(STACK_HEAD_REGISTER), stack);
+    test_load_memory(&machine.memory, program);
+    machine
+}
+
+#[test]
+fn rom_is_read_only_and_the_hole_reads_as_nothing() {
+    let mut machine = rom_machine(vec![
+        Instruction::Set(B8(1)).into(),
+        B32(ROM_ADDR),
+        Instruction::Read(B8(2), B8(1)).into(),
+        Instruction::Write(B8(1), B8(1)).into(),
+        Instruction::Halt().into(),
+    ]);
+    set_fault_handlers(&machine);
+    // the loader can write rom
+    machine.memory.write(B32(7), B32(ROM_ADDR), true);
+    machine.memory.write(B32(7), B32(0x18000), true);
+    assert_eq!(machine.memory.read(B32(0x18000)), B32(0));
+
+    machine.run_cycles(10).unwrap();
+    assert_eq!(machine.threads[0].read_register(B8(2)), B32(7));
+    assert_eq!(
+        entered_interrupt(&machine),
+        (FaultCause::Write as u32, B32(3))
+    );
+    assert_eq!(machine.memory.read(B32(ROM_ADDR)), B32(7));
+}
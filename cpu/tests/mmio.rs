@@ -0,0 +1,157 @@
+mod common;
+
+use anyhow::Result;
+use common::program_machine;
+use cpu::{
+    chips::{b8::B8, b32::B32, thread::instructions::Instruction},
+    machine::Machine,
+    memory_map::{DEFAULT_MMIO_ADDR, DEFAULT_SERIAL_ADDR},
+    peripherals::{Peripheral, Peripherals},
+    snapshot::{SnapshotReader, SnapshotWriter},
+};
+
+const INDEX: u32 = 20;
+/// free space between the mailbox and the console of the default map
+const ADDR: u32 = DEFAULT_MMIO_ADDR + 64;
+
+/// 4 words that threads can read and write
+#[derive(Default)]
+struct Registers([u32; 4]);
+impl Peripheral for Registers {
+    fn name(&self) -> &str {
+        "registers"
+    }
+    fn size(&self) -> u32 {
+        4
+    }
+    fn read(&mut self, _thread_index: usize, offset: u32) -> Result<B32> {
+        Ok(B32(self.0[offset as usize]))
+    }
+    fn write(&mut self, _thread_index: usize, offset: u32, data: B32) -> Result<()> {
+        self.0[offset as usize] = data.0;
+        Ok(())
+    }
+    fn reset(&mut self) {
+        self.0 = [0; 4];
+    }
+    fn write_snapshot(&self, writer: &mut SnapshotWriter) {
+        for word in self.0 {
+            writer.u32(word);
+        }
+    }
+    fn read_snapshot(&mut self, reader: &mut SnapshotReader) -> Result<()> {
+        for word in &mut self.0 {
+            *word = reader.u32()?;
+        }
+        Ok(())
+    }
+}
+
+/// writes 5 to the second word thru the bus, 6 to the first one with `Phrp` and reads both into
+/// r4 and r5
+fn registers_machine() -> Machine {
+    let mut machine = program_machine(vec![
+        Instruction::Set(B8(1)).into(),
+        B32(ADDR + 1),
+        Instruction::Set(B8(2)).into(),
+        B32(5),
+        Instruction::Write(B8(1), B8(2)).into(),
+        Instruction::Set(B8(3)).into(),
+        B32(INDEX),
+        Instruction::Set(B8(2)).into(),
+        B32(6),
+        Instruction::Phrp(B8(3), B8(2)).into(),
+        Instruction::Read(B8(5), B8(1)).into(),
+        Instruction::Set(B8(1)).into(),
+        B32(ADDR),
+        Instruction::Read(B8(4), B8(1)).into(),
+        Instruction::Halt().into(),
+    ]);
+    machine
+        .register_peripheral(INDEX, Box::new(Registers::default()))
+        .unwrap();
+    machine.map_peripheral("registers", ADDR).unwrap();
+    machine
+}
+
+#[test]
+fn bus_and_phrp_reach_the_registered_device() {
+    let mut machine = registers_machine();
+    machine.run_cycles(20).unwrap();
+    let thread = &machine.threads[0];
+    assert!(thread.is_halting());
+    assert_eq!(thread.read_register(B8(4)), B32(6));
+    assert_eq!(thread.read_register(B8(5)), B32(5));
+    // the device is not backed by memory
+    assert_eq!(machine.memory.read(B32(ADDR + 1)), B32(0));
+    assert_eq!(
+        machine.peripheral_mut::<Registers>().unwrap().0,
+        [6, 5, 0, 0]
+    );
+}
+
+#[test]
+fn devices_need_free_index_name_and_addresses() {
+    let mut machine = program_machine(Vec::new());
+    machine
+        .register_peripheral(INDEX, Box::new(Registers::default()))
+        .unwrap();
+    // index and name are taken
+    assert!(
+        machine
+            .register_peripheral(INDEX, Box::new(Registers::default()))
+            .is_err()
+    );
+    assert!(
+        machine
+            .register_peripheral(INDEX + 1, Box::new(Registers::default()))
+            .is_err()
+    );
+    let mut peripherals = Peripherals::new();
+    assert!(
+        peripherals
+            .register(INDEX, Box::new(Registers::default()))
+            .is_ok()
+    );
+    assert_eq!(peripherals.index_of("registers"), Some(INDEX));
+
+    for addr in [
+        // serial is there
+        DEFAULT_SERIAL_ADDR + 2,
+        // ram
+        100,
+        // mmio region ends in the middle of the device
+        DEFAULT_MMIO_ADDR - 2,
+    ] {
+        assert!(
+            machine.map_peripheral("registers", addr).is_err(),
+            "{addr:#x}"
+        );
+    }
+    assert!(machine.map_peripheral("nothing", ADDR).is_err());
+    machine.map_peripheral("registers", ADDR).unwrap();
+}
+
+#[test]
+fn snapshot_keeps_mapping_of_registered_device() {
+    let mut machine = registers_machine();
+    machine.run_cycles(20).unwrap();
+    let path = common::temp_path("mmio.snapshot");
+    machine.save_snapshot(&path).unwrap();
+
+    // without the device its state and mapping are skipped
+    Machine::load_snapshot(&path).unwrap();
+
+    let mut peripherals = Peripherals::new();
+    peripherals
+        .register(INDEX, Box::new(Registers::default()))
+        .unwrap();
+    let mut loaded = Machine::load_snapshot_with_peripherals(&path, peripherals).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        loaded.peripheral_mut::<Registers>().unwrap().0,
+        [6, 5, 0, 0]
+    );
+    // it's mapped at ADDR again
+    assert!(loaded.map_peripheral("registers", ADDR + 1).is_err());
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use std::sync::atomic::Ordering;
+
+use common::{entered_interrupt, program_machine, set_fault_handlers};
+use cpu::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{
+            instructions::Instruction,
+            mmu::{
+                MemoryAccessKind, PAGE_PRESENT_BIT, PAGE_SIZE, PAGE_TABLE_LEN, PAGE_WRITABLE_BIT,
+            },
+        },
+    },
+    machine::Machine,
+};
+
+const PAGE_TABLE_ADDR: u32 = 8 * PAGE_SIZE;
+/// mapped to `FRAME`
+const MAPPED_PAGE: u32 = 100;
+const FRAME: u32 = 120;
+const UNMAPPED_PAGE: u32 = 101;
+const READ_ONLY_PAGE: u32 = 102;
+
+fn page_addr(page: u32, offset: u32) -> B32 {
+    B32(page * PAGE_SIZE + offset)
+}
+/// pages other than the three above are mapped to the frame with their own index, so code,
+/// stack and the IDT are where they would be without paging
+fn write_page_table(machine: &Machine) {
+    let present = 1 << PAGE_PRESENT_BIT;
+    let writable = 1 << PAGE_WRITABLE_BIT;
+    for page in 0..PAGE_TABLE_LEN {
+        let entry = match page {
+            MAPPED_PAGE => present | writable | FRAME,
+            UNMAPPED_PAGE => 0,
+            READ_ONLY_PAGE => present | page,
+            page => present | writable | page,
+        };
+        machine
+            .memory
+            .write(B32(entry), B32(PAGE_TABLE_ADDR + page), true);
+    }
+}
+/// turns paging on with `Pgt` and then runs the program
+fn paged_machine(program: Vec<B32>) -> Machine {
+    let program = [
+        vec![
+            Instruction::Set(B8(10)).into(),
+            B32(PAGE_TABLE_ADDR),
+            Instruction::Pgt(B8(10)).into(),
+        ],
+        program,
+        vec![Instruction::Halt().into()],
+    ]
+    .concat();
+    let machine = program_machine(program);
+    write_page_table(&machine);
+    set_fault_handlers(&machine);
+    machine
+}
+fn set(register: u8, value: B32) -> Vec<B32> {
+    vec![Instruction::Set(B8(register)).into(), value]
+}
+
+#[test]
+fn virtual_address_goes_to_the_frame_of_its_page() {
+    let mut machine = paged_machine(
+        [
+            set(1, page_addr(MAPPED_PAGE, 5)),
+            set(2, B32(42)),
+            vec![
+                Instruction::Write(B8(1), B8(2)).into(),
+                Instruction::Read(B8(3), B8(1)).into(),
+            ],
+        ]
+        .concat(),
+    );
+    machine.run_cycles(20).unwrap();
+    let thread = &machine.threads[0];
+    assert!(thread.is_halting());
+    assert_eq!(thread.read_register(B8(3)), B32(42));
+    assert_eq!(machine.memory.read(page_addr(FRAME, 5)), B32(42));
+    assert_eq!(machine.memory.read(page_addr(MAPPED_PAGE, 5)), B32(0));
+}
+
+#[test]
+fn unmapped_page_raises_page_fault() {
+    let addr = page_addr(UNMAPPED_PAGE, 7);
+    let mut machine =
+        paged_machine([set(1, addr), vec![Instruction::Read(B8(3), B8(1)).into()]].concat());
+    machine.run_cycles(20).unwrap();
+    // the fault returns to the read, so it runs again after the handler
+    assert_eq!(entered_interrupt(&machine), (addr.0, B32(5)));
+}
+
+#[test]
+fn write_to_read_only_page_raises_page_fault() {
+    let addr = page_addr(READ_ONLY_PAGE, 3);
+    let mut machine = paged_machine(
+        [
+            set(1, addr),
+            vec![
+                Instruction::Read(B8(3), B8(1)).into(),
+                Instruction::Write(B8(1), B8(1)).into(),
+            ],
+        ]
+        .concat(),
+    );
+    machine.memory.write(B32(9), addr, true);
+    machine.run_cycles(20).unwrap();
+    assert_eq!(entered_interrupt(&machine), (addr.0, B32(6)));
+    assert_eq!(machine.threads[0].read_register(B8(3)), B32(9));
+    assert_eq!(machine.memory.read(addr), B32(9));
+}
+
+#[test]
+fn pgt_0_turns_paging_off() {
+    let mut machine = paged_machine(
+        [
+            set(1, page_addr(MAPPED_PAGE, 5)),
+            set(2, B32(42)),
+            set(3, B32(0)),
+            vec![
+                Instruction::Pgt(B8(3)).into(),
+                Instruction::Write(B8(1), B8(2)).into(),
+            ],
+        ]
+        .concat(),
+    );
+    machine.run_cycles(20).unwrap();
+    let mmu = &machine.threads[0].mmu;
+    assert!(!mmu.paging_enabled.load(Ordering::Relaxed));
+    assert_eq!(mmu.page_table_addr.load(Ordering::Relaxed), 0);
+    assert_eq!(machine.memory.read(page_addr(MAPPED_PAGE, 5)), B32(42));
+    assert_eq!(machine.memory.read(page_addr(FRAME, 5)), B32(0));
+}
+
+#[test]
+fn page_table_survives_snapshot() {
+    let mut machine = paged_machine(Vec::new());
+    machine.run_cycles(5).unwrap();
+    let path = common::temp_path("mmu.snapshot");
+    machine.save_snapshot(&path).unwrap();
+    let loaded = Machine::load_snapshot(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let mmu = &loaded.threads[0].mmu;
+    assert!(mmu.paging_enabled.load(Ordering::Relaxed));
+    assert_eq!(mmu.page_table_addr.load(Ordering::Relaxed), PAGE_TABLE_ADDR);
+    assert_eq!(
+        mmu.translate(
+            &loaded.memory,
+            page_addr(MAPPED_PAGE, 5),
+            MemoryAccessKind::Read
+        ),
+        Some(page_addr(FRAME, 5))
+    );
+    assert_eq!(
+        mmu.translate(
+            &loaded.memory,
+            page_addr(UNMAPPED_PAGE, 0),
+            MemoryAccessKind::Read
+        ),
+        None
+    );
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::program_machine;
+use cpu::{
+    chips::{b8::B8, b32::B32, thread::instructions::Instruction},
+    machine::Machine,
+    rng::Rng,
+};
+
+/// r3 and then r4 get a number from 10 to 20
+fn rng_machine(seed: u64) -> Machine {
+    let mut machine = program_machine(vec![
+        Instruction::Set(B8(1)).into(),
+        B32(10),
+        Instruction::Set(B8(2)).into(),
+        B32(20),
+        Instruction::Rng(B8(3), B8(1), B8(2)).into(),
+        Instruction::Rng(B8(4), B8(1), B8(2)).into(),
+        Instruction::Halt().into(),
+    ]);
+    machine.seed_rng(seed);
+    machine
+}
+fn numbers(machine: &Machine) -> (B32, B32) {
+    let thread = &machine.threads[0];
+    (thread.read_register(B8(3)), thread.read_register(B8(4)))
+}
+
+#[test]
+fn range_includes_both_bounds_and_swaps_them() {
+    let rng = Rng::new(7);
+    let values: Vec<u32> = (0..1000).map(|_| rng.range(5, 3)).collect();
+    assert!(values.iter().all(|value| (3..=5).contains(value)));
+    for bound in [3, 5] {
+        assert!(values.contains(&bound), "{bound} never came");
+    }
+    assert_eq!(rng.range(9, 9), 9);
+    // the whole u32 span doesn't overflow
+    rng.range(0, u32::MAX);
+}
+
+#[test]
+fn same_seed_gives_same_numbers() {
+    let first = Rng::new(42);
+    let second = Rng::new(42);
+    let other = Rng::new(43);
+    let first: Vec<u64> = (0..10).map(|_| first.next_u64()).collect();
+    let second: Vec<u64> = (0..10).map(|_| second.next_u64()).collect();
+    let other: Vec<u64> = (0..10).map(|_| other.next_u64()).collect();
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+
+    let mut machines = [rng_machine(42), rng_machine(42)];
+    for machine in &mut machines {
+        machine.run_cycles(10).unwrap();
+        assert!(machine.threads[0].is_halting());
+    }
+    let (a, b) = numbers(&machines[0]);
+    assert!(
+        (10..=20).contains(&a.0) && (10..=20).contains(&b.0),
+        "{a} {b}"
+    );
+    assert_eq!(numbers(&machines[0]), numbers(&machines[1]));
+}
+
+#[test]
+fn snapshot_continues_the_sequence() {
+    let mut machine = rng_machine(42);
+    // both sets and the first rng
+    machine.run_cycles(3).unwrap();
+    let (first, second) = numbers(&machine);
+    assert!(first != B32(0) && second == B32(0), "{first} {second}");
+
+    let path = common::temp_path("rng.snapshot");
+    machine.save_snapshot(&path).unwrap();
+    let mut loaded = Machine::load_snapshot(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    machine.run_cycles(5).unwrap();
+    loaded.run_cycles(5).unwrap();
+    assert_ne!(numbers(&loaded).1, B32(0));
+    assert_eq!(numbers(&loaded), numbers(&machine));
+}
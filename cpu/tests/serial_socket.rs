@@ -0,0 +1,120 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::{
+    io::{Read, Write},
+    os::unix::net::UnixStream,
+    time::{Duration, Instant},
+};
+
+use cpu::{
+    chips::b32::B32,
+    memory_map::Memory,
+    peripherals::{
+        Peripheral,
+        serial::{SERIAL_DATA, SERIAL_EMPTY, SERIAL_STATUS, Serial},
+    },
+};
+
+/// moves bytes that came from clients into the fifo until it holds len of them
+fn receive(serial: &mut Serial, len: u32) -> Vec<u8> {
+    let memory = Memory::new(&[]).unwrap();
+    while serial.read(0, SERIAL_STATUS).unwrap().0 < len {
+        serial.tick(&memory, &mut |_, _| {});
+        std::thread::yield_now();
+    }
+    (0..len)
+        .map(|_| serial.read(0, SERIAL_DATA).unwrap().0 as u8)
+        .collect()
+}
+fn send(serial: &mut Serial, text: &[u8; 4]) {
+    serial
+        .write(0, SERIAL_DATA, B32(u32::from_le_bytes(*text)))
+        .unwrap();
+}
+
+#[test]
+fn unix_socket_round_trip() {
+    let path = common::temp_path("serial.sock");
+    let mut serial = Serial::named("serial1");
+    serial.listen_unix(&path).unwrap();
+
+    let mut client = UnixStream::connect(&path).unwrap();
+    client.write_all(b"ping").unwrap();
+    assert_eq!(receive(&mut serial, 4), b"ping");
+    assert_eq!(serial.read(0, SERIAL_DATA).unwrap(), B32(SERIAL_EMPTY));
+
+    send(&mut serial, b"pong");
+    let mut reply = [0; 4];
+    client.read_exact(&mut reply).unwrap();
+    assert_eq!(&reply, b"pong");
+
+    // socket left by the serial above is replaced
+    let mut serial = Serial::named("serial1");
+    serial.listen_unix(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn client_that_does_not_read_does_not_stop_the_serial() {
+    let path = common::temp_path("stalled_serial.sock");
+    let mut serial = Serial::named("serial1");
+    serial.listen_unix(&path).unwrap();
+
+    let mut stalled = UnixStream::connect(&path).unwrap();
+    let mut reading = UnixStream::connect(&path).unwrap();
+    stalled.write_all(b"1").unwrap();
+    reading.write_all(b"2").unwrap();
+    receive(&mut serial, 2);
+    std::fs::remove_file(&path).unwrap();
+
+    // reading client gets output even though it can't keep up and some of it is dropped
+    let reader = std::thread::spawn(move || {
+        let mut received = Vec::new();
+        let mut buf = [0; 4096];
+        while !received.chunks_exact(4).any(|word| word == b"end!") {
+            let len = reading.read(&mut buf).unwrap();
+            assert!(len > 0, "serial disconnected the reading client");
+            received.extend_from_slice(&buf[..len]);
+        }
+    });
+    // far more than socket buffers hold, the stalled client would block a direct write
+    for _ in 0..1_000_000 {
+        send(&mut serial, b"data");
+    }
+    while !reader.is_finished() {
+        send(&mut serial, b"end!");
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    reader.join().unwrap();
+    // connected, but never read, until here
+    drop(stalled);
+}
+
+#[test]
+fn dropped_serial_disconnects_clients_and_stops_listening() {
+    let path = common::temp_path("dropped_serial.sock");
+    let mut serial = Serial::named("serial1");
+    serial.listen_unix(&path).unwrap();
+    let mut client = UnixStream::connect(&path).unwrap();
+    client.write_all(b"1").unwrap();
+    receive(&mut serial, 1);
+
+    drop(serial);
+    client
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let mut buf = [0; 4];
+    assert_eq!(client.read(&mut buf).unwrap(), 0);
+    // listener is closed right after its clients
+    let start = Instant::now();
+    while UnixStream::connect(&path).is_ok() {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "serial still listens"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    std::fs::remove_file(&path).unwrap();
+}
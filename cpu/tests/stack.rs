@@ -0,0 +1,95 @@
+mod common;
+
+use common::{entered_interrupt, program_machine, set_fault_handlers};
+use cpu::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{FaultCause, STACK_HEAD_REGISTER, instructions::Instruction},
+    },
+    machine::Machine,
+    memory_map::MemoryMap,
+};
+
+fn stack_base() -> B32 {
+    MemoryMap::default().layout(1).unwrap().stack_base_addr(0)
+}
+fn stack_size() -> u32 {
+    MemoryMap::default().stack_size
+}
+
+#[test]
+fn push_pop_call_and_ret() {
+    let mut machine = program_machine(vec![
+        Instruction::Set(B8(1)).into(),
+        B32(7),
+        Instruction::Push(B8(1)).into(),
+        Instruction::Pop(B8(2)).into(),
+        Instruction::Set(B8(3)).into(),
+        B32(9),
+        // goes to 10, returns to 7
+        Instruction::Call(B8(3)).into(),
+        Instruction::Set(B8(4)).into(),
+        B32(42),
+        Instruction::Halt().into(),
+        Instruction::Set(B8(5)).into(),
+        B32(5),
+        Instruction::Ret().into(),
+    ]);
+    machine.run_cycles(9).unwrap();
+
+    let thread = &machine.threads[0];
+    assert!(thread.is_halting());
+    assert_eq!(thread.read_register(B8(2)), B32(7));
+    assert_eq!(thread.read_register(B8(5)), B32(5));
+    assert_eq!(thread.read_register(B8(4)), B32(42));
+    assert_eq!(thread.read_register(STACK_HEAD_REGISTER), stack_base());
+    // the call pushed its own address
+    assert_eq!(machine.memory.read(stack_base()), B32(6));
+}
+
+/// machine whose only instruction is at 0, with the stack head set to head
+fn stack_machine(instruction: Instruction, head: B32) -> Machine {
+    let machine = program_machine(vec![instruction.into()]);
+    machine.threads[0].write_register(STACK_HEAD_REGISTER, head);
+    machine.threads[0].write_register(B8(1), B32(7));
+    machine
+}
+
+#[test]
+fn push_above_the_stack_raises_bad_address() {
+    let full = stack_base() + B32(stack_size());
+    for instruction in [Instruction::Push(B8(1)), Instruction::Call(B8(1))] {
+        let mut machine = stack_machine(instruction, full);
+        set_fault_handlers(&machine);
+        machine.run_cycles(3).unwrap();
+        assert_eq!(
+            entered_interrupt(&machine),
+            (FaultCause::StackOverflow as u32, B32(0)),
+            "{instruction:?}"
+        );
+        assert_eq!(machine.memory.read(full), B32(0), "{instruction:?}");
+    }
+}
+
+#[test]
+fn pop_below_the_stack_raises_bad_address() {
+    for instruction in [Instruction::Pop(B8(1)), Instruction::Ret()] {
+        let mut machine = stack_machine(instruction, stack_base());
+        set_fault_handlers(&machine);
+        machine.run_cycles(3).unwrap();
+        assert_eq!(
+            entered_interrupt(&machine),
+            (FaultCause::StackUnderflow as u32, B32(0)),
+            "{instruction:?}"
+        );
+        assert_eq!(machine.threads[0].read_register(B8(1)), B32(7));
+    }
+}
+
+#[test]
+fn stack_fault_without_handler_stops_the_machine() {
+    let mut machine = stack_machine(Instruction::Pop(B8(1)), stack_base());
+    let err = machine.run_cycles(1).unwrap_err();
+    assert!(format!("{err:?}").contains("BadAddress"), "{err:?}");
+}
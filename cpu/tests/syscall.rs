@@ -0,0 +1,72 @@
+mod common;
+
+use common::{HANDLER_ADDR, entered_interrupt, program_machine, set_fault_handlers};
+use cpu::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{FaultCause, STACK_HEAD_REGISTER, instructions::Instruction},
+    },
+    machine::Machine,
+    memory_map::{MemoryMap, Region, RegionKind},
+    test_load_memory,
+};
+
+fn syscall_program() -> Vec<B32> {
+    vec![
+        Instruction::Set(B8(1)).into(),
+        B32(11),
+        Instruction::Set(B8(2)).into(),
+        B32(22),
+        Instruction::Set(B8(3)).into(),
+        B32(33),
+        Instruction::Syscall(B8(1), B8(2), B8(3)).into(),
+        Instruction::Halt().into(),
+    ]
+}
+
+#[test]
+fn syscall_calls_the_handler_with_its_arguments() {
+    let mut machine = program_machine(syscall_program());
+    set_fault_handlers(&machine);
+    machine.run_cycles(5).unwrap();
+    let thread = &machine.threads[0];
+    assert!(thread.is_halting());
+    let head = thread.read_register(STACK_HEAD_REGISTER);
+    // return addr at the head, then index, argument block and output addr below the gap
+    let stack: Vec<u32> = (0..5)
+        .map(|offset| machine.memory.read(head - B32(offset)).0)
+        .collect();
+    assert_eq!(stack, [6, 0, 11, 22, 33]);
+}
+
+#[test]
+fn syscall_with_idt_outside_of_memory_raises_bad_address() {
+    // hole in ram between the two regions, IDT starts 7 words before the second one so its
+    // syscall entry is in the hole and the bad address entry isn't
+    let mut memory_map = MemoryMap::default();
+    memory_map.regions[0].size = 0x20000;
+    memory_map.regions.push(Region {
+        kind: RegionKind::Ram,
+        start: 0x21000,
+        size: 0x1f000,
+    });
+    let idt_addr = 0x21000 - 7;
+    let mut machine = Machine::new(1, &memory_map).unwrap();
+    test_load_memory(&machine.memory, syscall_program());
+    machine
+        .memory
+        .write(B32(HANDLER_ADDR), B32(idt_addr + 7), true);
+    machine
+        .memory
+        .write(Instruction::Halt().into(), B32(HANDLER_ADDR + 1), true);
+    let thread = &machine.threads[0];
+    thread.write_register(B8(200), B32(idt_addr));
+    thread.Idt(B8(200), true);
+
+    machine.run_cycles(6).unwrap();
+    assert_eq!(
+        entered_interrupt(&machine),
+        (FaultCause::Read as u32, B32(6))
+    );
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{entered_interrupt, program_machine, set_fault_handlers};
+use cpu::{
+    chips::{
+        b8::B8,
+        b32::B32,
+        thread::{InterruptKind, instructions::Instruction},
+    },
+    machine::Machine,
+    peripherals::{
+        Peripheral, TIMER_INDEX,
+        timer::{TIMER_ONE_SHOT, TIMER_PERIODIC, TIMER_STOP, Timer},
+    },
+};
+
+fn command(command: u32, interval: u32) -> B32 {
+    B32((command << 28) | interval)
+}
+/// ticks the timer for cycles, returns cycle (counted from 1), thread and data of every interrupt
+fn run(timer: &mut Timer, machine: &Machine, cycles: u32) -> Vec<(u32, usize, u32)> {
+    let mut fired = Vec::new();
+    for cycle in 1..=cycles {
+        timer.tick(&machine.memory, &mut |thread, interrupt| {
+            assert_eq!(interrupt.kind, InterruptKind::Timer);
+            fired.push((cycle, thread, interrupt.data));
+        });
+    }
+    fired
+}
+
+#[test]
+fn one_shot_fires_once() {
+    let machine = program_machine(Vec::new());
+    let mut timer = Timer::new();
+    timer.write(1, 0, command(TIMER_ONE_SHOT, 3)).unwrap();
+    assert_eq!(run(&mut timer, &machine, 10), [(3, 1, 1)]);
+    assert_eq!(timer.read(1, 0).unwrap(), B32(1));
+    assert_eq!(timer.read(0, 0).unwrap(), B32(0));
+}
+
+#[test]
+fn periodic_fires_every_interval_until_stopped() {
+    let machine = program_machine(Vec::new());
+    let mut timer = Timer::new();
+    timer.write(0, 0, command(TIMER_PERIODIC, 2)).unwrap();
+    assert_eq!(
+        run(&mut timer, &machine, 6),
+        [(2, 0, 1), (4, 0, 2), (6, 0, 3)]
+    );
+    timer.write(0, 0, command(TIMER_STOP, 0)).unwrap();
+    assert_eq!(run(&mut timer, &machine, 10), []);
+    // ticks are kept, the next start counts on from them
+    timer.write(0, 0, command(TIMER_ONE_SHOT, 1)).unwrap();
+    assert_eq!(run(&mut timer, &machine, 1), [(1, 0, 4)]);
+}
+
+#[test]
+fn invalid_command_leaves_the_channel_running() {
+    let machine = program_machine(Vec::new());
+    let mut timer = Timer::new();
+    timer.write(0, 0, command(TIMER_PERIODIC, 4)).unwrap();
+    timer.write(0, 0, command(7, 1)).unwrap();
+    timer.write(0, 0, command(TIMER_ONE_SHOT, 0)).unwrap();
+    assert_eq!(run(&mut timer, &machine, 8), [(4, 0, 1), (8, 0, 2)]);
+}
+
+#[test]
+fn timer_interrupt_reaches_the_thread() {
+    let mut machine = program_machine(vec![
+        Instruction::Set(B8(1)).into(),
+        B32(TIMER_INDEX),
+        Instruction::Set(B8(2)).into(),
+        command(TIMER_ONE_SHOT, 20),
+        Instruction::Phrp(B8(1), B8(2)).into(),
+        Instruction::Halt().into(),
+    ]);
+    set_fault_handlers(&machine);
+    machine.run_cycles(10).unwrap();
+    assert!(machine.threads[0].is_halting());
+    machine.run_cycles(20).unwrap();
+    // the thread waited in the halt at 5, the handler returns after it
+    assert_eq!(entered_interrupt(&machine), (1, B32(6)));
+}
@@ -12,11 +12,11 @@ Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-RSet r246 , :static_declaration_end2
+RSet r246 , :static_declaration_end3
 RJmp r246
 :screen_width
 Set r0 , 0
-:static_declaration_end2
+:static_declaration_end3
 // allocate_stack - current: 1 size: 1
 Set r248 , 1
 Add r254 , r248
@@ -62,11 +62,11 @@ Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-RSet r246 , :static_declaration_end3
+RSet r246 , :static_declaration_end4
 RJmp r246
 :screen_height
 Set r0 , 0
-:static_declaration_end3
+:static_declaration_end4
 // allocate_stack - current: 3 size: 1
 Set r248 , 1
 Add r254 , r248
@@ -98,79 +98,175 @@ Add r244 , r243
 Write r244 , r247
 // write_register- end
 
+// allocate_stack - current: 4 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+Cp r249 , r3
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 4
+Add r246 , r255
+Add r246 , r247
+Write r246 , r249
+// write_register- end
+RSet r249 , :static_declaration_end5
+RJmp r249
+:serial_addr
+Set r0 , 0
+:static_declaration_end5
+// allocate_stack - current: 5 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+RSet r249 , :serial_addr
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 245, input: r249
+Set r245 , 5
+Add r245 , r255
+Write r245 , r249
+// write_directly_to_reference_pointer - end
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 4
+Add r243 , r255
+Add r243 , r244
+Read r246 , r243
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r245 , 5
+Add r245 , r255
+Read r245 , r245
+Set r243 , 0
+Add r245 , r243
+Add r245 , r244
+Write r245 , r246
+// write_register- end
+
+// allocate_stack - current: 6 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+Cp r249 , r4
+// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 6
+Add r246 , r255
+Add r246 , r247
+Write r246 , r249
+// write_register- end
+RSet r249 , :static_declaration_end6
+RJmp r249
+:framebuffer_addr
+Set r0 , 0
+:static_declaration_end6
+// allocate_stack - current: 7 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+RSet r249 , :framebuffer_addr
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 245, input: r249
+Set r245 , 7
+Add r245 , r255
+Write r245 , r249
+// write_directly_to_reference_pointer - end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 6
+Add r243 , r255
+Add r243 , r244
+Read r246 , r243
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r245 , 7
+Add r245 , r255
+Read r245 , r245
+Set r243 , 0
+Add r245 , r243
+Add r245 , r244
+Write r245 , r246
+// write_register- end
+
 // handle number
 Set r250 , 0
-// allocate_stack - current: 4 size: 1
+// allocate_stack - current: 8 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 4
+Set r246 , 8
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-// allocate_stack - current: 5 size: 4
+// allocate_stack - current: 9 size: 4
 Set r247 , 4
 Add r254 , r247
 // alloc_stack - end
 Set r246 , 3
-// write_register- self: Data { stack_frame_offset: 5, size: 4, data_type: Array { inside: Char, len: 3 } }
+// write_register- self: Data { stack_frame_offset: 9, size: 4, data_type: Array { inside: Char, len: 3 } }
 Set r245 , 0
-Set r244 , 5
+Set r244 , 9
 Add r244 , r255
 Add r244 , r245
 Write r244 , r246
 // write_register- end
 Set r246 , 99
-// write_register- self: Data { stack_frame_offset: 5, size: 4, data_type: Array { inside: Char, len: 3 } }
+// write_register- self: Data { stack_frame_offset: 9, size: 4, data_type: Array { inside: Char, len: 3 } }
 Set r245 , 1
-Set r248 , 5
+Set r248 , 9
 Add r248 , r255
 Add r248 , r245
 Write r248 , r246
 // write_register- end
 Set r246 , 109
-// write_register- self: Data { stack_frame_offset: 5, size: 4, data_type: Array { inside: Char, len: 3 } }
+// write_register- self: Data { stack_frame_offset: 9, size: 4, data_type: Array { inside: Char, len: 3 } }
 Set r245 , 2
-Set r244 , 5
+Set r244 , 9
 Add r244 , r255
 Add r244 , r245
 Write r244 , r246
 // write_register- end
 Set r246 , 100
-// write_register- self: Data { stack_frame_offset: 5, size: 4, data_type: Array { inside: Char, len: 3 } }
+// write_register- self: Data { stack_frame_offset: 9, size: 4, data_type: Array { inside: Char, len: 3 } }
 Set r245 , 3
-Set r248 , 5
+Set r248 , 9
 Add r248 , r255
 Add r248 , r245
 Write r248 , r246
 // write_register- end
-// allocate_stack - current: 9 size: 1
+// allocate_stack - current: 13 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 4
+Set r242 , 8
 Add r242 , r255
 Add r242 , r243
 Read r245 , r242
 // read_register- end
 // read_addr_of_self
-Set r242 , 5
+Set r242 , 9
 Add r242 , r255
 // read_addr_of_self - end
 // read_addr_of_self
-Set r243 , 9
+Set r243 , 13
 Add r243 , r255
 // read_addr_of_self - end
 Syscall r245 , r242 , r243
-// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
 Set r241 , 0
-Set r240 , 9
+Set r240 , 13
 Add r240 , r255
 Add r240 , r241
 Write r240 , r243
@@ -178,157 +274,677 @@ Write r240 , r243
 
 // call_function_code
 // call_function_code - needed_stack: 1
-// allocate_stack - current: 10 size: 1
+// allocate_stack - current: 14 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-RSet r247 , :function-return4
+RSet r247 , :function-return7
 Add r247 , r251
 Set r245 , 0
 Sub r247 , r245
 Write r254 , r247
-RSet r248 , :function_test_screen_1
+RSet r248 , :function_test_screen_2
 RJmp r248
-:function-return4
+:function-return7
 // function call output: None
 // function call converted output data : None
 
 // handle number
 Set r250 , 0
-// allocate_stack - current: 11 size: 1
+// allocate_stack - current: 15 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 15, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 11
+Set r246 , 15
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-// allocate_stack - current: 12 size: 5
+// allocate_stack - current: 16 size: 5
 Set r247 , 5
 Add r254 , r247
 // alloc_stack - end
 Set r246 , 4
-// write_register- self: Data { stack_frame_offset: 12, size: 5, data_type: Array { inside: Char, len: 4 } }
+// write_register- self: Data { stack_frame_offset: 16, size: 5, data_type: Array { inside: Char, len: 4 } }
 Set r245 , 0
-Set r244 , 12
+Set r244 , 16
 Add r244 , r255
 Add r244 , r245
 Write r244 , r246
 // write_register- end
 Set r246 , 69
-// write_register- self: Data { stack_frame_offset: 12, size: 5, data_type: Array { inside: Char, len: 4 } }
+// write_register- self: Data { stack_frame_offset: 16, size: 5, data_type: Array { inside: Char, len: 4 } }
 Set r245 , 1
-Set r248 , 12
+Set r248 , 16
 Add r248 , r255
 Add r248 , r245
 Write r248 , r246
 // write_register- end
 Set r246 , 78
-// write_register- self: Data { stack_frame_offset: 12, size: 5, data_type: Array { inside: Char, len: 4 } }
+// write_register- self: Data { stack_frame_offset: 16, size: 5, data_type: Array { inside: Char, len: 4 } }
 Set r245 , 2
-Set r244 , 12
+Set r244 , 16
 Add r244 , r255
 Add r244 , r245
 Write r244 , r246
 // write_register- end
 Set r246 , 68
-// write_register- self: Data { stack_frame_offset: 12, size: 5, data_type: Array { inside: Char, len: 4 } }
+// write_register- self: Data { stack_frame_offset: 16, size: 5, data_type: Array { inside: Char, len: 4 } }
 Set r245 , 3
-Set r248 , 12
+Set r248 , 16
 Add r248 , r255
 Add r248 , r245
 Write r248 , r246
 // write_register- end
 Set r246 , 33
-// write_register- self: Data { stack_frame_offset: 12, size: 5, data_type: Array { inside: Char, len: 4 } }
+// write_register- self: Data { stack_frame_offset: 16, size: 5, data_type: Array { inside: Char, len: 4 } }
 Set r245 , 4
-Set r244 , 12
+Set r244 , 16
 Add r244 , r255
 Add r244 , r245
 Write r244 , r246
 // write_register- end
-// allocate_stack - current: 17 size: 1
+// allocate_stack - current: 21 size: 1
 Set r244 , 1
 Add r254 , r244
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 15, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 11
+Set r242 , 15
 Add r242 , r255
 Add r242 , r243
 Read r245 , r242
 // read_register- end
 // read_addr_of_self
-Set r242 , 12
+Set r242 , 16
 Add r242 , r255
 // read_addr_of_self - end
 // read_addr_of_self
-Set r243 , 17
+Set r243 , 21
 Add r243 , r255
 // read_addr_of_self - end
 Syscall r245 , r242 , r243
-// write_register- self: Data { stack_frame_offset: 17, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 21, size: 1, data_type: U32 }
 Set r241 , 0
-Set r240 , 17
+Set r240 , 21
 Add r240 , r255
 Add r240 , r241
 Write r240 , r243
 // write_register- end
 
-// allocate_stack - current: 18 size: 1
+// assignment
+// memory_access
+// allocate_stack - current: 22 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// handle_binary_expr
+// access_static_variable
+// allocate_stack - current: 23 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+RSet r248 , :serial_addr
+Add r248 , r251
+Set r247 , 0
+Sub r248 , r247
+// write_directly_to_reference_pointer - 246, input: r248
+Set r246 , 23
+Add r246 , r255
+Write r246 , r248
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// handle number
+Set r248 , 2
+// allocate_stack - current: 24 size: 1
+Set r246 , 1
+Add r254 , r246
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 24, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 24
+Add r243 , r255
+Add r243 , r244
+Write r243 , r248
+// write_register- end
+// handle number end
+// allocate_stack - current: 25 size: 1
+Set r242 , 1
+Add r254 , r242
+// alloc_stack - end
+// handle_binary_expr - output_register- r243
+// read_register- self: Data { stack_frame_offset: 23, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r240 , 0
+Set r239 , 23
+Add r239 , r255
+Read r239 , r239
+Set r241 , 0
+Add r239 , r241
+Add r239 , r240
+Read r244 , r239
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 24, size: 1, data_type: U32 }
+Set r240 , 0
+Set r241 , 24
+Add r241 , r255
+Add r241 , r240
+Read r245 , r241
+// read_register- end
+Add r244 , r245
+Cp r243 , r244
+// write_register- self: Data { stack_frame_offset: 25, size: 1, data_type: U32 }
+Set r240 , 0
+Set r239 , 25
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 25, size: 1, data_type: U32 }
+Set r244 , 0
+Set r239 , 25
+Add r239 , r255
+Add r239 , r244
+Read r243 , r239
+// read_register- end
+// write_directly_to_reference_pointer - 244, input: r243
+Set r244 , 22
+Add r244 , r255
+Write r244 , r243
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// handle number
+Set r239 , 1
+// allocate_stack - current: 26 size: 1
+Set r243 , 1
+Add r254 , r243
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 26, size: 1, data_type: U32 }
+Set r245 , 0
+Set r240 , 26
+Add r240 , r255
+Add r240 , r245
+Write r240 , r239
+// write_register- end
+// handle number end
+// assignment data: Data { stack_frame_offset: 26, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 26, size: 1, data_type: U32 }
+Set r244 , 0
+Set r241 , 26
+Add r241 , r255
+Add r241 , r244
+Read r240 , r241
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r245 , 22
+Add r245 , r255
+Read r245 , r245
+Set r241 , 0
+Add r245 , r241
+Add r245 , r244
+Write r245 , r240
+// write_register- end
+// end assignment
+
+// assign_new_variable
+// allocate_stack - current: 27 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
+Gmsk r249
+// write_register- self: Data { stack_frame_offset: 27, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 27
+Add r246 , r255
+Add r246 , r247
+Write r246 , r249
+// write_register- end
+// assign_new_variable - end
+
+// allocate_stack - current: 28 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 28, size: 1, data_type: U32 }
 Set r248 , 0
-Set r247 , 18
+Set r247 , 28
 Add r247 , r255
 Add r247 , r248
 Write r247 , r254
 // write_register- end
-:while_start5
+:while_start8
 Set r249 , 1
-// allocate_stack - current: 19 size: 1
+// allocate_stack - current: 29 size: 1
 Set r246 , 1
 Add r254 , r246
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 19, size: 1, data_type: Bool }
+// write_register- self: Data { stack_frame_offset: 29, size: 1, data_type: Bool }
 Set r244 , 0
-Set r243 , 19
+Set r243 , 29
 Add r243 , r255
 Add r243 , r244
 Write r243 , r249
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 19, size: 1, data_type: Bool }
+// read_register- self: Data { stack_frame_offset: 29, size: 1, data_type: Bool }
 Set r244 , 0
-Set r245 , 19
+Set r245 , 29
 Add r245 , r255
 Add r245 , r244
 Read r247 , r245
 // read_register- end
 Not r247
-RSet r248 , :while_end6
+RSet r248 , :while_end9
 RJmpc r248 , r247
 // while loop contents:
+// handle_binary_expr
+// handle_binary_expr
+// handle number
+Set r245 , 1
+// allocate_stack - current: 30 size: 1
+Set r244 , 1
+Add r254 , r244
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 30
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
+// write_register- end
+// handle number end
+// handle number
+Set r241 , 10
+// allocate_stack - current: 31 size: 1
+Set r242 , 1
+Add r254 , r242
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 31, size: 1, data_type: U32 }
+Set r240 , 0
+Set r239 , 31
+Add r239 , r255
+Add r239 , r240
+Write r239 , r241
+// write_register- end
+// handle number end
+// allocate_stack - current: 32 size: 1
+Set r238 , 1
+Add r254 , r238
+// alloc_stack - end
+// handle_binary_expr - output_register- r239
+// read_register- self: Data { stack_frame_offset: 30, size: 1, data_type: U32 }
+Set r236 , 0
+Set r235 , 30
+Add r235 , r255
+Add r235 , r236
+Read r240 , r235
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 31, size: 1, data_type: U32 }
+Set r236 , 0
+Set r237 , 31
+Add r237 , r255
+Add r237 , r236
+Read r243 , r237
+// read_register- end
+Shl r240 , r243
+Cp r239 , r240
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: U32 }
+Set r236 , 0
+Set r235 , 32
+Add r235 , r255
+Add r235 , r236
+Write r235 , r239
+// write_register- end
+// handle_binary_expr - end
+// allocate_stack - current: 33 size: 1
+Set r235 , 1
+Add r254 , r235
+// alloc_stack - end
+// handle_binary_expr - output_register- r239
+// read_register- self: Data { stack_frame_offset: 27, size: 1, data_type: U32 }
+Set r237 , 0
+Set r234 , 27
+Add r234 , r255
+Add r234 , r237
+Read r243 , r234
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 32, size: 1, data_type: U32 }
+Set r237 , 0
+Set r236 , 32
+Add r236 , r255
+Add r236 , r237
+Read r240 , r236
+// read_register- end
+Add r243 , r240
+Cp r239 , r243
+// write_register- self: Data { stack_frame_offset: 33, size: 1, data_type: U32 }
+Set r237 , 0
+Set r234 , 33
+Add r234 , r255
+Add r234 , r237
+Write r234 , r239
+// write_register- end
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 33, size: 1, data_type: U32 }
+Set r243 , 0
+Set r234 , 33
+Add r234 , r255
+Add r234 , r243
+Read r239 , r234
+// read_register- end
+Smsk r239
+
+// call_function_code
+// call_function_code - needed_stack: 1
+// allocate_stack - current: 34 size: 1
+Set r234 , 1
+Add r254 , r234
+// alloc_stack - end
+RSet r240 , :function-return10
+Add r240 , r251
+Set r236 , 0
+Sub r240 , r236
+Write r254 , r240
+RSet r243 , :function_echo_serial_input_1
+RJmp r243
+:function-return10
+// function call output: None
+// function call converted output data : None
+
 Halt
 
+// read_register- self: Data { stack_frame_offset: 27, size: 1, data_type: U32 }
+Set r243 , 0
+Set r239 , 27
+Add r239 , r255
+Add r239 , r243
+Read r237 , r239
+// read_register- end
+Smsk r237
+
 // while loop contents - end
-RSet r248 , :while_start5
+RSet r248 , :while_start8
 RJmp r248
-:while_end6
-// read_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
-Set r244 , 0
-Set r243 , 18
+:while_end9
+// read_register- self: Data { stack_frame_offset: 28, size: 1, data_type: U32 }
+Set r239 , 0
+Set r243 , 28
 Add r243 , r255
-Add r243 , r244
+Add r243 , r239
 Read r254 , r243
 // read_register- end
-// function: Function { is_interrupt_function: false, name: "test_screen", input: [], output: None, label_name: "function_test_screen_1" }
-:function_test_screen_1
+// function: Function { is_interrupt_function: false, name: "echo_serial_input", input: [], output: None, label_name: "function_echo_serial_input_1" }
+:function_echo_serial_input_1
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// assign_new_variable
+// access_static_variable
+// allocate_stack - current: 2 size: 1
+Set r245 , 1
+Add r254 , r245
+// alloc_stack - end
+RSet r246 , :serial_addr
+Add r246 , r251
+Set r247 , 0
+Sub r246 , r247
+// write_directly_to_reference_pointer - 244, input: r246
+Set r244 , 2
+Add r244 , r255
+Write r244 , r246
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// assign_new_variable - end
+
+// allocate_stack - current: 3 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r248 , 0
+Set r247 , 3
+Add r247 , r255
+Add r247 , r248
+Write r247 , r254
+// write_register- end
+:while_start11
+// handle_binary_expr
+// memory_access
+// allocate_stack - current: 4 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// handle_binary_expr
+// handle number
+Set r246 , 1
+// allocate_stack - current: 5 size: 1
+Set r245 , 1
+Add r254 , r245
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 5
+Add r242 , r255
+Add r242 , r243
+Write r242 , r246
+// write_register- end
+// handle number end
+// allocate_stack - current: 6 size: 1
+Set r241 , 1
+Add r254 , r241
+// alloc_stack - end
+// handle_binary_expr - output_register- r242
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r239 , 0
+Set r238 , 2
+Add r238 , r255
+Read r238 , r238
+Set r240 , 0
+Add r238 , r240
+Add r238 , r239
+Read r243 , r238
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r239 , 0
+Set r240 , 5
+Add r240 , r255
+Add r240 , r239
+Read r244 , r240
+// read_register- end
+Add r243 , r244
+Cp r242 , r243
+// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r239 , 0
+Set r238 , 6
+Add r238 , r255
+Add r238 , r239
+Write r238 , r242
+// write_register- end
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r243 , 0
+Set r238 , 6
+Add r238 , r255
+Add r238 , r243
+Read r242 , r238
+// read_register- end
+// write_directly_to_reference_pointer - 243, input: r242
+Set r243 , 4
+Add r243 , r255
+Write r243 , r242
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// handle number
+Set r238 , 0
+// allocate_stack - current: 7 size: 1
+Set r242 , 1
+Add r254 , r242
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r244 , 0
+Set r239 , 7
+Add r239 , r255
+Add r239 , r244
+Write r239 , r238
+// write_register- end
+// handle number end
+// allocate_stack - current: 8 size: 1
+Set r240 , 1
+Add r254 , r240
+// alloc_stack - end
+// handle_binary_expr - output_register- r239
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r236 , 0
+Set r235 , 4
+Add r235 , r255
+Read r235 , r235
+Set r237 , 0
+Add r235 , r237
+Add r235 , r236
+Read r244 , r235
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r236 , 0
+Set r237 , 7
+Add r237 , r255
+Add r237 , r236
+Read r243 , r237
+// read_register- end
+Gt r244 , r243 , r239
+// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: Bool }
+Set r236 , 0
+Set r235 , 8
+Add r235 , r255
+Add r235 , r236
+Write r235 , r239
+// write_register- end
+// handle_binary_expr - end
+// allocate_stack - current: 9 size: 1
+Set r239 , 1
+Add r254 , r239
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: Bool }
+Set r235 , 0
+Set r236 , 8
+Add r236 , r255
+Add r236 , r235
+Read r243 , r236
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: Bool }
+Set r235 , 0
+Set r244 , 9
+Add r244 , r255
+Add r244 , r235
+Write r244 , r243
+// write_register- end
+// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: Bool }
+Set r244 , 0
+Set r235 , 9
+Add r235 , r255
+Add r235 , r244
+Read r247 , r235
+// read_register- end
+Not r247
+RSet r248 , :while_end12
+RJmpc r248 , r247
+// while loop contents:
+// memory_access
+// allocate_stack - current: 10 size: 1
+Set r243 , 1
+Add r254 , r243
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r234 , 0
+Set r233 , 2
+Add r233 , r255
+Read r233 , r233
+Set r237 , 0
+Add r233 , r237
+Add r233 , r234
+Read r236 , r233
+// read_register- end
+// write_directly_to_reference_pointer - 234, input: r236
+Set r234 , 10
+Add r234 , r255
+Write r234 , r236
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// allocate_stack - current: 11 size: 1
+Set r233 , 1
+Add r254 , r233
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 10, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r237 , 0
+Set r232 , 10
+Add r232 , r255
+Read r232 , r232
+Set r234 , 0
+Add r232 , r234
+Add r232 , r237
+Read r236 , r232
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
+Set r237 , 0
+Set r234 , 11
+Add r234 , r255
+Add r234 , r237
+Write r234 , r236
+// write_register- end
+Set r235 , 0
+// read_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
+Set r234 , 0
+Set r237 , 11
+Add r237 , r255
+Add r237 , r234
+Read r244 , r237
+// read_register- end
+Phrp r235 , r244
+
+// while loop contents - end
+RSet r248 , :while_start11
+RJmp r248
+:while_end12
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r235 , 0
+Set r237 , 3
+Add r237 , r255
+Add r237 , r235
+Read r254 , r237
+// read_register- end
+// Return
+
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Jmp r250
+// function: Function { is_interrupt_function: false, name: "test_screen", input: [], output: None, label_name: "function_test_screen_2" }
+:function_test_screen_2
 Cp r250 , r255
 Cp r255 , r254
 // allocate_stack - current: 0 size: 1
@@ -382,38 +998,56 @@ Write r247 , r249
 // access_static_variable-end
 // assign_new_variable - end
 
+// assign_new_variable
+// access_static_variable
 // allocate_stack - current: 4 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+RSet r249 , :framebuffer_addr
+Add r249 , r251
 Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 247, input: r249
 Set r247 , 4
 Add r247 , r255
+Write r247 , r249
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// assign_new_variable - end
+
+// allocate_stack - current: 5 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r248 , 0
+Set r247 , 5
+Add r247 , r255
 Add r247 , r248
 Write r247 , r254
 // write_register- end
-// allocate_stack - current: 5 size: 1
+// allocate_stack - current: 6 size: 1
 Set r245 , 1
 Add r254 , r245
 // alloc_stack - end
 // handle number
 Set r244 , 0
-// allocate_stack - current: 6 size: 1
+// allocate_stack - current: 7 size: 1
 Set r243 , 1
 Add r254 , r243
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
 Set r241 , 0
-Set r240 , 6
+Set r240 , 7
 Add r240 , r255
 Add r240 , r241
 Write r240 , r244
 // write_register- end
 // handle number end
-// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
 Set r241 , 0
-Set r242 , 6
+Set r242 , 7
 Add r242 , r255
 Add r242 , r241
 Read r248 , r242
@@ -430,52 +1064,52 @@ Read r246 , r240
 // read_register- end
 Set r247 , 1
 Sub r248 , r247
-:for_start7
+:for_start13
 Set r247 , 1
 Add r248 , r247
 Gte r248 , r246 , r247
-RSet r249 , :for_end8
+RSet r249 , :for_end14
 RJmpc r249 , r247
-// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
 Set r241 , 0
-Set r242 , 5
+Set r242 , 6
 Add r242 , r255
 Add r242 , r241
 Write r242 , r248
 // write_register- end
 // for loop contents:
-// allocate_stack - current: 7 size: 1
+// allocate_stack - current: 8 size: 1
 Set r242 , 1
 Add r254 , r242
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
 Set r240 , 0
-Set r239 , 7
+Set r239 , 8
 Add r239 , r255
 Add r239 , r240
 Write r239 , r254
 // write_register- end
-// allocate_stack - current: 8 size: 1
+// allocate_stack - current: 9 size: 1
 Set r237 , 1
 Add r254 , r237
 // alloc_stack - end
 // handle number
 Set r236 , 0
-// allocate_stack - current: 9 size: 1
+// allocate_stack - current: 10 size: 1
 Set r235 , 1
 Add r254 , r235
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 10, size: 1, data_type: U32 }
 Set r233 , 0
-Set r232 , 9
+Set r232 , 10
 Add r232 , r255
 Add r232 , r233
 Write r232 , r236
 // write_register- end
 // handle number end
-// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 10, size: 1, data_type: U32 }
 Set r233 , 0
-Set r234 , 9
+Set r234 , 10
 Add r234 , r255
 Add r234 , r233
 Read r240 , r234
@@ -492,15 +1126,15 @@ Read r238 , r232
 // read_register- end
 Set r239 , 1
 Sub r240 , r239
-:for_start9
+:for_start15
 Set r239 , 1
 Add r240 , r239
 Gte r240 , r238 , r239
-RSet r241 , :for_end10
+RSet r241 , :for_end16
 RJmpc r241 , r239
-// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
 Set r233 , 0
-Set r234 , 8
+Set r234 , 9
 Add r234 , r255
 Add r234 , r233
 Write r234 , r240
@@ -509,14 +1143,14 @@ Write r234 , r240
 // assign_new_variable
 // handle_binary_expr
 // handle_binary_expr
-// allocate_stack - current: 10 size: 1
+// allocate_stack - current: 11 size: 1
 Set r231 , 1
 Add r254 , r231
 // alloc_stack - end
 // handle_binary_expr - output_register- r234
-// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
 Set r229 , 0
-Set r228 , 5
+Set r228 , 6
 Add r228 , r255
 Add r228 , r229
 Read r233 , r228
@@ -533,38 +1167,38 @@ Read r232 , r230
 // read_register- end
 Mul r233 , r232
 Cp r234 , r233
-// write_register- self: Data { stack_frame_offset: 10, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
 Set r229 , 0
-Set r228 , 10
+Set r228 , 11
 Add r228 , r255
 Add r228 , r229
 Write r228 , r234
 // write_register- end
 // handle_binary_expr - end
-// allocate_stack - current: 11 size: 1
+// allocate_stack - current: 12 size: 1
 Set r228 , 1
 Add r254 , r228
 // alloc_stack - end
 // handle_binary_expr - output_register- r234
-// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
 Set r230 , 0
-Set r227 , 8
+Set r227 , 9
 Add r227 , r255
 Add r227 , r230
 Read r232 , r227
 // read_register- end
-// read_register- self: Data { stack_frame_offset: 10, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
 Set r230 , 0
-Set r229 , 10
+Set r229 , 11
 Add r229 , r255
 Add r229 , r230
 Read r233 , r229
 // read_register- end
 Add r232 , r233
 Cp r234 , r232
-// write_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
 Set r230 , 0
-Set r227 , 11
+Set r227 , 12
 Add r227 , r255
 Add r227 , r230
 Write r227 , r234
@@ -575,13 +1209,13 @@ Write r227 , r234
 // assign_new_variable
 // handle number
 Set r234 , 11110
-// allocate_stack - current: 12 size: 1
+// allocate_stack - current: 13 size: 1
 Set r233 , 1
 Add r254 , r233
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
 Set r227 , 0
-Set r230 , 12
+Set r230 , 13
 Add r230 , r255
 Add r230 , r227
 Write r230 , r234
@@ -589,84 +1223,97 @@ Write r230 , r234
 // handle number end
 // assign_new_variable - end
 
-// handle number
+// assignment
+// memory_access
+// allocate_stack - current: 14 size: 1
 Set r230 , 1
-// allocate_stack - current: 13 size: 1
-Set r227 , 1
-Add r254 , r227
+Add r254 , r230
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
-Set r229 , 0
-Set r226 , 13
-Add r226 , r255
-Add r226 , r229
-Write r226 , r230
-// write_register- end
-// handle number end
-// read_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
+// handle_binary_expr
+// allocate_stack - current: 15 size: 1
+Set r226 , 1
+Add r254 , r226
+// alloc_stack - end
+// handle_binary_expr - output_register- r227
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r224 , 0
+Set r223 , 4
+Add r223 , r255
+Read r223 , r223
 Set r225 , 0
-Set r224 , 13
-Add r224 , r255
-Add r224 , r225
-Read r226 , r224
+Add r223 , r225
+Add r223 , r224
+Read r232 , r223
 // read_register- end
-// read_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
-Set r225 , 0
-Set r232 , 11
-Add r232 , r255
-Add r232 , r225
-Read r229 , r232
+// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+Set r224 , 0
+Set r225 , 12
+Add r225 , r255
+Add r225 , r224
+Read r229 , r225
 // read_register- end
-Phrp r226 , r229
-
-// handle number
-Set r232 , 1
-// allocate_stack - current: 14 size: 1
-Set r225 , 1
-Add r254 , r225
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
-Set r223 , 0
-Set r222 , 14
-Add r222 , r255
-Add r222 , r223
-Write r222 , r232
+Add r232 , r229
+Cp r227 , r232
+// write_register- self: Data { stack_frame_offset: 15, size: 1, data_type: U32 }
+Set r224 , 0
+Set r223 , 15
+Add r223 , r255
+Add r223 , r224
+Write r223 , r227
 // write_register- end
-// handle number end
-// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
-Set r221 , 0
-Set r220 , 14
-Add r220 , r255
-Add r220 , r221
-Read r222 , r220
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 15, size: 1, data_type: U32 }
+Set r232 , 0
+Set r223 , 15
+Add r223 , r255
+Add r223 , r232
+Read r227 , r223
 // read_register- end
-// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
-Set r221 , 0
-Set r224 , 12
-Add r224 , r255
-Add r224 , r221
-Read r223 , r224
+// write_directly_to_reference_pointer - 232, input: r227
+Set r232 , 14
+Add r232 , r255
+Write r232 , r227
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// assignment data: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
+Set r232 , 0
+Set r229 , 13
+Add r229 , r255
+Add r229 , r232
+Read r223 , r229
 // read_register- end
-Phrp r222 , r223
+// write_register- self: Data { stack_frame_offset: 14, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r232 , 0
+Set r227 , 14
+Add r227 , r255
+Read r227 , r227
+Set r229 , 0
+Add r227 , r229
+Add r227 , r232
+Write r227 , r223
+// write_register- end
+// end assignment
 
 // for loop contents - end
-RSet r241 , :for_start9
+RSet r241 , :for_start15
 RJmp r241
-:for_end10
-// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
-Set r221 , 0
-Set r220 , 7
-Add r220 , r255
-Add r220 , r221
-Read r254 , r220
+:for_end16
+// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+Set r227 , 0
+Set r232 , 8
+Add r232 , r255
+Add r232 , r227
+Read r254 , r232
 // read_register- end
 // for loop contents - end
-RSet r249 , :for_start7
+RSet r249 , :for_start13
 RJmp r249
-:for_end8
-// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+:for_end14
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
 Set r240 , 0
-Set r241 , 4
+Set r241 , 5
 Add r241 , r255
 Add r241 , r240
 Read r254 , r241
@@ -1,75 +1,121 @@
-// handle number
-Set r250 , 9000
 // allocate_stack - current: 0 size: 1
-Set r249 , 1
-Add r254 , r249
+Set r250 , 1
+Add r254 , r250
 // alloc_stack - end
+Cp r249 , r0
 // write_register- self: Data { stack_frame_offset: 0, size: 1, data_type: U32 }
 Set r247 , 0
 Set r246 , 0
 Add r246 , r255
 Add r246 , r247
-Write r246 , r250
+Write r246 , r249
 // write_register- end
-// handle number end
-RSet r246 , :static_declaration_end11
-RJmp r246
-:heap_start
+RSet r249 , :static_declaration_end19
+RJmp r249
+:command_line_addr
 Set r0 , 0
-:static_declaration_end11
+:static_declaration_end19
 // allocate_stack - current: 1 size: 1
-Set r248 , 1
-Add r254 , r248
+Set r247 , 1
+Add r254 , r247
 // alloc_stack - end
-RSet r246 , :heap_start
-Add r246 , r251
-Set r245 , 0
-Sub r246 , r245
-// write_directly_to_reference_pointer - 244, input: r246
-Set r244 , 1
-Add r244 , r255
-Write r244 , r246
+RSet r249 , :command_line_addr
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 245, input: r249
+Set r245 , 1
+Add r245 , r255
+Write r245 , r249
 // write_directly_to_reference_pointer - end
 // read_register- self: Data { stack_frame_offset: 0, size: 1, data_type: U32 }
+Set r244 , 0
 Set r243 , 0
-Set r242 , 0
-Add r242 , r255
-Add r242 , r243
-Read r247 , r242
+Add r243 , r255
+Add r243 , r244
+Read r246 , r243
 // read_register- end
 // write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r245 , 1
+Add r245 , r255
+Read r245 , r245
 Set r243 , 0
-Set r244 , 1
-Add r244 , r255
-Read r244 , r244
-Set r242 , 0
-Add r244 , r242
-Add r244 , r243
-Write r244 , r247
+Add r245 , r243
+Add r245 , r244
+Write r245 , r246
 // write_register- end
 
-// access_static_variable
 // allocate_stack - current: 2 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
+Cp r249 , r1
+// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 2
+Add r246 , r255
+Add r246 , r247
+Write r246 , r249
+// write_register- end
+RSet r249 , :static_declaration_end20
+RJmp r249
+:heap_start
+Set r0 , 0
+:static_declaration_end20
+// allocate_stack - current: 3 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+RSet r249 , :heap_start
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 245, input: r249
+Set r245 , 3
+Add r245 , r255
+Write r245 , r249
+// write_directly_to_reference_pointer - end
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Read r246 , r243
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r245 , 3
+Add r245 , r255
+Read r245 , r245
+Set r243 , 0
+Add r245 , r243
+Add r245 , r244
+Write r245 , r246
+// write_register- end
+
+// access_static_variable
+// allocate_stack - current: 4 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
 RSet r249 , :heap_start
 Add r249 , r251
 Set r248 , 0
 Sub r249 , r248
 // write_directly_to_reference_pointer - 247, input: r249
-Set r247 , 2
+Set r247 , 4
 Add r247 , r255
 Write r247 , r249
 // write_directly_to_reference_pointer - end
 // access_static_variable-end
-// allocate_stack - current: 3 size: 1
+// allocate_stack - current: 5 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r245 , 0
-Set r244 , 2
+Set r244 , 4
 Add r244 , r255
 Read r244 , r244
 Set r246 , 0
@@ -77,19 +123,19 @@ Add r244 , r246
 Add r244 , r245
 Read r247 , r244
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
 Set r245 , 0
-Set r246 , 3
+Set r246 , 5
 Add r246 , r255
 Add r246 , r245
 Write r246 , r247
 // write_register- end
-RSet r247 , :static_declaration_end12
+RSet r247 , :static_declaration_end21
 RJmp r247
 :heap_main_pool_head
 Set r0 , 0
-:static_declaration_end12
-// allocate_stack - current: 4 size: 1
+:static_declaration_end21
+// allocate_stack - current: 6 size: 1
 Set r245 , 1
 Add r254 , r245
 // alloc_stack - end
@@ -98,20 +144,20 @@ Add r247 , r251
 Set r244 , 0
 Sub r247 , r244
 // write_directly_to_reference_pointer - 243, input: r247
-Set r243 , 4
+Set r243 , 6
 Add r243 , r255
 Write r243 , r247
 // write_directly_to_reference_pointer - end
-// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
 Set r242 , 0
-Set r241 , 3
+Set r241 , 5
 Add r241 , r255
 Add r241 , r242
 Read r246 , r241
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r242 , 0
-Set r243 , 4
+Set r243 , 6
 Add r243 , r255
 Read r243 , r243
 Set r241 , 0
@@ -120,76 +166,74 @@ Add r243 , r242
 Write r243 , r246
 // write_register- end
 
-// handle number
-Set r250 , 30000
-// allocate_stack - current: 5 size: 1
-Set r249 , 1
-Add r254 , r249
+// allocate_stack - current: 7 size: 1
+Set r250 , 1
+Add r254 , r250
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Cp r249 , r2
+// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 5
+Set r246 , 7
 Add r246 , r255
 Add r246 , r247
-Write r246 , r250
+Write r246 , r249
 // write_register- end
-// handle number end
-RSet r246 , :static_declaration_end13
-RJmp r246
+RSet r249 , :static_declaration_end22
+RJmp r249
 :heap_end
 Set r0 , 0
-:static_declaration_end13
-// allocate_stack - current: 6 size: 1
-Set r248 , 1
-Add r254 , r248
+:static_declaration_end22
+// allocate_stack - current: 8 size: 1
+Set r247 , 1
+Add r254 , r247
 // alloc_stack - end
-RSet r246 , :heap_end
-Add r246 , r251
-Set r245 , 0
-Sub r246 , r245
-// write_directly_to_reference_pointer - 244, input: r246
-Set r244 , 6
-Add r244 , r255
-Write r244 , r246
+RSet r249 , :heap_end
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 245, input: r249
+Set r245 , 8
+Add r245 , r255
+Write r245 , r249
 // write_directly_to_reference_pointer - end
-// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
-Set r243 , 0
-Set r242 , 5
-Add r242 , r255
-Add r242 , r243
-Read r247 , r242
+// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 7
+Add r243 , r255
+Add r243 , r244
+Read r246 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r245 , 8
+Add r245 , r255
+Read r245 , r245
 Set r243 , 0
-Set r244 , 6
-Add r244 , r255
-Read r244 , r244
-Set r242 , 0
-Add r244 , r242
-Add r244 , r243
-Write r244 , r247
+Add r245 , r243
+Add r245 , r244
+Write r245 , r246
 // write_register- end
 
 // handle number
 Set r250 , 2
-// allocate_stack - current: 7 size: 1
+// allocate_stack - current: 9 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 7
+Set r246 , 9
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-RSet r246 , :static_declaration_end14
+RSet r246 , :static_declaration_end23
 RJmp r246
 :min_heap_size_pow_2
 Set r0 , 0
-:static_declaration_end14
-// allocate_stack - current: 8 size: 1
+:static_declaration_end23
+// allocate_stack - current: 10 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
@@ -198,20 +242,20 @@ Add r246 , r251
 Set r245 , 0
 Sub r246 , r245
 // write_directly_to_reference_pointer - 244, input: r246
-Set r244 , 8
+Set r244 , 10
 Add r244 , r255
 Write r244 , r246
 // write_directly_to_reference_pointer - end
-// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 7
+Set r242 , 9
 Add r242 , r255
 Add r242 , r243
 Read r247 , r242
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 10, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r243 , 0
-Set r244 , 8
+Set r244 , 10
 Add r244 , r255
 Read r244 , r244
 Set r242 , 0
@@ -222,24 +266,24 @@ Write r244 , r247
 
 // handle number
 Set r250 , 10
-// allocate_stack - current: 9 size: 1
+// allocate_stack - current: 11 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 9
+Set r246 , 11
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-RSet r246 , :static_declaration_end15
+RSet r246 , :static_declaration_end24
 RJmp r246
 :max_heap_size_pow_2
 Set r0 , 0
-:static_declaration_end15
-// allocate_stack - current: 10 size: 1
+:static_declaration_end24
+// allocate_stack - current: 12 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
@@ -248,20 +292,20 @@ Add r246 , r251
 Set r245 , 0
 Sub r246 , r245
 // write_directly_to_reference_pointer - 244, input: r246
-Set r244 , 10
+Set r244 , 12
 Add r244 , r255
 Write r244 , r246
 // write_directly_to_reference_pointer - end
-// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 11, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 9
+Set r242 , 11
 Add r242 , r255
 Add r242 , r243
 Read r247 , r242
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 10, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 12, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r243 , 0
-Set r244 , 10
+Set r244 , 12
 Add r244 , r255
 Read r244 , r244
 Set r242 , 0
@@ -271,85 +315,85 @@ Write r244 , r247
 // write_register- end
 
 // array_initialization
-// allocate_stack - current: 11 size: 9
+// allocate_stack - current: 13 size: 9
 Set r249 , 9
 Add r254 , r249
 // alloc_stack - end
 Set r250 , 0
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 0
-Set r246 , 11
+Set r246 , 13
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 1
-Set r248 , 11
+Set r248 , 13
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 2
-Set r246 , 11
+Set r246 , 13
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 3
-Set r248 , 11
+Set r248 , 13
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 4
-Set r246 , 11
+Set r246 , 13
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 5
-Set r248 , 11
+Set r248 , 13
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 6
-Set r246 , 11
+Set r246 , 13
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 7
-Set r248 , 11
+Set r248 , 13
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 8
-Set r246 , 11
+Set r246 , 13
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 //  write array length
 Set r250 , 8
-// write_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r247 , 0
-Set r248 , 11
+Set r248 , 13
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
 // array_initialization- end
-RSet r250 , :static_declaration_end16
+RSet r250 , :static_declaration_end25
 RJmp r250
 :free_heap_blocks_linked_lists
 Set r0 , 0
@@ -361,8 +405,8 @@ Set r0 , 0
 Set r0 , 0
 Set r0 , 0
 Set r0 , 0
-:static_declaration_end16
-// allocate_stack - current: 20 size: 1
+:static_declaration_end25
+// allocate_stack - current: 22 size: 1
 Set r247 , 1
 Add r254 , r247
 // alloc_stack - end
@@ -371,20 +415,20 @@ Add r250 , r251
 Set r246 , 0
 Sub r250 , r246
 // write_directly_to_reference_pointer - 245, input: r250
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Write r245 , r250
 // write_directly_to_reference_pointer - end
-// read_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r244 , 0
-Set r243 , 11
+Set r243 , 13
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 0
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -392,16 +436,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r244 , 1
-Set r243 , 11
+Set r243 , 13
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 1
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -409,16 +453,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r244 , 2
-Set r243 , 11
+Set r243 , 13
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 2
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -426,16 +470,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r244 , 3
-Set r243 , 11
+Set r243 , 13
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 3
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -443,16 +487,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r244 , 4
-Set r243 , 11
+Set r243 , 13
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 4
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -460,16 +504,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r244 , 5
-Set r243 , 11
+Set r243 , 13
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 5
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -477,16 +521,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r244 , 6
-Set r243 , 11
+Set r243 , 13
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 6
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -494,16 +538,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r244 , 7
-Set r243 , 11
+Set r243 , 13
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 7
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -511,16 +555,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 11, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 13, size: 9, data_type: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 } }
 Set r244 , 8
-Set r243 , 11
+Set r243 , 13
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 8
-Set r245 , 20
+Set r245 , 22
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -530,85 +574,85 @@ Write r245 , r248
 // write_register- end
 
 // array_initialization
-// allocate_stack - current: 21 size: 9
+// allocate_stack - current: 23 size: 9
 Set r249 , 9
 Add r254 , r249
 // alloc_stack - end
 Set r250 , 0
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 0
-Set r246 , 21
+Set r246 , 23
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 1
-Set r248 , 21
+Set r248 , 23
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 2
-Set r246 , 21
+Set r246 , 23
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 3
-Set r248 , 21
+Set r248 , 23
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 4
-Set r246 , 21
+Set r246 , 23
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 5
-Set r248 , 21
+Set r248 , 23
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 6
-Set r246 , 21
+Set r246 , 23
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 7
-Set r248 , 21
+Set r248 , 23
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 8
-Set r246 , 21
+Set r246 , 23
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 //  write array length
 Set r250 , 8
-// write_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// write_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r247 , 0
-Set r248 , 21
+Set r248 , 23
 Add r248 , r255
 Add r248 , r247
 Write r248 , r250
 // write_register- end
 // array_initialization- end
-RSet r250 , :static_declaration_end17
+RSet r250 , :static_declaration_end26
 RJmp r250
 :task_que
 Set r0 , 0
@@ -620,8 +664,8 @@ Set r0 , 0
 Set r0 , 0
 Set r0 , 0
 Set r0 , 0
-:static_declaration_end17
-// allocate_stack - current: 30 size: 1
+:static_declaration_end26
+// allocate_stack - current: 32 size: 1
 Set r247 , 1
 Add r254 , r247
 // alloc_stack - end
@@ -630,20 +674,20 @@ Add r250 , r251
 Set r246 , 0
 Sub r250 , r246
 // write_directly_to_reference_pointer - 245, input: r250
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Write r245 , r250
 // write_directly_to_reference_pointer - end
-// read_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r244 , 0
-Set r243 , 21
+Set r243 , 23
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 0
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -651,16 +695,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r244 , 1
-Set r243 , 21
+Set r243 , 23
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 1
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -668,16 +712,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r244 , 2
-Set r243 , 21
+Set r243 , 23
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 2
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -685,16 +729,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r244 , 3
-Set r243 , 21
+Set r243 , 23
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 3
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -702,16 +746,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r244 , 4
-Set r243 , 21
+Set r243 , 23
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 4
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -719,16 +763,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r244 , 5
-Set r243 , 21
+Set r243 , 23
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 5
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -736,16 +780,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r244 , 6
-Set r243 , 21
+Set r243 , 23
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 6
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -753,16 +797,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r244 , 7
-Set r243 , 21
+Set r243 , 23
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 7
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -770,16 +814,16 @@ Add r245 , r243
 Add r245 , r244
 Write r245 , r248
 // write_register- end
-// read_register- self: Data { stack_frame_offset: 21, size: 9, data_type: Array { inside: U32, len: 8 } }
+// read_register- self: Data { stack_frame_offset: 23, size: 9, data_type: Array { inside: U32, len: 8 } }
 Set r244 , 8
-Set r243 , 21
+Set r243 , 23
 Add r243 , r255
 Add r243 , r244
 Read r248 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 30, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: Array { inside: U32, len: 8 }, offset_of_data_from_reference_addr: 0 } }
 Set r244 , 8
-Set r245 , 30
+Set r245 , 32
 Add r245 , r255
 Read r245 , r245
 Set r243 , 0
@@ -790,24 +834,24 @@ Write r245 , r248
 
 // handle number
 Set r250 , 0
-// allocate_stack - current: 31 size: 1
+// allocate_stack - current: 33 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 31, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 33, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 31
+Set r246 , 33
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-RSet r246 , :static_declaration_end18
+RSet r246 , :static_declaration_end27
 RJmp r246
 :task_que_head_index
 Set r0 , 0
-:static_declaration_end18
-// allocate_stack - current: 32 size: 1
+:static_declaration_end27
+// allocate_stack - current: 34 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
@@ -816,20 +860,20 @@ Add r246 , r251
 Set r245 , 0
 Sub r246 , r245
 // write_directly_to_reference_pointer - 244, input: r246
-Set r244 , 32
+Set r244 , 34
 Add r244 , r255
 Write r244 , r246
 // write_directly_to_reference_pointer - end
-// read_register- self: Data { stack_frame_offset: 31, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 33, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 31
+Set r242 , 33
 Add r242 , r255
 Add r242 , r243
 Read r247 , r242
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 32, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 34, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r243 , 0
-Set r244 , 32
+Set r244 , 34
 Add r244 , r255
 Read r244 , r244
 Set r242 , 0
@@ -840,14 +884,14 @@ Write r244 , r247
 
 // assign_new_variable
 // handle number
-Set r250 , 4
-// allocate_stack - current: 33 size: 1
+Set r250 , 12
+// allocate_stack - current: 35 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 33, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 35, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 33
+Set r246 , 35
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
@@ -855,13 +899,13 @@ Write r246 , r250
 // handle number end
 // call_function_code
 // call_function_code - needed_stack: 4
-// allocate_stack - current: 34 size: 4
+// allocate_stack - current: 36 size: 4
 Set r247 , 4
 Add r254 , r247
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 33, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 35, size: 1, data_type: U32 }
 Set r242 , 0
-Set r241 , 33
+Set r241 , 35
 Add r241 , r255
 Add r241 , r242
 Read r245 , r241
@@ -869,24 +913,24 @@ Read r245 , r241
 Set r248 , 4294967294
 Add r248 , r254
 Write r248 , r245
-RSet r245 , :function-return19
+RSet r245 , :function-return28
 Add r245 , r251
 Set r241 , 0
 Sub r245 , r241
 Write r254 , r245
 RSet r248 , :function_core_allocate_4
 RJmp r248
-:function-return19
+:function-return28
 // function call output: Some(FunctionInputData { name: "core_allocate", data_type: U32, stack_frame_offset: -3 })
-// function call converted output data : Some(Data { stack_frame_offset: 35, size: 1, data_type: U32 })
+// function call converted output data : Some(Data { stack_frame_offset: 37, size: 1, data_type: U32 })
 // assign_new_variable - end
 
-RSet r250 , :static_declaration_end20
+RSet r250 , :static_declaration_end29
 RJmp r250
 :idt_addr
 Set r0 , 0
-:static_declaration_end20
-// allocate_stack - current: 38 size: 1
+:static_declaration_end29
+// allocate_stack - current: 40 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
@@ -895,20 +939,20 @@ Add r250 , r251
 Set r247 , 0
 Sub r250 , r247
 // write_directly_to_reference_pointer - 246, input: r250
-Set r246 , 38
+Set r246 , 40
 Add r246 , r255
 Write r246 , r250
 // write_directly_to_reference_pointer - end
-// read_register- self: Data { stack_frame_offset: 35, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
 Set r245 , 0
-Set r244 , 35
+Set r244 , 37
 Add r244 , r255
 Add r244 , r245
 Read r249 , r244
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 38, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 40, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r245 , 0
-Set r246 , 38
+Set r246 , 40
 Add r246 , r255
 Read r246 , r246
 Set r244 , 0
@@ -919,94 +963,94 @@ Write r246 , r249
 
 // assignment
 // memory_access
-// allocate_stack - current: 39 size: 1
+// allocate_stack - current: 41 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
 // handle_binary_expr
 // handle number
 Set r249 , 0
-// allocate_stack - current: 40 size: 1
+// allocate_stack - current: 42 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 40, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 42, size: 1, data_type: U32 }
 Set r246 , 0
-Set r245 , 40
+Set r245 , 42
 Add r245 , r255
 Add r245 , r246
 Write r245 , r249
 // write_register- end
 // handle number end
-// allocate_stack - current: 41 size: 1
+// allocate_stack - current: 43 size: 1
 Set r244 , 1
 Add r254 , r244
 // alloc_stack - end
 // handle_binary_expr - output_register- r245
-// read_register- self: Data { stack_frame_offset: 35, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
 Set r242 , 0
-Set r241 , 35
+Set r241 , 37
 Add r241 , r255
 Add r241 , r242
 Read r246 , r241
 // read_register- end
-// read_register- self: Data { stack_frame_offset: 40, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 42, size: 1, data_type: U32 }
 Set r242 , 0
-Set r243 , 40
+Set r243 , 42
 Add r243 , r255
 Add r243 , r242
 Read r247 , r243
 // read_register- end
 Add r246 , r247
 Cp r245 , r246
-// write_register- self: Data { stack_frame_offset: 41, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 43, size: 1, data_type: U32 }
 Set r242 , 0
-Set r241 , 41
+Set r241 , 43
 Add r241 , r255
 Add r241 , r242
 Write r241 , r245
 // write_register- end
 // handle_binary_expr - end
-// read_register- self: Data { stack_frame_offset: 41, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 43, size: 1, data_type: U32 }
 Set r246 , 0
-Set r241 , 41
+Set r241 , 43
 Add r241 , r255
 Add r241 , r246
 Read r245 , r241
 // read_register- end
 // write_directly_to_reference_pointer - 246, input: r245
-Set r246 , 39
+Set r246 , 41
 Add r246 , r255
 Write r246 , r245
 // write_directly_to_reference_pointer - end
 // memory_access- end
-// allocate_stack - current: 42 size: 1
+// allocate_stack - current: 44 size: 1
 Set r241 , 1
 Add r254 , r241
 // alloc_stack - end
-RSet r245 , :function_syscall_handler_10
+RSet r245 , :function_syscall_handler_18
 Add r245 , r251
 Set r246 , 0
 Sub r245 , r246
-// write_register- self: Data { stack_frame_offset: 42, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 44, size: 1, data_type: U32 }
 Set r242 , 0
-Set r243 , 42
+Set r243 , 44
 Add r243 , r255
 Add r243 , r242
 Write r243 , r245
 // write_register- end
-// assignment data: Data { stack_frame_offset: 42, size: 1, data_type: U32 }
+// assignment data: Data { stack_frame_offset: 44, size: 1, data_type: U32 }
 // assignment- variable.is_reference: true data.is_reference: false 
-// read_register- self: Data { stack_frame_offset: 42, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 44, size: 1, data_type: U32 }
 Set r242 , 0
-Set r247 , 42
+Set r247 , 44
 Add r247 , r255
 Add r247 , r242
 Read r245 , r247
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 39, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 41, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r242 , 0
-Set r243 , 39
+Set r243 , 41
 Add r243 , r255
 Read r243 , r243
 Set r247 , 0
@@ -1018,68 +1062,68 @@ Write r243 , r245
 
 // assignment
 // memory_access
-// allocate_stack - current: 43 size: 1
+// allocate_stack - current: 45 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
 // handle_binary_expr
 // handle number
 Set r249 , 1
-// allocate_stack - current: 44 size: 1
+// allocate_stack - current: 46 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 44, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 46, size: 1, data_type: U32 }
 Set r246 , 0
-Set r245 , 44
+Set r245 , 46
 Add r245 , r255
 Add r245 , r246
 Write r245 , r249
 // write_register- end
 // handle number end
-// allocate_stack - current: 45 size: 1
+// allocate_stack - current: 47 size: 1
 Set r244 , 1
 Add r254 , r244
 // alloc_stack - end
 // handle_binary_expr - output_register- r245
-// read_register- self: Data { stack_frame_offset: 35, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
 Set r242 , 0
-Set r241 , 35
+Set r241 , 37
 Add r241 , r255
 Add r241 , r242
 Read r246 , r241
 // read_register- end
-// read_register- self: Data { stack_frame_offset: 44, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 46, size: 1, data_type: U32 }
 Set r242 , 0
-Set r243 , 44
+Set r243 , 46
 Add r243 , r255
 Add r243 , r242
 Read r247 , r243
 // read_register- end
 Add r246 , r247
 Cp r245 , r246
-// write_register- self: Data { stack_frame_offset: 45, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 47, size: 1, data_type: U32 }
 Set r242 , 0
-Set r241 , 45
+Set r241 , 47
 Add r241 , r255
 Add r241 , r242
 Write r241 , r245
 // write_register- end
 // handle_binary_expr - end
-// read_register- self: Data { stack_frame_offset: 45, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 47, size: 1, data_type: U32 }
 Set r246 , 0
-Set r241 , 45
+Set r241 , 47
 Add r241 , r255
 Add r241 , r246
 Read r245 , r241
 // read_register- end
 // write_directly_to_reference_pointer - 246, input: r245
-Set r246 , 43
+Set r246 , 45
 Add r246 , r255
 Write r246 , r245
 // write_directly_to_reference_pointer - end
 // memory_access- end
-// allocate_stack - current: 46 size: 1
+// allocate_stack - current: 48 size: 1
 Set r241 , 1
 Add r254 , r241
 // alloc_stack - end
@@ -1087,25 +1131,25 @@ RSet r245 , :function_timer_interrupt_9
 Add r245 , r251
 Set r246 , 0
 Sub r245 , r246
-// write_register- self: Data { stack_frame_offset: 46, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 48, size: 1, data_type: U32 }
 Set r242 , 0
-Set r243 , 46
+Set r243 , 48
 Add r243 , r255
 Add r243 , r242
 Write r243 , r245
 // write_register- end
-// assignment data: Data { stack_frame_offset: 46, size: 1, data_type: U32 }
+// assignment data: Data { stack_frame_offset: 48, size: 1, data_type: U32 }
 // assignment- variable.is_reference: true data.is_reference: false 
-// read_register- self: Data { stack_frame_offset: 46, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 48, size: 1, data_type: U32 }
 Set r242 , 0
-Set r247 , 46
+Set r247 , 48
 Add r247 , r255
 Add r247 , r242
 Read r245 , r247
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 43, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 45, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r242 , 0
-Set r243 , 43
+Set r243 , 45
 Add r243 , r255
 Read r243 , r243
 Set r247 , 0
@@ -1117,68 +1161,68 @@ Write r243 , r245
 
 // assignment
 // memory_access
-// allocate_stack - current: 47 size: 1
+// allocate_stack - current: 49 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
 // handle_binary_expr
 // handle number
 Set r249 , 2
-// allocate_stack - current: 48 size: 1
+// allocate_stack - current: 50 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 48, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 50, size: 1, data_type: U32 }
 Set r246 , 0
-Set r245 , 48
+Set r245 , 50
 Add r245 , r255
 Add r245 , r246
 Write r245 , r249
 // write_register- end
 // handle number end
-// allocate_stack - current: 49 size: 1
+// allocate_stack - current: 51 size: 1
 Set r244 , 1
 Add r254 , r244
 // alloc_stack - end
 // handle_binary_expr - output_register- r245
-// read_register- self: Data { stack_frame_offset: 35, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
 Set r242 , 0
-Set r241 , 35
+Set r241 , 37
 Add r241 , r255
 Add r241 , r242
 Read r246 , r241
 // read_register- end
-// read_register- self: Data { stack_frame_offset: 48, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 50, size: 1, data_type: U32 }
 Set r242 , 0
-Set r243 , 48
+Set r243 , 50
 Add r243 , r255
 Add r243 , r242
 Read r247 , r243
 // read_register- end
 Add r246 , r247
 Cp r245 , r246
-// write_register- self: Data { stack_frame_offset: 49, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 51, size: 1, data_type: U32 }
 Set r242 , 0
-Set r241 , 49
+Set r241 , 51
 Add r241 , r255
 Add r241 , r242
 Write r241 , r245
 // write_register- end
 // handle_binary_expr - end
-// read_register- self: Data { stack_frame_offset: 49, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 51, size: 1, data_type: U32 }
 Set r246 , 0
-Set r241 , 49
+Set r241 , 51
 Add r241 , r255
 Add r241 , r246
 Read r245 , r241
 // read_register- end
 // write_directly_to_reference_pointer - 246, input: r245
-Set r246 , 47
+Set r246 , 49
 Add r246 , r255
 Write r246 , r245
 // write_directly_to_reference_pointer - end
 // memory_access- end
-// allocate_stack - current: 50 size: 1
+// allocate_stack - current: 52 size: 1
 Set r241 , 1
 Add r254 , r241
 // alloc_stack - end
@@ -1186,25 +1230,25 @@ RSet r245 , :function_keyboard_interrupt_8
 Add r245 , r251
 Set r246 , 0
 Sub r245 , r246
-// write_register- self: Data { stack_frame_offset: 50, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 52, size: 1, data_type: U32 }
 Set r242 , 0
-Set r243 , 50
+Set r243 , 52
 Add r243 , r255
 Add r243 , r242
 Write r243 , r245
 // write_register- end
-// assignment data: Data { stack_frame_offset: 50, size: 1, data_type: U32 }
+// assignment data: Data { stack_frame_offset: 52, size: 1, data_type: U32 }
 // assignment- variable.is_reference: true data.is_reference: false 
-// read_register- self: Data { stack_frame_offset: 50, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 52, size: 1, data_type: U32 }
 Set r242 , 0
-Set r247 , 50
+Set r247 , 52
 Add r247 , r255
 Add r247 , r242
 Read r245 , r247
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 47, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// write_register- self: Data { stack_frame_offset: 49, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r242 , 0
-Set r243 , 47
+Set r243 , 49
 Add r243 , r255
 Read r243 , r243
 Set r247 , 0
@@ -1214,1674 +1258,1802 @@ Write r243 , r245
 // write_register- end
 // end assignment
 
-// read_register- self: Data { stack_frame_offset: 35, size: 1, data_type: U32 }
-Set r248 , 0
-Set r247 , 35
-Add r247 , r255
-Add r247 , r248
-Read r250 , r247
-// read_register- end
-Idt r250
-
-Halt
-
+// assignment
+// memory_access
+// allocate_stack - current: 53 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// handle_binary_expr
 // handle number
-Set r250 , 0
-// allocate_stack - current: 51 size: 1
-Set r249 , 1
-Add r254 , r249
+Set r249 , 5
+// allocate_stack - current: 54 size: 1
+Set r248 , 1
+Add r254 , r248
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 51, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 51
-Add r246 , r255
-Add r246 , r247
-Write r246 , r250
+// write_register- self: Data { stack_frame_offset: 54, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 54
+Add r245 , r255
+Add r245 , r246
+Write r245 , r249
 // write_register- end
 // handle number end
-// allocate_stack - current: 52 size: 8
-Set r247 , 8
-Add r254 , r247
+// allocate_stack - current: 55 size: 1
+Set r244 , 1
+Add r254 , r244
 // alloc_stack - end
-Set r246 , 7
-// write_register- self: Data { stack_frame_offset: 52, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 0
-Set r244 , 52
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 50
-// write_register- self: Data { stack_frame_offset: 52, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 1
-Set r248 , 52
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 52, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 2
-Set r244 , 52
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 52, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 3
-Set r248 , 52
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 52, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 4
-Set r244 , 52
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 52, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 5
-Set r248 , 52
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 52, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 6
-Set r244 , 52
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
+// handle_binary_expr - output_register- r245
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 37
+Add r241 , r255
+Add r241 , r242
+Read r246 , r241
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 54, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 54
+Add r243 , r255
+Add r243 , r242
+Read r247 , r243
+// read_register- end
+Add r246 , r247
+Cp r245 , r246
+// write_register- self: Data { stack_frame_offset: 55, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 55
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
 // write_register- end
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 55, size: 1, data_type: U32 }
+Set r246 , 0
+Set r241 , 55
+Add r241 , r255
+Add r241 , r246
+Read r245 , r241
+// read_register- end
+// write_directly_to_reference_pointer - 246, input: r245
 Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 52, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 7
-Set r248 , 52
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-// allocate_stack - current: 60 size: 1
-Set r248 , 1
-Add r254 , r248
+Add r246 , r255
+Write r246 , r245
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// allocate_stack - current: 56 size: 1
+Set r241 , 1
+Add r254 , r241
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 51, size: 1, data_type: U32 }
-Set r243 , 0
-Set r242 , 51
-Add r242 , r255
-Add r242 , r243
-Read r245 , r242
+RSet r245 , :function_illegal_instruction_interrupt_11
+Add r245 , r251
+Set r246 , 0
+Sub r245 , r246
+// write_register- self: Data { stack_frame_offset: 56, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 56
+Add r243 , r255
+Add r243 , r242
+Write r243 , r245
+// write_register- end
+// assignment data: Data { stack_frame_offset: 56, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 56, size: 1, data_type: U32 }
+Set r242 , 0
+Set r247 , 56
+Add r247 , r255
+Add r247 , r242
+Read r245 , r247
 // read_register- end
-// read_addr_of_self
-Set r242 , 52
-Add r242 , r255
-// read_addr_of_self - end
-// read_addr_of_self
-Set r243 , 60
+// write_register- self: Data { stack_frame_offset: 53, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r242 , 0
+Set r243 , 53
 Add r243 , r255
-// read_addr_of_self - end
-Syscall r245 , r242 , r243
-// write_register- self: Data { stack_frame_offset: 60, size: 1, data_type: U32 }
-Set r241 , 0
-Set r240 , 60
-Add r240 , r255
-Add r240 , r241
-Write r240 , r243
+Read r243 , r243
+Set r247 , 0
+Add r243 , r247
+Add r243 , r242
+Write r243 , r245
 // write_register- end
+// end assignment
 
+// assignment
+// memory_access
+// allocate_stack - current: 57 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// handle_binary_expr
 // handle number
-Set r250 , 0
-// allocate_stack - current: 61 size: 1
-Set r249 , 1
-Add r254 , r249
+Set r249 , 6
+// allocate_stack - current: 58 size: 1
+Set r248 , 1
+Add r254 , r248
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 61, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 61
-Add r246 , r255
-Add r246 , r247
-Write r246 , r250
+// write_register- self: Data { stack_frame_offset: 58, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 58
+Add r245 , r255
+Add r245 , r246
+Write r245 , r249
 // write_register- end
 // handle number end
-// allocate_stack - current: 62 size: 8
-Set r247 , 8
-Add r254 , r247
+// allocate_stack - current: 59 size: 1
+Set r244 , 1
+Add r254 , r244
 // alloc_stack - end
-Set r246 , 7
-// write_register- self: Data { stack_frame_offset: 62, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 0
-Set r244 , 62
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 50
-// write_register- self: Data { stack_frame_offset: 62, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 1
-Set r248 , 62
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 62, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 2
-Set r244 , 62
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 62, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 3
-Set r248 , 62
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 62, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 4
-Set r244 , 62
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 62, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 5
-Set r248 , 62
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 62, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 6
-Set r244 , 62
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 62, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 7
-Set r248 , 62
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
+// handle_binary_expr - output_register- r245
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 37
+Add r241 , r255
+Add r241 , r242
+Read r246 , r241
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 58, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 58
+Add r243 , r255
+Add r243 , r242
+Read r247 , r243
+// read_register- end
+Add r246 , r247
+Cp r245 , r246
+// write_register- self: Data { stack_frame_offset: 59, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 59
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
 // write_register- end
-// allocate_stack - current: 70 size: 1
-Set r248 , 1
-Add r254 , r248
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 59, size: 1, data_type: U32 }
+Set r246 , 0
+Set r241 , 59
+Add r241 , r255
+Add r241 , r246
+Read r245 , r241
+// read_register- end
+// write_directly_to_reference_pointer - 246, input: r245
+Set r246 , 57
+Add r246 , r255
+Write r246 , r245
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// allocate_stack - current: 60 size: 1
+Set r241 , 1
+Add r254 , r241
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 61, size: 1, data_type: U32 }
-Set r243 , 0
-Set r242 , 61
-Add r242 , r255
-Add r242 , r243
-Read r245 , r242
+RSet r245 , :function_divide_by_zero_interrupt_12
+Add r245 , r251
+Set r246 , 0
+Sub r245 , r246
+// write_register- self: Data { stack_frame_offset: 60, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 60
+Add r243 , r255
+Add r243 , r242
+Write r243 , r245
+// write_register- end
+// assignment data: Data { stack_frame_offset: 60, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 60, size: 1, data_type: U32 }
+Set r242 , 0
+Set r247 , 60
+Add r247 , r255
+Add r247 , r242
+Read r245 , r247
 // read_register- end
-// read_addr_of_self
-Set r242 , 62
-Add r242 , r255
-// read_addr_of_self - end
-// read_addr_of_self
-Set r243 , 70
+// write_register- self: Data { stack_frame_offset: 57, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r242 , 0
+Set r243 , 57
 Add r243 , r255
-// read_addr_of_self - end
-Syscall r245 , r242 , r243
-// write_register- self: Data { stack_frame_offset: 70, size: 1, data_type: U32 }
-Set r241 , 0
-Set r240 , 70
-Add r240 , r255
-Add r240 , r241
-Write r240 , r243
+Read r243 , r243
+Set r247 , 0
+Add r243 , r247
+Add r243 , r242
+Write r243 , r245
 // write_register- end
+// end assignment
 
+// assignment
+// memory_access
+// allocate_stack - current: 61 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// handle_binary_expr
 // handle number
-Set r250 , 0
-// allocate_stack - current: 71 size: 1
-Set r249 , 1
-Add r254 , r249
+Set r249 , 7
+// allocate_stack - current: 62 size: 1
+Set r248 , 1
+Add r254 , r248
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 71, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 71
-Add r246 , r255
-Add r246 , r247
-Write r246 , r250
+// write_register- self: Data { stack_frame_offset: 62, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 62
+Add r245 , r255
+Add r245 , r246
+Write r245 , r249
 // write_register- end
 // handle number end
-// allocate_stack - current: 72 size: 8
-Set r247 , 8
-Add r254 , r247
+// allocate_stack - current: 63 size: 1
+Set r244 , 1
+Add r254 , r244
 // alloc_stack - end
-Set r246 , 7
-// write_register- self: Data { stack_frame_offset: 72, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 0
-Set r244 , 72
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 50
-// write_register- self: Data { stack_frame_offset: 72, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 1
-Set r248 , 72
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 72, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 2
-Set r244 , 72
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 72, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 3
-Set r248 , 72
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 72, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 4
-Set r244 , 72
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 72, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 5
-Set r248 , 72
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 72, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 6
-Set r244 , 72
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 72, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 7
-Set r248 , 72
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
+// handle_binary_expr - output_register- r245
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 37
+Add r241 , r255
+Add r241 , r242
+Read r246 , r241
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 62, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 62
+Add r243 , r255
+Add r243 , r242
+Read r247 , r243
+// read_register- end
+Add r246 , r247
+Cp r245 , r246
+// write_register- self: Data { stack_frame_offset: 63, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 63
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
 // write_register- end
-// allocate_stack - current: 80 size: 1
-Set r248 , 1
-Add r254 , r248
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 63, size: 1, data_type: U32 }
+Set r246 , 0
+Set r241 , 63
+Add r241 , r255
+Add r241 , r246
+Read r245 , r241
+// read_register- end
+// write_directly_to_reference_pointer - 246, input: r245
+Set r246 , 61
+Add r246 , r255
+Write r246 , r245
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// allocate_stack - current: 64 size: 1
+Set r241 , 1
+Add r254 , r241
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 71, size: 1, data_type: U32 }
-Set r243 , 0
-Set r242 , 71
-Add r242 , r255
-Add r242 , r243
-Read r245 , r242
+RSet r245 , :function_bad_address_interrupt_13
+Add r245 , r251
+Set r246 , 0
+Sub r245 , r246
+// write_register- self: Data { stack_frame_offset: 64, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 64
+Add r243 , r255
+Add r243 , r242
+Write r243 , r245
+// write_register- end
+// assignment data: Data { stack_frame_offset: 64, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 64, size: 1, data_type: U32 }
+Set r242 , 0
+Set r247 , 64
+Add r247 , r255
+Add r247 , r242
+Read r245 , r247
 // read_register- end
-// read_addr_of_self
-Set r242 , 72
-Add r242 , r255
-// read_addr_of_self - end
-// read_addr_of_self
-Set r243 , 80
+// write_register- self: Data { stack_frame_offset: 61, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r242 , 0
+Set r243 , 61
 Add r243 , r255
-// read_addr_of_self - end
-Syscall r245 , r242 , r243
-// write_register- self: Data { stack_frame_offset: 80, size: 1, data_type: U32 }
-Set r241 , 0
-Set r240 , 80
-Add r240 , r255
-Add r240 , r241
-Write r240 , r243
+Read r243 , r243
+Set r247 , 0
+Add r243 , r247
+Add r243 , r242
+Write r243 , r245
 // write_register- end
+// end assignment
 
-// handle number
-Set r250 , 0
-// allocate_stack - current: 81 size: 1
-Set r249 , 1
-Add r254 , r249
+// assignment
+// memory_access
+// allocate_stack - current: 65 size: 1
+Set r250 , 1
+Add r254 , r250
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 81, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 81
-Add r246 , r255
-Add r246 , r247
-Write r246 , r250
+// handle_binary_expr
+// handle number
+Set r249 , 8
+// allocate_stack - current: 66 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 66, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 66
+Add r245 , r255
+Add r245 , r246
+Write r245 , r249
 // write_register- end
 // handle number end
-// allocate_stack - current: 82 size: 8
-Set r247 , 8
-Add r254 , r247
+// allocate_stack - current: 67 size: 1
+Set r244 , 1
+Add r254 , r244
 // alloc_stack - end
-Set r246 , 7
-// write_register- self: Data { stack_frame_offset: 82, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 0
-Set r244 , 82
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 50
-// write_register- self: Data { stack_frame_offset: 82, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 1
-Set r248 , 82
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 82, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 2
-Set r244 , 82
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 82, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 3
-Set r248 , 82
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 82, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 4
-Set r244 , 82
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 82, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 5
-Set r248 , 82
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
+// handle_binary_expr - output_register- r245
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 37
+Add r241 , r255
+Add r241 , r242
+Read r246 , r241
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 66, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 66
+Add r243 , r255
+Add r243 , r242
+Read r247 , r243
+// read_register- end
+Add r246 , r247
+Cp r245 , r246
+// write_register- self: Data { stack_frame_offset: 67, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 67
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
 // write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 82, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 6
-Set r244 , 82
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 67, size: 1, data_type: U32 }
+Set r246 , 0
+Set r241 , 67
+Add r241 , r255
+Add r241 , r246
+Read r245 , r241
+// read_register- end
+// write_directly_to_reference_pointer - 246, input: r245
+Set r246 , 65
+Add r246 , r255
+Write r246 , r245
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// allocate_stack - current: 68 size: 1
+Set r241 , 1
+Add r254 , r241
+// alloc_stack - end
+RSet r245 , :function_bad_thread_index_interrupt_14
+Add r245 , r251
+Set r246 , 0
+Sub r245 , r246
+// write_register- self: Data { stack_frame_offset: 68, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 68
+Add r243 , r255
+Add r243 , r242
+Write r243 , r245
 // write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 82, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 7
-Set r248 , 82
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
+// assignment data: Data { stack_frame_offset: 68, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 68, size: 1, data_type: U32 }
+Set r242 , 0
+Set r247 , 68
+Add r247 , r255
+Add r247 , r242
+Read r245 , r247
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 65, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r242 , 0
+Set r243 , 65
+Add r243 , r255
+Read r243 , r243
+Set r247 , 0
+Add r243 , r247
+Add r243 , r242
+Write r243 , r245
 // write_register- end
-// allocate_stack - current: 90 size: 1
+// end assignment
+
+// assignment
+// memory_access
+// allocate_stack - current: 69 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// handle_binary_expr
+// handle number
+Set r249 , 9
+// allocate_stack - current: 70 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 81, size: 1, data_type: U32 }
-Set r243 , 0
-Set r242 , 81
-Add r242 , r255
-Add r242 , r243
-Read r245 , r242
+// write_register- self: Data { stack_frame_offset: 70, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 70
+Add r245 , r255
+Add r245 , r246
+Write r245 , r249
+// write_register- end
+// handle number end
+// allocate_stack - current: 71 size: 1
+Set r244 , 1
+Add r254 , r244
+// alloc_stack - end
+// handle_binary_expr - output_register- r245
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 37
+Add r241 , r255
+Add r241 , r242
+Read r246 , r241
 // read_register- end
-// read_addr_of_self
-Set r242 , 82
-Add r242 , r255
-// read_addr_of_self - end
-// read_addr_of_self
-Set r243 , 90
+// read_register- self: Data { stack_frame_offset: 70, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 70
 Add r243 , r255
-// read_addr_of_self - end
-Syscall r245 , r242 , r243
-// write_register- self: Data { stack_frame_offset: 90, size: 1, data_type: U32 }
-Set r241 , 0
-Set r240 , 90
-Add r240 , r255
-Add r240 , r241
-Write r240 , r243
+Add r243 , r242
+Read r247 , r243
+// read_register- end
+Add r246 , r247
+Cp r245 , r246
+// write_register- self: Data { stack_frame_offset: 71, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 71
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
+// write_register- end
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 71, size: 1, data_type: U32 }
+Set r246 , 0
+Set r241 , 71
+Add r241 , r255
+Add r241 , r246
+Read r245 , r241
+// read_register- end
+// write_directly_to_reference_pointer - 246, input: r245
+Set r246 , 69
+Add r246 , r255
+Write r246 , r245
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// allocate_stack - current: 72 size: 1
+Set r241 , 1
+Add r254 , r241
+// alloc_stack - end
+RSet r245 , :function_ipi_interrupt_17
+Add r245 , r251
+Set r246 , 0
+Sub r245 , r246
+// write_register- self: Data { stack_frame_offset: 72, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 72
+Add r243 , r255
+Add r243 , r242
+Write r243 , r245
+// write_register- end
+// assignment data: Data { stack_frame_offset: 72, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 72, size: 1, data_type: U32 }
+Set r242 , 0
+Set r247 , 72
+Add r247 , r255
+Add r247 , r242
+Read r245 , r247
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 69, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r242 , 0
+Set r243 , 69
+Add r243 , r255
+Read r243 , r243
+Set r247 , 0
+Add r243 , r247
+Add r243 , r242
+Write r243 , r245
 // write_register- end
+// end assignment
 
+// assignment
+// memory_access
+// allocate_stack - current: 73 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// handle_binary_expr
 // handle number
-Set r250 , 0
-// allocate_stack - current: 91 size: 1
-Set r249 , 1
-Add r254 , r249
+Set r249 , 10
+// allocate_stack - current: 74 size: 1
+Set r248 , 1
+Add r254 , r248
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 91, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 91
-Add r246 , r255
-Add r246 , r247
-Write r246 , r250
+// write_register- self: Data { stack_frame_offset: 74, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 74
+Add r245 , r255
+Add r245 , r246
+Write r245 , r249
 // write_register- end
 // handle number end
-// allocate_stack - current: 92 size: 8
-Set r247 , 8
-Add r254 , r247
+// allocate_stack - current: 75 size: 1
+Set r244 , 1
+Add r254 , r244
 // alloc_stack - end
-Set r246 , 7
-// write_register- self: Data { stack_frame_offset: 92, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 0
-Set r244 , 92
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 50
-// write_register- self: Data { stack_frame_offset: 92, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 1
-Set r248 , 92
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 92, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 2
-Set r244 , 92
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 92, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 3
-Set r248 , 92
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 92, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 4
-Set r244 , 92
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 92, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 5
-Set r248 , 92
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
+// handle_binary_expr - output_register- r245
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 37
+Add r241 , r255
+Add r241 , r242
+Read r246 , r241
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 74, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 74
+Add r243 , r255
+Add r243 , r242
+Read r247 , r243
+// read_register- end
+Add r246 , r247
+Cp r245 , r246
+// write_register- self: Data { stack_frame_offset: 75, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 75
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
 // write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 92, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 6
-Set r244 , 92
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 75, size: 1, data_type: U32 }
+Set r246 , 0
+Set r241 , 75
+Add r241 , r255
+Add r241 , r246
+Read r245 , r241
+// read_register- end
+// write_directly_to_reference_pointer - 246, input: r245
+Set r246 , 73
+Add r246 , r255
+Write r246 , r245
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// allocate_stack - current: 76 size: 1
+Set r241 , 1
+Add r254 , r241
+// alloc_stack - end
+RSet r245 , :function_serial_input_interrupt_15
+Add r245 , r251
+Set r246 , 0
+Sub r245 , r246
+// write_register- self: Data { stack_frame_offset: 76, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 76
+Add r243 , r255
+Add r243 , r242
+Write r243 , r245
 // write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 92, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 7
-Set r248 , 92
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
+// assignment data: Data { stack_frame_offset: 76, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 76, size: 1, data_type: U32 }
+Set r242 , 0
+Set r247 , 76
+Add r247 , r255
+Add r247 , r242
+Read r245 , r247
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 73, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r242 , 0
+Set r243 , 73
+Add r243 , r255
+Read r243 , r243
+Set r247 , 0
+Add r243 , r247
+Add r243 , r242
+Write r243 , r245
 // write_register- end
-// allocate_stack - current: 100 size: 1
+// end assignment
+
+// assignment
+// memory_access
+// allocate_stack - current: 77 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// handle_binary_expr
+// handle number
+Set r249 , 11
+// allocate_stack - current: 78 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 91, size: 1, data_type: U32 }
-Set r243 , 0
-Set r242 , 91
-Add r242 , r255
-Add r242 , r243
-Read r245 , r242
+// write_register- self: Data { stack_frame_offset: 78, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 78
+Add r245 , r255
+Add r245 , r246
+Write r245 , r249
+// write_register- end
+// handle number end
+// allocate_stack - current: 79 size: 1
+Set r244 , 1
+Add r254 , r244
+// alloc_stack - end
+// handle_binary_expr - output_register- r245
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 37
+Add r241 , r255
+Add r241 , r242
+Read r246 , r241
 // read_register- end
-// read_addr_of_self
-Set r242 , 92
-Add r242 , r255
-// read_addr_of_self - end
-// read_addr_of_self
-Set r243 , 100
+// read_register- self: Data { stack_frame_offset: 78, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 78
 Add r243 , r255
-// read_addr_of_self - end
-Syscall r245 , r242 , r243
-// write_register- self: Data { stack_frame_offset: 100, size: 1, data_type: U32 }
-Set r241 , 0
-Set r240 , 100
-Add r240 , r255
-Add r240 , r241
-Write r240 , r243
+Add r243 , r242
+Read r247 , r243
+// read_register- end
+Add r246 , r247
+Cp r245 , r246
+// write_register- self: Data { stack_frame_offset: 79, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 79
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
+// write_register- end
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 79, size: 1, data_type: U32 }
+Set r246 , 0
+Set r241 , 79
+Add r241 , r255
+Add r241 , r246
+Read r245 , r241
+// read_register- end
+// write_directly_to_reference_pointer - 246, input: r245
+Set r246 , 77
+Add r246 , r255
+Write r246 , r245
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// allocate_stack - current: 80 size: 1
+Set r241 , 1
+Add r254 , r241
+// alloc_stack - end
+RSet r245 , :function_vblank_interrupt_16
+Add r245 , r251
+Set r246 , 0
+Sub r245 , r246
+// write_register- self: Data { stack_frame_offset: 80, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 80
+Add r243 , r255
+Add r243 , r242
+Write r243 , r245
+// write_register- end
+// assignment data: Data { stack_frame_offset: 80, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 80, size: 1, data_type: U32 }
+Set r242 , 0
+Set r247 , 80
+Add r247 , r255
+Add r247 , r242
+Read r245 , r247
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 77, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r242 , 0
+Set r243 , 77
+Add r243 , r255
+Read r243 , r243
+Set r247 , 0
+Add r243 , r247
+Add r243 , r242
+Write r243 , r245
 // write_register- end
+// end assignment
 
 // handle number
 Set r250 , 0
-// allocate_stack - current: 101 size: 1
+// allocate_stack - current: 81 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 101, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 81, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 101
+Set r246 , 81
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-// allocate_stack - current: 102 size: 8
-Set r247 , 8
+// handle number
+Set r246 , 255
+// allocate_stack - current: 82 size: 1
+Set r247 , 1
 Add r254 , r247
 // alloc_stack - end
-Set r246 , 7
-// write_register- self: Data { stack_frame_offset: 102, size: 8, data_type: Array { inside: Char, len: 7 } }
+// write_register- self: Data { stack_frame_offset: 82, size: 1, data_type: U32 }
 Set r245 , 0
-Set r244 , 102
+Set r244 , 82
 Add r244 , r255
 Add r244 , r245
 Write r244 , r246
 // write_register- end
-Set r246 , 50
-// write_register- self: Data { stack_frame_offset: 102, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 1
-Set r248 , 102
+// handle number end
+// read_register- self: Data { stack_frame_offset: 81, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 81
+Add r242 , r255
+Add r242 , r243
+Read r244 , r242
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 82, size: 1, data_type: U32 }
+Set r243 , 0
+Set r248 , 82
 Add r248 , r255
-Add r248 , r245
-Write r248 , r246
+Add r248 , r243
+Read r245 , r248
+// read_register- end
+Ictx r244 , r245
+
+// handle_binary_expr
+// handle number
+Set r250 , 1
+// allocate_stack - current: 83 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 83, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 83
+Add r246 , r255
+Add r246 , r247
+Write r246 , r250
 // write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 102, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 2
-Set r244 , 102
+// handle number end
+// handle number
+Set r246 , 31
+// allocate_stack - current: 84 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 84, size: 1, data_type: U32 }
+Set r245 , 0
+Set r244 , 84
 Add r244 , r255
 Add r244 , r245
 Write r244 , r246
 // write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 102, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 3
-Set r248 , 102
-Add r248 , r255
-Add r248 , r245
-Write r248 , r246
-// write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 102, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 4
-Set r244 , 102
-Add r244 , r255
-Add r244 , r245
-Write r244 , r246
+// handle number end
+// allocate_stack - current: 85 size: 1
+Set r243 , 1
+Add r254 , r243
+// alloc_stack - end
+// handle_binary_expr - output_register- r244
+// read_register- self: Data { stack_frame_offset: 83, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 83
+Add r240 , r255
+Add r240 , r241
+Read r245 , r240
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 84, size: 1, data_type: U32 }
+Set r241 , 0
+Set r242 , 84
+Add r242 , r255
+Add r242 , r241
+Read r248 , r242
+// read_register- end
+Shl r245 , r248
+Cp r244 , r245
+// write_register- self: Data { stack_frame_offset: 85, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 85
+Add r240 , r255
+Add r240 , r241
+Write r240 , r244
 // write_register- end
-Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 102, size: 8, data_type: Array { inside: Char, len: 7 } }
-Set r245 , 5
-Set r248 , 102
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 85, size: 1, data_type: U32 }
+Set r245 , 0
+Set r240 , 85
+Add r240 , r255
+Add r240 , r245
+Read r244 , r240
+// read_register- end
+Smsk r244
+
+// read_register- self: Data { stack_frame_offset: 37, size: 1, data_type: U32 }
+Set r248 , 0
+Set r247 , 37
+Add r247 , r255
+Add r247 , r248
+Read r250 , r247
+// read_register- end
+Idt r250
+
+// handle number
+Set r250 , 2
+// allocate_stack - current: 86 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 86, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 86
+Add r246 , r255
+Add r246 , r247
+Write r246 , r250
+// write_register- end
+// handle number end
+// handle_binary_expr
+// handle_binary_expr
+// handle number
+Set r246 , 1
+// allocate_stack - current: 87 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 87, size: 1, data_type: U32 }
+Set r245 , 0
+Set r244 , 87
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+// handle number end
+// handle number
+Set r244 , 28
+// allocate_stack - current: 88 size: 1
+Set r245 , 1
+Add r254 , r245
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 88, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 88
+Add r242 , r255
+Add r242 , r243
+Write r242 , r244
+// write_register- end
+// handle number end
+// allocate_stack - current: 89 size: 1
+Set r241 , 1
+Add r254 , r241
+// alloc_stack - end
+// handle_binary_expr - output_register- r242
+// read_register- self: Data { stack_frame_offset: 87, size: 1, data_type: U32 }
+Set r239 , 0
+Set r238 , 87
+Add r238 , r255
+Add r238 , r239
+Read r243 , r238
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 88, size: 1, data_type: U32 }
+Set r239 , 0
+Set r240 , 88
+Add r240 , r255
+Add r240 , r239
+Read r248 , r240
+// read_register- end
+Shl r243 , r248
+Cp r242 , r243
+// write_register- self: Data { stack_frame_offset: 89, size: 1, data_type: U32 }
+Set r239 , 0
+Set r238 , 89
+Add r238 , r255
+Add r238 , r239
+Write r238 , r242
+// write_register- end
+// handle_binary_expr - end
+// handle number
+Set r242 , 1000000
+// allocate_stack - current: 90 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 90, size: 1, data_type: U32 }
+Set r238 , 0
+Set r239 , 90
+Add r239 , r255
+Add r239 , r238
+Write r239 , r242
+// write_register- end
+// handle number end
+// allocate_stack - current: 91 size: 1
+Set r240 , 1
+Add r254 , r240
+// alloc_stack - end
+// handle_binary_expr - output_register- r239
+// read_register- self: Data { stack_frame_offset: 89, size: 1, data_type: U32 }
+Set r236 , 0
+Set r235 , 89
+Add r235 , r255
+Add r235 , r236
+Read r238 , r235
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 90, size: 1, data_type: U32 }
+Set r236 , 0
+Set r237 , 90
+Add r237 , r255
+Add r237 , r236
+Read r243 , r237
+// read_register- end
+Add r238 , r243
+Cp r239 , r238
+// write_register- self: Data { stack_frame_offset: 91, size: 1, data_type: U32 }
+Set r236 , 0
+Set r235 , 91
+Add r235 , r255
+Add r235 , r236
+Write r235 , r239
+// write_register- end
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 86, size: 1, data_type: U32 }
+Set r235 , 0
+Set r236 , 86
+Add r236 , r255
+Add r236 , r235
+Read r239 , r236
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 91, size: 1, data_type: U32 }
+Set r235 , 0
+Set r238 , 91
+Add r238 , r255
+Add r238 , r235
+Read r243 , r238
+// read_register- end
+Phrp r239 , r243
+
+Halt
+
+// handle number
+Set r250 , 0
+// allocate_stack - current: 92 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 92, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 92
+Add r246 , r255
+Add r246 , r247
+Write r246 , r250
+// write_register- end
+// handle number end
+// allocate_stack - current: 93 size: 8
+Set r247 , 8
+Add r254 , r247
+// alloc_stack - end
+Set r246 , 7
+// write_register- self: Data { stack_frame_offset: 93, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 0
+Set r244 , 93
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 50
+// write_register- self: Data { stack_frame_offset: 93, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 1
+Set r248 , 93
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 93, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 2
+Set r244 , 93
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 93, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 3
+Set r248 , 93
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 93, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 4
+Set r244 , 93
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 93, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 5
+Set r248 , 93
 Add r248 , r255
 Add r248 , r245
 Write r248 , r246
 // write_register- end
 Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 102, size: 8, data_type: Array { inside: Char, len: 7 } }
+// write_register- self: Data { stack_frame_offset: 93, size: 8, data_type: Array { inside: Char, len: 7 } }
 Set r245 , 6
-Set r244 , 102
+Set r244 , 93
 Add r244 , r255
 Add r244 , r245
 Write r244 , r246
 // write_register- end
 Set r246 , 53
-// write_register- self: Data { stack_frame_offset: 102, size: 8, data_type: Array { inside: Char, len: 7 } }
+// write_register- self: Data { stack_frame_offset: 93, size: 8, data_type: Array { inside: Char, len: 7 } }
 Set r245 , 7
-Set r248 , 102
+Set r248 , 93
 Add r248 , r255
 Add r248 , r245
 Write r248 , r246
 // write_register- end
-// allocate_stack - current: 110 size: 1
+// allocate_stack - current: 101 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 101, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 92, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 101
+Set r242 , 92
 Add r242 , r255
 Add r242 , r243
 Read r245 , r242
 // read_register- end
 // read_addr_of_self
-Set r242 , 102
+Set r242 , 93
 Add r242 , r255
 // read_addr_of_self - end
 // read_addr_of_self
-Set r243 , 110
+Set r243 , 101
 Add r243 , r255
 // read_addr_of_self - end
 Syscall r245 , r242 , r243
-// write_register- self: Data { stack_frame_offset: 110, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 101, size: 1, data_type: U32 }
 Set r241 , 0
-Set r240 , 110
+Set r240 , 101
 Add r240 , r255
 Add r240 , r241
 Write r240 , r243
 // write_register- end
 
-// assign_new_variable
 // handle number
-Set r250 , 5000
-// allocate_stack - current: 111 size: 1
+Set r250 , 0
+// allocate_stack - current: 102 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 111, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 102, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 111
+Set r246 , 102
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-// assign_new_variable - end
-
-// read_register- self: Data { stack_frame_offset: 111, size: 1, data_type: U32 }
-Set r248 , 0
-Set r247 , 111
-Add r247 , r255
-Add r247 , r248
-Read r250 , r247
-// read_register- end
-Jmp r250
-
-// allocate_stack - current: 112 size: 1
-Set r250 , 1
-Add r254 , r250
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 112, size: 1, data_type: U32 }
-Set r248 , 0
-Set r247 , 112
-Add r247 , r255
-Add r247 , r248
-Write r247 , r254
-// write_register- end
-:while_start21
-Set r249 , 1
-// allocate_stack - current: 113 size: 1
-Set r246 , 1
-Add r254 , r246
+// allocate_stack - current: 103 size: 8
+Set r247 , 8
+Add r254 , r247
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 113, size: 1, data_type: Bool }
-Set r244 , 0
-Set r243 , 113
-Add r243 , r255
-Add r243 , r244
-Write r243 , r249
-// write_register- end
-// read_register- self: Data { stack_frame_offset: 113, size: 1, data_type: Bool }
-Set r244 , 0
-Set r245 , 113
-Add r245 , r255
-Add r245 , r244
-Read r247 , r245
-// read_register- end
-Not r247
-RSet r248 , :while_end22
-RJmpc r248 , r247
-// while loop contents:
-Halt
-
-// handle number
+Set r246 , 7
+// write_register- self: Data { stack_frame_offset: 103, size: 8, data_type: Array { inside: Char, len: 7 } }
 Set r245 , 0
-// allocate_stack - current: 114 size: 1
-Set r244 , 1
-Add r254 , r244
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 114, size: 1, data_type: U32 }
-Set r242 , 0
-Set r241 , 114
-Add r241 , r255
-Add r241 , r242
-Write r241 , r245
-// write_register- end
-// handle number end
-// allocate_stack - current: 115 size: 6
-Set r242 , 6
-Add r254 , r242
-// alloc_stack - end
-Set r241 , 5
-// write_register- self: Data { stack_frame_offset: 115, size: 6, data_type: Array { inside: Char, len: 5 } }
-Set r240 , 0
-Set r239 , 115
-Add r239 , r255
-Add r239 , r240
-Write r239 , r241
+Set r244 , 103
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
 // write_register- end
-Set r241 , 67
-// write_register- self: Data { stack_frame_offset: 115, size: 6, data_type: Array { inside: Char, len: 5 } }
-Set r240 , 1
-Set r243 , 115
-Add r243 , r255
-Add r243 , r240
-Write r243 , r241
+Set r246 , 50
+// write_register- self: Data { stack_frame_offset: 103, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 1
+Set r248 , 103
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
 // write_register- end
-Set r241 , 108
-// write_register- self: Data { stack_frame_offset: 115, size: 6, data_type: Array { inside: Char, len: 5 } }
-Set r240 , 2
-Set r239 , 115
-Add r239 , r255
-Add r239 , r240
-Write r239 , r241
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 103, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 2
+Set r244 , 103
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
 // write_register- end
-Set r241 , 111
-// write_register- self: Data { stack_frame_offset: 115, size: 6, data_type: Array { inside: Char, len: 5 } }
-Set r240 , 3
-Set r243 , 115
-Add r243 , r255
-Add r243 , r240
-Write r243 , r241
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 103, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 3
+Set r248 , 103
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
 // write_register- end
-Set r241 , 99
-// write_register- self: Data { stack_frame_offset: 115, size: 6, data_type: Array { inside: Char, len: 5 } }
-Set r240 , 4
-Set r239 , 115
-Add r239 , r255
-Add r239 , r240
-Write r239 , r241
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 103, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 4
+Set r244 , 103
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
 // write_register- end
-Set r241 , 107
-// write_register- self: Data { stack_frame_offset: 115, size: 6, data_type: Array { inside: Char, len: 5 } }
-Set r240 , 5
-Set r243 , 115
-Add r243 , r255
-Add r243 , r240
-Write r243 , r241
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 103, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 5
+Set r248 , 103
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
 // write_register- end
-// allocate_stack - current: 121 size: 1
-Set r243 , 1
-Add r254 , r243
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 103, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 6
+Set r244 , 103
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 103, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 7
+Set r248 , 103
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+// allocate_stack - current: 111 size: 1
+Set r248 , 1
+Add r254 , r248
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 114, size: 1, data_type: U32 }
-Set r238 , 0
-Set r237 , 114
-Add r237 , r255
-Add r237 , r238
-Read r240 , r237
+// read_register- self: Data { stack_frame_offset: 102, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 102
+Add r242 , r255
+Add r242 , r243
+Read r245 , r242
 // read_register- end
 // read_addr_of_self
-Set r237 , 115
-Add r237 , r255
+Set r242 , 103
+Add r242 , r255
 // read_addr_of_self - end
 // read_addr_of_self
-Set r238 , 121
-Add r238 , r255
+Set r243 , 111
+Add r243 , r255
 // read_addr_of_self - end
-Syscall r240 , r237 , r238
-// write_register- self: Data { stack_frame_offset: 121, size: 1, data_type: U32 }
-Set r236 , 0
-Set r235 , 121
-Add r235 , r255
-Add r235 , r236
-Write r235 , r238
+Syscall r245 , r242 , r243
+// write_register- self: Data { stack_frame_offset: 111, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 111
+Add r240 , r255
+Add r240 , r241
+Write r240 , r243
 // write_register- end
 
-// while loop contents - end
-RSet r248 , :while_start21
-RJmp r248
-:while_end22
-// read_register- self: Data { stack_frame_offset: 112, size: 1, data_type: U32 }
-Set r240 , 0
-Set r238 , 112
-Add r238 , r255
-Add r238 , r240
-Read r254 , r238
-// read_register- end
-// function: Function { is_interrupt_function: false, name: "print_chars", input: [FunctionInputData { name: "text", data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 }, stack_frame_offset: -2 }], output: None, label_name: "function_print_chars_1" }
-:function_print_chars_1
-Cp r250 , r255
-Cp r255 , r254
-// allocate_stack - current: 0 size: 1
+// handle number
+Set r250 , 0
+// allocate_stack - current: 112 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// allocate_stack - current: 1 size: 1
-Set r248 , 1
-Add r254 , r248
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r246 , 0
-Set r245 , 1
-Add r245 , r255
-Add r245 , r246
-Write r245 , r250
-// write_register- end
-// assign_new_variable
-// allocate_stack - current: 2 size: 1
-Set r245 , 1
-Add r254 , r245
-// alloc_stack - end
-// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 } }
-Set r244 , 0
-Set r243 , 4294967294
-Add r243 , r255
-Read r243 , r243
+// write_register- self: Data { stack_frame_offset: 112, size: 1, data_type: U32 }
 Set r247 , 0
-Add r243 , r247
-Add r243 , r244
-Read r246 , r243
-// read_register- end
-// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
-Set r244 , 0
-Set r247 , 2
-Add r247 , r255
-Add r247 , r244
-Write r247 , r246
+Set r246 , 112
+Add r246 , r255
+Add r246 , r247
+Write r246 , r250
 // write_register- end
-// assign_new_variable - end
-
-// allocate_stack - current: 3 size: 1
-Set r250 , 1
-Add r254 , r250
+// handle number end
+// allocate_stack - current: 113 size: 8
+Set r247 , 8
+Add r254 , r247
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
-Set r248 , 0
-Set r247 , 3
-Add r247 , r255
-Add r247 , r248
-Write r247 , r254
+Set r246 , 7
+// write_register- self: Data { stack_frame_offset: 113, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 0
+Set r244 , 113
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
 // write_register- end
-// allocate_stack - current: 4 size: 1
+Set r246 , 50
+// write_register- self: Data { stack_frame_offset: 113, size: 8, data_type: Array { inside: Char, len: 7 } }
 Set r245 , 1
-Add r254 , r245
-// alloc_stack - end
-// handle number
-Set r244 , 0
-// allocate_stack - current: 5 size: 1
-Set r243 , 1
-Add r254 , r243
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
-Set r241 , 0
-Set r240 , 5
-Add r240 , r255
-Add r240 , r241
-Write r240 , r244
+Set r248 , 113
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
 // write_register- end
-// handle number end
-// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
-Set r241 , 0
-Set r242 , 5
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 113, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 2
+Set r244 , 113
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 113, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 3
+Set r248 , 113
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 113, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 4
+Set r244 , 113
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 113, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 5
+Set r248 , 113
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 113, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 6
+Set r244 , 113
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 113, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 7
+Set r248 , 113
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+// allocate_stack - current: 121 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 112, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 112
 Add r242 , r255
-Add r242 , r241
-Read r248 , r242
+Add r242 , r243
+Read r245 , r242
 // read_register- end
-// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+// read_addr_of_self
+Set r242 , 113
+Add r242 , r255
+// read_addr_of_self - end
+// read_addr_of_self
+Set r243 , 121
+Add r243 , r255
+// read_addr_of_self - end
+Syscall r245 , r242 , r243
+// write_register- self: Data { stack_frame_offset: 121, size: 1, data_type: U32 }
 Set r241 , 0
-Set r240 , 2
+Set r240 , 121
 Add r240 , r255
 Add r240 , r241
-Read r246 , r240
-// read_register- end
-Set r247 , 1
-Sub r248 , r247
-:for_start23
-Set r247 , 1
-Add r248 , r247
-Gte r248 , r246 , r247
-RSet r249 , :for_end24
-RJmpc r249 , r247
-// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
-Set r241 , 0
-Set r242 , 4
-Add r242 , r255
-Add r242 , r241
-Write r242 , r248
+Write r240 , r243
 // write_register- end
-// for loop contents:
-// index_array
-// index_array - var:Data { stack_frame_offset: -2, size: 1, data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 } }
-// allocate_stack - current: 6 size: 1
-Set r240 , 1
-Add r254 , r240
+
+// handle number
+Set r250 , 0
+// allocate_stack - current: 122 size: 1
+Set r249 , 1
+Add r254 , r249
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
-Set r237 , 0
-Set r236 , 4
-Add r236 , r255
-Add r236 , r237
-Read r239 , r236
-// read_register- end
-Set r237 , 4294967294
-// array indexing- var_to_index.is_reference: true
-Cp r238 , r239
-Set r236 , 1
-Mul r238 , r236
-// add 1 offset to account for 1'st register holding size of array
-Set r236 , 1
-Add r238 , r236
-// read_addr_of_register - self: Data { stack_frame_offset: -2, size: 1, data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 } }
-Set r236 , 4294967294
-Add r236 , r255
-Read r236 , r236
-Set r235 , 0
-Add r236 , r235
-Add r236 , r238
-// read_addr_of_register - end
-// index_array: read_addr_of_register - end addr_register:236 , offset_register:238
-// write_directly_to_reference_pointer - 235, input: r236
-Set r235 , 6
-Add r235 , r255
-Write r235 , r236
-// write_directly_to_reference_pointer - end
-// index_array - end
-Set r242 , 0
-// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r238 , 0
-Set r236 , 6
-Add r236 , r255
-Read r236 , r236
-Set r237 , 0
-Add r236 , r237
-Add r236 , r238
-Read r241 , r236
-// read_register- end
-Phrp r242 , r241
-
-// for loop contents - end
-RSet r249 , :for_start23
-RJmp r249
-:for_end24
-// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
-Set r242 , 0
-Set r236 , 3
-Add r236 , r255
-Add r236 , r242
-Read r254 , r236
-// read_register- end
-// allocate_stack - current: 4 size: 2
-Set r247 , 2
+// write_register- self: Data { stack_frame_offset: 122, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 122
+Add r246 , r255
+Add r246 , r247
+Write r246 , r250
+// write_register- end
+// handle number end
+// allocate_stack - current: 123 size: 8
+Set r247 , 8
 Add r254 , r247
 // alloc_stack - end
-Set r248 , 1
-// write_register- self: Data { stack_frame_offset: 4, size: 2, data_type: Array { inside: Char, len: 1 } }
+Set r246 , 7
+// write_register- self: Data { stack_frame_offset: 123, size: 8, data_type: Array { inside: Char, len: 7 } }
 Set r245 , 0
-Set r244 , 4
+Set r244 , 123
 Add r244 , r255
 Add r244 , r245
-Write r244 , r248
+Write r244 , r246
 // write_register- end
-Set r248 , 10
-// write_register- self: Data { stack_frame_offset: 4, size: 2, data_type: Array { inside: Char, len: 1 } }
+Set r246 , 50
+// write_register- self: Data { stack_frame_offset: 123, size: 8, data_type: Array { inside: Char, len: 7 } }
 Set r245 , 1
-Set r246 , 4
-Add r246 , r255
-Add r246 , r245
-Write r246 , r248
+Set r248 , 123
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
 // write_register- end
-Set r250 , 0
-// read_register- self: Data { stack_frame_offset: 4, size: 2, data_type: Array { inside: Char, len: 1 } }
-Set r245 , 0
-Set r244 , 4
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 123, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 2
+Set r244 , 123
 Add r244 , r255
 Add r244 , r245
-Read r249 , r244
-// read_register- end
-Phrp r250 , r249
-// read_register- self: Data { stack_frame_offset: 4, size: 2, data_type: Array { inside: Char, len: 1 } }
-Set r245 , 1
-Set r246 , 4
-Add r246 , r255
-Add r246 , r245
-Read r249 , r246
-// read_register- end
-Phrp r250 , r249
-
-// Return
-
-Cp r250 , r255
-Cp r254 , r255
-// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 1
-Add r246 , r255
-Add r246 , r247
-Read r249 , r246
-// read_register- end
-Cp r255 , r249
-Read r250 , r250
-Jmp r250
-// function: Function { is_interrupt_function: false, name: "panic", input: [FunctionInputData { name: "text", data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 }, stack_frame_offset: -2 }], output: None, label_name: "function_panic_2" }
-:function_panic_2
-Cp r250 , r255
-Cp r255 , r254
-// allocate_stack - current: 0 size: 1
-Set r249 , 1
-Add r254 , r249
-// alloc_stack - end
-// allocate_stack - current: 1 size: 1
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 123, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 3
+Set r248 , 123
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 123, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 4
+Set r244 , 123
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 123, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 5
+Set r248 , 123
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 123, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 6
+Set r244 , 123
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 123, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 7
+Set r248 , 123
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+// allocate_stack - current: 131 size: 1
 Set r248 , 1
 Add r254 , r248
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r246 , 0
-Set r245 , 1
-Add r245 , r255
-Add r245 , r246
-Write r245 , r250
+// read_register- self: Data { stack_frame_offset: 122, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 122
+Add r242 , r255
+Add r242 , r243
+Read r245 , r242
+// read_register- end
+// read_addr_of_self
+Set r242 , 123
+Add r242 , r255
+// read_addr_of_self - end
+// read_addr_of_self
+Set r243 , 131
+Add r243 , r255
+// read_addr_of_self - end
+Syscall r245 , r242 , r243
+// write_register- self: Data { stack_frame_offset: 131, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 131
+Add r240 , r255
+Add r240 , r241
+Write r240 , r243
 // write_register- end
-// call_function_code
-// function.input.is_empty(), 0, 3
-// call_function_code - needed_stack: 3
-// allocate_stack - current: 2 size: 3
-Set r246 , 3
-Add r254 , r246
-// alloc_stack - end
-// call_function_code - handle reference variable
-Set r247 , 4294967294
-Add r247 , r254
-// read_referenced_address
-Set r244 , 4294967294
-Add r244 , r255
-Read r244 , r244
-Set r242 , 0
-Add r244 , r242
-// read_referenced_address - end
-Write r247 , r244
-// call_function_code - handle reference variable, end
-RSet r244 , :function-return25
-Add r244 , r251
-Set r242 , 0
-Sub r244 , r242
-Write r254 , r244
-RSet r247 , :function_print_chars_1
-RJmp r247
-:function-return25
-// function call output: None
-// function call converted output data : None
 
-Halt
-
-// Return
-
-Cp r250 , r255
-Cp r254 , r255
-// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 1
-Add r246 , r255
-Add r246 , r247
-Read r249 , r246
-// read_register- end
-Cp r255 , r249
-Read r250 , r250
-Jmp r250
-// function: Function { is_interrupt_function: false, name: "core_deallocate", input: [FunctionInputData { name: "addr", data_type: U32, stack_frame_offset: -2 }, FunctionInputData { name: "size_raw", data_type: U32, stack_frame_offset: -3 }], output: None, label_name: "function_core_deallocate_3" }
-:function_core_deallocate_3
-Cp r250 , r255
-Cp r255 , r254
-// allocate_stack - current: 0 size: 1
+// handle number
+Set r250 , 0
+// allocate_stack - current: 132 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// allocate_stack - current: 1 size: 1
-Set r248 , 1
-Add r254 , r248
+// write_register- self: Data { stack_frame_offset: 132, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 132
+Add r246 , r255
+Add r246 , r247
+Write r246 , r250
+// write_register- end
+// handle number end
+// allocate_stack - current: 133 size: 8
+Set r247 , 8
+Add r254 , r247
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r246 , 0
+Set r246 , 7
+// write_register- self: Data { stack_frame_offset: 133, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 0
+Set r244 , 133
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 50
+// write_register- self: Data { stack_frame_offset: 133, size: 8, data_type: Array { inside: Char, len: 7 } }
 Set r245 , 1
-Add r245 , r255
-Add r245 , r246
-Write r245 , r250
+Set r248 , 133
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
 // write_register- end
-// assign_new_variable
-// call_function_code
-// call_function_code - needed_stack: 4
-// allocate_stack - current: 2 size: 4
-Set r246 , 4
-Add r254 , r246
-// alloc_stack - end
-// read_register- self: Data { stack_frame_offset: -3, size: 1, data_type: U32 }
-Set r241 , 0
-Set r240 , 4294967293
-Add r240 , r255
-Add r240 , r241
-Read r244 , r240
-// read_register- end
-Set r247 , 4294967294
-Add r247 , r254
-Write r247 , r244
-RSet r244 , :function-return26
-Add r244 , r251
-Set r240 , 0
-Sub r244 , r240
-Write r254 , r244
-RSet r247 , :function_ceil_log2_7
-RJmp r247
-:function-return26
-// function call output: Some(FunctionInputData { name: "ceil_log2", data_type: U32, stack_frame_offset: -3 })
-// function call converted output data : Some(Data { stack_frame_offset: 3, size: 1, data_type: U32 })
-// assign_new_variable - end
-
-// assign_new_variable
-// access_static_variable
-// allocate_stack - current: 6 size: 1
-Set r250 , 1
-Add r254 , r250
-// alloc_stack - end
-RSet r249 , :min_heap_size_pow_2
-Add r249 , r251
-Set r248 , 0
-Sub r249 , r248
-// write_directly_to_reference_pointer - 247, input: r249
-Set r247 , 6
-Add r247 , r255
-Write r247 , r249
-// write_directly_to_reference_pointer - end
-// access_static_variable-end
-// assign_new_variable - end
-
-// assign_new_variable
-// handle_binary_expr
-// call_function_code
-// call_function_code - needed_stack: 5
-// allocate_stack - current: 7 size: 5
-Set r249 , 5
-Add r254 , r249
-// alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r244 , 0
-Set r243 , 6
-Add r243 , r255
-Read r243 , r243
-Set r245 , 0
-Add r243 , r245
-Add r243 , r244
-Read r247 , r243
-// read_register- end
-Set r248 , 4294967294
-Add r248 , r254
-Write r248 , r247
-// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
-Set r244 , 0
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 133, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 2
+Set r244 , 133
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 133, size: 8, data_type: Array { inside: Char, len: 7 } }
 Set r245 , 3
-Add r245 , r255
-Add r245 , r244
-Read r247 , r245
-// read_register- end
-Set r248 , 4294967293
-Add r248 , r254
-Write r248 , r247
-RSet r247 , :function-return27
-Add r247 , r251
-Set r245 , 0
-Sub r247 , r245
-Write r254 , r247
-RSet r248 , :function_max_6
-RJmp r248
-:function-return27
-// function call output: Some(FunctionInputData { name: "max", data_type: U32, stack_frame_offset: -4 })
-// function call converted output data : Some(Data { stack_frame_offset: 8, size: 1, data_type: U32 })
-// allocate_stack - current: 12 size: 1
-Set r250 , 1
-Add r254 , r250
+Set r248 , 133
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 133, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 4
+Set r244 , 133
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 133, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 5
+Set r248 , 133
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 133, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 6
+Set r244 , 133
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 133, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 7
+Set r248 , 133
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+// allocate_stack - current: 141 size: 1
+Set r248 , 1
+Add r254 , r248
 // alloc_stack - end
-// handle_binary_expr - output_register- r246
-// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 132, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 8
+Set r242 , 132
 Add r242 , r255
 Add r242 , r243
-Read r247 , r242
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r243 , 0
-Set r244 , 6
-Add r244 , r255
-Read r244 , r244
-Set r242 , 0
-Add r244 , r242
-Add r244 , r243
-Read r248 , r244
+Read r245 , r242
 // read_register- end
-Sub r247 , r248
-Cp r246 , r247
-// write_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
-Set r243 , 0
-Set r242 , 12
+// read_addr_of_self
+Set r242 , 133
 Add r242 , r255
-Add r242 , r243
-Write r242 , r246
+// read_addr_of_self - end
+// read_addr_of_self
+Set r243 , 141
+Add r243 , r255
+// read_addr_of_self - end
+Syscall r245 , r242 , r243
+// write_register- self: Data { stack_frame_offset: 141, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 141
+Add r240 , r255
+Add r240 , r241
+Write r240 , r243
 // write_register- end
-// handle_binary_expr - end
-// assign_new_variable - end
 
-// assign_new_variable
-// handle_binary_expr
 // handle number
-Set r250 , 2
-// allocate_stack - current: 13 size: 1
+Set r250 , 0
+// allocate_stack - current: 142 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 142, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 13
+Set r246 , 142
 Add r246 , r255
 Add r246 , r247
 Write r246 , r250
 // write_register- end
 // handle number end
-// handle_binary_expr
-// handle_binary_expr
-// allocate_stack - current: 14 size: 1
+// allocate_stack - current: 143 size: 8
+Set r247 , 8
+Add r254 , r247
+// alloc_stack - end
+Set r246 , 7
+// write_register- self: Data { stack_frame_offset: 143, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 0
+Set r244 , 143
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 50
+// write_register- self: Data { stack_frame_offset: 143, size: 8, data_type: Array { inside: Char, len: 7 } }
 Set r245 , 1
-Add r254 , r245
+Set r248 , 143
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 143, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 2
+Set r244 , 143
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 143, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 3
+Set r248 , 143
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 143, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 4
+Set r244 , 143
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 143, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 5
+Set r248 , 143
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 143, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 6
+Set r244 , 143
+Add r244 , r255
+Add r244 , r245
+Write r244 , r246
+// write_register- end
+Set r246 , 53
+// write_register- self: Data { stack_frame_offset: 143, size: 8, data_type: Array { inside: Char, len: 7 } }
+Set r245 , 7
+Set r248 , 143
+Add r248 , r255
+Add r248 , r245
+Write r248 , r246
+// write_register- end
+// allocate_stack - current: 151 size: 1
+Set r248 , 1
+Add r254 , r248
 // alloc_stack - end
-// handle_binary_expr - output_register- r246
-// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 142, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 12
+Set r242 , 142
 Add r242 , r255
 Add r242 , r243
-Read r247 , r242
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r243 , 0
-Set r244 , 6
-Add r244 , r255
-Read r244 , r244
-Set r242 , 0
-Add r244 , r242
-Add r244 , r243
-Read r248 , r244
+Read r245 , r242
 // read_register- end
-Add r247 , r248
-Cp r246 , r247
-// write_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
-Set r243 , 0
-Set r242 , 14
+// read_addr_of_self
+Set r242 , 143
 Add r242 , r255
-Add r242 , r243
-Write r242 , r246
-// write_register- end
-// handle_binary_expr - end
-// handle number
-Set r246 , 1
-// allocate_stack - current: 15 size: 1
-Set r248 , 1
-Add r254 , r248
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 15, size: 1, data_type: U32 }
-Set r242 , 0
-Set r243 , 15
+// read_addr_of_self - end
+// read_addr_of_self
+Set r243 , 151
 Add r243 , r255
-Add r243 , r242
-Write r243 , r246
+// read_addr_of_self - end
+Syscall r245 , r242 , r243
+// write_register- self: Data { stack_frame_offset: 151, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 151
+Add r240 , r255
+Add r240 , r241
+Write r240 , r243
 // write_register- end
-// handle number end
-// allocate_stack - current: 16 size: 1
-Set r244 , 1
-Add r254 , r244
-// alloc_stack - end
-// handle_binary_expr - output_register- r243
-// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
-Set r240 , 0
-Set r239 , 14
-Add r239 , r255
-Add r239 , r240
-Read r242 , r239
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 15, size: 1, data_type: U32 }
-Set r240 , 0
-Set r241 , 15
-Add r241 , r255
-Add r241 , r240
-Read r247 , r241
-// read_register- end
-Sub r242 , r247
-Cp r243 , r242
-// write_register- self: Data { stack_frame_offset: 16, size: 1, data_type: U32 }
-Set r240 , 0
-Set r239 , 16
-Add r239 , r255
-Add r239 , r240
-Write r239 , r243
-// write_register- end
-// handle_binary_expr - end
-// allocate_stack - current: 17 size: 1
-Set r239 , 1
-Add r254 , r239
-// alloc_stack - end
-// handle_binary_expr - output_register- r243
-// read_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
-Set r241 , 0
-Set r238 , 13
-Add r238 , r255
-Add r238 , r241
-Read r247 , r238
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 16, size: 1, data_type: U32 }
-Set r241 , 0
-Set r240 , 16
-Add r240 , r255
-Add r240 , r241
-Read r242 , r240
-// read_register- end
-Shl r247 , r242
-Cp r243 , r247
-// write_register- self: Data { stack_frame_offset: 17, size: 1, data_type: U32 }
-Set r241 , 0
-Set r238 , 17
-Add r238 , r255
-Add r238 , r241
-Write r238 , r243
-// write_register- end
-// handle_binary_expr - end
-// assign_new_variable - end
 
-// assign_new_variable
 // access_static_variable
-// allocate_stack - current: 18 size: 1
+// allocate_stack - current: 152 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
-RSet r249 , :free_heap_blocks_linked_lists
+RSet r249 , :command_line_addr
 Add r249 , r251
 Set r248 , 0
 Sub r249 , r248
 // write_directly_to_reference_pointer - 247, input: r249
-Set r247 , 18
+Set r247 , 152
 Add r247 , r255
 Write r247 , r249
 // write_directly_to_reference_pointer - end
 // access_static_variable-end
-// index_array
-// index_array - var:Data { stack_frame_offset: 18, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-// allocate_stack - current: 19 size: 1
+// allocate_stack - current: 153 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 152, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r245 , 0
-Set r244 , 12
+Set r244 , 152
 Add r244 , r255
+Read r244 , r244
+Set r246 , 0
+Add r244 , r246
 Add r244 , r245
 Read r247 , r244
 // read_register- end
-Set r245 , 18
-// array indexing- var_to_index.is_reference: true
-Cp r246 , r247
-Set r244 , 1
-Mul r246 , r244
-// add 1 offset to account for 1'st register holding size of array
-Set r244 , 1
-Add r246 , r244
-// read_addr_of_register - self: Data { stack_frame_offset: 18, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-Set r244 , 18
+// write_register- self: Data { stack_frame_offset: 153, size: 1, data_type: U32 }
+Set r245 , 0
+Set r246 , 153
+Add r246 , r255
+Add r246 , r245
+Write r246 , r247
+// write_register- end
+// read_register- self: Data { stack_frame_offset: 153, size: 1, data_type: U32 }
+Set r245 , 0
+Set r244 , 153
 Add r244 , r255
-Read r244 , r244
-Set r243 , 0
-Add r244 , r243
-Add r244 , r246
-// read_addr_of_register - end
-// index_array: read_addr_of_register - end addr_register:244 , offset_register:246
-// write_directly_to_reference_pointer - 243, input: r244
-Set r243 , 19
-Add r243 , r255
-Write r243 , r244
-// write_directly_to_reference_pointer - end
-// index_array - end
-// assign_new_variable - end
-
-// assign_new_variable
-// direct_reference_access
-// allocate_stack - current: 20 size: 1
-Set r250 , 1
-Add r254 , r250
-// alloc_stack - end
-// direct_reference_access - handle_inside_expr
-// direct_reference_access - handle_inside_expr- end
-// read_addr_of_last_reference_in_chain -output_register - r249
-Set r249 , 19
-Add r249 , r255
-Read r249 , r249
-Set r248 , 0
-Add r249 , r248
-// read_addr_of_last_reference_in_chain 
-// write_directly_to_reference_pointer - 248, input: r249
-Set r248 , 20
-Add r248 , r255
-Write r248 , r249
-// write_directly_to_reference_pointer - end
-// direct_reference_accesss - end
-// allocate_stack - current: 21 size: 1
-Set r249 , 1
-Add r254 , r249
-// alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r246 , 0
-Set r245 , 20
-Add r245 , r255
-Read r245 , r245
-Set r247 , 0
-Add r245 , r247
-Add r245 , r246
-Read r248 , r245
+Add r244 , r245
+Read r247 , r244
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 21, size: 1, data_type: U32 }
-Set r246 , 0
-Set r247 , 21
-Add r247 , r255
-Add r247 , r246
-Write r247 , r248
-// write_register- end
-// assign_new_variable - end
+Jmp r247
 
-// assign_new_variable
-// handle_struct_initialization Struct {
- //    size: 1,
- //    name: "HeapBlock",
- //    properties: {
- //        "next": StructProperty {
- //            data_type: Reference {
- //                inside: Struct {
- //                    name: "HeapBlock",
- //                },
- //                offset_of_data_from_reference_addr: 0,
- //            },
- //            offset_from_struct_base: 0,
- //        },
- //    },
- //    contents_parsed: Done,
- //}
-// allocate_stack - current: 22 size: 1
+// allocate_stack - current: 154 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
-// assignment
-// assignment data: Data { stack_frame_offset: 21, size: 1, data_type: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 } }
-// assignment- variable.is_reference: true data.is_reference: true 
-// read_referenced_address
-Set r249 , 21
-Add r249 , r255
-Read r249 , r249
+// write_register- self: Data { stack_frame_offset: 154, size: 1, data_type: U32 }
 Set r248 , 0
-Add r249 , r248
-// read_referenced_address - end
-Set r247 , 22
-Add r247 , r255
-Write r247 , r249
-// end assignment
-// handle_struct_initialization end
-// assign_new_variable - end
-
-// assignment
-// memory_access
-// allocate_stack - current: 23 size: 1
-Set r250 , 1
-Add r254 , r250
-// alloc_stack - end
-// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 4294967294
-Add r246 , r255
-Add r246 , r247
-Read r249 , r246
-// read_register- end
-// write_directly_to_reference_pointer - 247, input: r249
-Set r247 , 23
+Set r247 , 154
 Add r247 , r255
-Write r247 , r249
-// write_directly_to_reference_pointer - end
-// memory_access- end
-// assignment data: Data { stack_frame_offset: 21, size: 1, data_type: U32 }
-// assignment- variable.is_reference: true data.is_reference: false 
-// read_register- self: Data { stack_frame_offset: 21, size: 1, data_type: U32 }
-Set r247 , 0
-Set r248 , 21
-Add r248 , r255
-Add r248 , r247
-Read r246 , r248
-// read_register- end
-// write_register- self: Data { stack_frame_offset: 23, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r247 , 0
-Set r249 , 23
-Add r249 , r255
-Read r249 , r249
-Set r248 , 0
-Add r249 , r248
-Add r249 , r247
-Write r249 , r246
+Add r247 , r248
+Write r247 , r254
 // write_register- end
-// end assignment
-
-// assignment
-// direct_reference_access
-// allocate_stack - current: 24 size: 1
-Set r250 , 1
-Add r254 , r250
-// alloc_stack - end
-// direct_reference_access - handle_inside_expr
-// access_static_variable
-// allocate_stack - current: 25 size: 1
+:while_start30
 Set r249 , 1
-Add r254 , r249
-// alloc_stack - end
-RSet r248 , :free_heap_blocks_linked_lists
-Add r248 , r251
-Set r247 , 0
-Sub r248 , r247
-// write_directly_to_reference_pointer - 246, input: r248
-Set r246 , 25
-Add r246 , r255
-Write r246 , r248
-// write_directly_to_reference_pointer - end
-// access_static_variable-end
-// index_array
-// index_array - var:Data { stack_frame_offset: 25, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-// allocate_stack - current: 26 size: 1
-Set r248 , 1
-Add r254 , r248
+// allocate_stack - current: 155 size: 1
+Set r246 , 1
+Add r254 , r246
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 155, size: 1, data_type: Bool }
 Set r244 , 0
-Set r243 , 12
+Set r243 , 155
 Add r243 , r255
 Add r243 , r244
-Read r246 , r243
+Write r243 , r249
+// write_register- end
+// read_register- self: Data { stack_frame_offset: 155, size: 1, data_type: Bool }
+Set r244 , 0
+Set r245 , 155
+Add r245 , r255
+Add r245 , r244
+Read r247 , r245
 // read_register- end
-Set r244 , 25
-// array indexing- var_to_index.is_reference: true
-Cp r245 , r246
-Set r243 , 1
-Mul r245 , r243
-// add 1 offset to account for 1'st register holding size of array
-Set r243 , 1
-Add r245 , r243
-// read_addr_of_register - self: Data { stack_frame_offset: 25, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-Set r243 , 25
-Add r243 , r255
-Read r243 , r243
-Set r242 , 0
-Add r243 , r242
-Add r243 , r245
-// read_addr_of_register - end
-// index_array: read_addr_of_register - end addr_register:243 , offset_register:245
-// write_directly_to_reference_pointer - 242, input: r243
-Set r242 , 26
-Add r242 , r255
-Write r242 , r243
-// write_directly_to_reference_pointer - end
-// index_array - end
-// direct_reference_access - handle_inside_expr- end
-// read_addr_of_last_reference_in_chain -output_register - r244
-Set r244 , 26
-Add r244 , r255
-Read r244 , r244
+Not r247
+RSet r248 , :while_end31
+RJmpc r248 , r247
+// while loop contents:
+Halt
+
+// handle number
 Set r245 , 0
-Add r244 , r245
-// read_addr_of_last_reference_in_chain 
-// write_directly_to_reference_pointer - 245, input: r244
-Set r245 , 24
-Add r245 , r255
-Write r245 , r244
-// write_directly_to_reference_pointer - end
-// direct_reference_accesss - end
-// assignment data: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
-// assignment- variable.is_reference: true data.is_reference: false 
-// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
-Set r243 , 0
-Set r246 , 4294967294
-Add r246 , r255
-Add r246 , r243
-Read r244 , r246
+// allocate_stack - current: 156 size: 1
+Set r244 , 1
+Add r254 , r244
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 156, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 156
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
+// write_register- end
+// handle number end
+// allocate_stack - current: 157 size: 6
+Set r242 , 6
+Add r254 , r242
+// alloc_stack - end
+Set r241 , 5
+// write_register- self: Data { stack_frame_offset: 157, size: 6, data_type: Array { inside: Char, len: 5 } }
+Set r240 , 0
+Set r239 , 157
+Add r239 , r255
+Add r239 , r240
+Write r239 , r241
+// write_register- end
+Set r241 , 67
+// write_register- self: Data { stack_frame_offset: 157, size: 6, data_type: Array { inside: Char, len: 5 } }
+Set r240 , 1
+Set r243 , 157
+Add r243 , r255
+Add r243 , r240
+Write r243 , r241
+// write_register- end
+Set r241 , 108
+// write_register- self: Data { stack_frame_offset: 157, size: 6, data_type: Array { inside: Char, len: 5 } }
+Set r240 , 2
+Set r239 , 157
+Add r239 , r255
+Add r239 , r240
+Write r239 , r241
+// write_register- end
+Set r241 , 111
+// write_register- self: Data { stack_frame_offset: 157, size: 6, data_type: Array { inside: Char, len: 5 } }
+Set r240 , 3
+Set r243 , 157
+Add r243 , r255
+Add r243 , r240
+Write r243 , r241
+// write_register- end
+Set r241 , 99
+// write_register- self: Data { stack_frame_offset: 157, size: 6, data_type: Array { inside: Char, len: 5 } }
+Set r240 , 4
+Set r239 , 157
+Add r239 , r255
+Add r239 , r240
+Write r239 , r241
+// write_register- end
+Set r241 , 107
+// write_register- self: Data { stack_frame_offset: 157, size: 6, data_type: Array { inside: Char, len: 5 } }
+Set r240 , 5
+Set r243 , 157
+Add r243 , r255
+Add r243 , r240
+Write r243 , r241
+// write_register- end
+// allocate_stack - current: 163 size: 1
+Set r243 , 1
+Add r254 , r243
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 156, size: 1, data_type: U32 }
+Set r238 , 0
+Set r237 , 156
+Add r237 , r255
+Add r237 , r238
+Read r240 , r237
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 24, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r243 , 0
-Set r245 , 24
-Add r245 , r255
-Read r245 , r245
-Set r246 , 0
-Add r245 , r246
-Add r245 , r243
-Write r245 , r244
+// read_addr_of_self
+Set r237 , 157
+Add r237 , r255
+// read_addr_of_self - end
+// read_addr_of_self
+Set r238 , 163
+Add r238 , r255
+// read_addr_of_self - end
+Syscall r240 , r237 , r238
+// write_register- self: Data { stack_frame_offset: 163, size: 1, data_type: U32 }
+Set r236 , 0
+Set r235 , 163
+Add r235 , r255
+Add r235 , r236
+Write r235 , r238
 // write_register- end
-// end assignment
 
-// Return
-
-Cp r250 , r255
-Cp r254 , r255
-// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 1
-Add r246 , r255
-Add r246 , r247
-Read r249 , r246
+// while loop contents - end
+RSet r248 , :while_start30
+RJmp r248
+:while_end31
+// read_register- self: Data { stack_frame_offset: 154, size: 1, data_type: U32 }
+Set r240 , 0
+Set r238 , 154
+Add r238 , r255
+Add r238 , r240
+Read r254 , r238
 // read_register- end
-Cp r255 , r249
-Read r250 , r250
-Jmp r250
-// function: Function { is_interrupt_function: false, name: "core_allocate", input: [FunctionInputData { name: "size", data_type: U32, stack_frame_offset: -2 }], output: Some(FunctionInputData { name: "core_allocate", data_type: U32, stack_frame_offset: -3 }), label_name: "function_core_allocate_4" }
-:function_core_allocate_4
+// function: Function { is_interrupt_function: false, name: "print_chars", input: [FunctionInputData { name: "text", data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 }, stack_frame_offset: -2 }], output: None, label_name: "function_print_chars_1" }
+:function_print_chars_1
 Cp r250 , r255
 Cp r255 , r254
 // allocate_stack - current: 0 size: 1
@@ -2900,343 +3072,435 @@ Add r245 , r246
 Write r245 , r250
 // write_register- end
 // assign_new_variable
-// call_function_code
-// call_function_code - needed_stack: 4
-// allocate_stack - current: 2 size: 4
-Set r246 , 4
-Add r254 , r246
+// allocate_stack - current: 2 size: 1
+Set r245 , 1
+Add r254 , r245
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
-Set r241 , 0
-Set r240 , 4294967294
-Add r240 , r255
-Add r240 , r241
-Read r244 , r240
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r243 , 4294967294
+Add r243 , r255
+Read r243 , r243
+Set r247 , 0
+Add r243 , r247
+Add r243 , r244
+Read r246 , r243
 // read_register- end
-Set r247 , 4294967294
-Add r247 , r254
-Write r247 , r244
-RSet r244 , :function-return28
-Add r244 , r251
-Set r240 , 0
-Sub r244 , r240
-Write r254 , r244
-RSet r247 , :function_ceil_log2_7
-RJmp r247
-:function-return28
-// function call output: Some(FunctionInputData { name: "ceil_log2", data_type: U32, stack_frame_offset: -3 })
-// function call converted output data : Some(Data { stack_frame_offset: 3, size: 1, data_type: U32 })
-// assign_new_variable - end
-
-// assign_new_variable
-// access_static_variable
-// allocate_stack - current: 6 size: 1
-Set r250 , 1
-Add r254 , r250
-// alloc_stack - end
-RSet r249 , :min_heap_size_pow_2
-Add r249 , r251
-Set r248 , 0
-Sub r249 , r248
-// write_directly_to_reference_pointer - 247, input: r249
-Set r247 , 6
-Add r247 , r255
-Write r247 , r249
-// write_directly_to_reference_pointer - end
-// access_static_variable-end
-// assign_new_variable - end
-
-// assign_new_variable
-// access_static_variable
-// allocate_stack - current: 7 size: 1
-Set r250 , 1
-Add r254 , r250
-// alloc_stack - end
-RSet r249 , :max_heap_size_pow_2
-Add r249 , r251
-Set r248 , 0
-Sub r249 , r248
-// write_directly_to_reference_pointer - 247, input: r249
-Set r247 , 7
+// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r244 , 0
+Set r247 , 2
 Add r247 , r255
-Write r247 , r249
-// write_directly_to_reference_pointer - end
-// access_static_variable-end
+Add r247 , r244
+Write r247 , r246
+// write_register- end
 // assign_new_variable - end
 
-// if- condition: Binary { left: Identifier("size_log2", DebugData { line: 43, file: "allocator.tip" }), operator: Token { kind: Greater, value: ">", line: 43 }, right: Identifier("max", DebugData { line: 43, file: "allocator.tip" }), debug_data: DebugData { line: 43, file: "allocator.tip" } }
-// allocate_stack - current: 8 size: 1
+// allocate_stack - current: 3 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
 Set r248 , 0
-Set r247 , 8
+Set r247 , 3
 Add r247 , r255
 Add r247 , r248
 Write r247 , r254
 // write_register- end
-// if condition data
-// handle_binary_expr
-// allocate_stack - current: 9 size: 1
-Set r246 , 1
-Add r254 , r246
+// allocate_stack - current: 4 size: 1
+Set r245 , 1
+Add r254 , r245
 // alloc_stack - end
-// handle_binary_expr - output_register- r247
-// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
-Set r244 , 0
-Set r243 , 3
-Add r243 , r255
-Add r243 , r244
-Read r248 , r243
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r244 , 0
-Set r245 , 7
-Add r245 , r255
-Read r245 , r245
-Set r243 , 0
-Add r245 , r243
-Add r245 , r244
-Read r249 , r245
-// read_register- end
-Gt r248 , r249 , r247
-// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: Bool }
+// handle number
 Set r244 , 0
-Set r243 , 9
-Add r243 , r255
-Add r243 , r244
-Write r243 , r247
-// write_register- end
-// handle_binary_expr - end
-// read condition
-// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: Bool }
-Set r248 , 0
-Set r243 , 9
-Add r243 , r255
-Add r243 , r248
-Read r247 , r243
-// read_register- end
-Not r247
-RSet r243 , :if30
-RJmpc r243 , r247
-// if contents
-// allocate_stack - current: 10 size: 21
-Set r249 , 21
-Add r254 , r249
+// allocate_stack - current: 5 size: 1
+Set r243 , 1
+Add r254 , r243
 // alloc_stack - end
-Set r248 , 20
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 0
-Set r242 , 10
-Add r242 , r255
-Add r242 , r245
-Write r242 , r248
-// write_register- end
-Set r248 , 97
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 1
-Set r244 , 10
-Add r244 , r255
-Add r244 , r245
-Write r244 , r248
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 5
+Add r240 , r255
+Add r240 , r241
+Write r240 , r244
 // write_register- end
-Set r248 , 108
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 2
-Set r242 , 10
+// handle number end
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r241 , 0
+Set r242 , 5
 Add r242 , r255
-Add r242 , r245
-Write r242 , r248
-// write_register- end
-Set r248 , 108
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 3
-Set r244 , 10
-Add r244 , r255
-Add r244 , r245
-Write r244 , r248
-// write_register- end
-Set r248 , 111
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 4
-Set r242 , 10
+Add r242 , r241
+Read r248 , r242
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 2
+Add r240 , r255
+Add r240 , r241
+Read r246 , r240
+// read_register- end
+Set r247 , 1
+Sub r248 , r247
+:for_start32
+Set r247 , 1
+Add r248 , r247
+Gte r248 , r246 , r247
+RSet r249 , :for_end33
+RJmpc r249 , r247
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r241 , 0
+Set r242 , 4
 Add r242 , r255
-Add r242 , r245
+Add r242 , r241
 Write r242 , r248
 // write_register- end
-Set r248 , 99
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 5
-Set r244 , 10
+// for loop contents:
+// index_array
+// index_array - var:Data { stack_frame_offset: -2, size: 1, data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 } }
+// allocate_stack - current: 6 size: 1
+Set r240 , 1
+Add r254 , r240
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r237 , 0
+Set r236 , 4
+Add r236 , r255
+Add r236 , r237
+Read r239 , r236
+// read_register- end
+Set r237 , 4294967294
+// array indexing- var_to_index.is_reference: true
+Cp r238 , r239
+Set r236 , 1
+Mul r238 , r236
+// add 1 offset to account for 1'st register holding size of array
+Set r236 , 1
+Add r238 , r236
+// read_addr_of_register - self: Data { stack_frame_offset: -2, size: 1, data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 } }
+Set r236 , 4294967294
+Add r236 , r255
+Read r236 , r236
+Set r235 , 0
+Add r236 , r235
+Add r236 , r238
+// read_addr_of_register - end
+// index_array: read_addr_of_register - end addr_register:236 , offset_register:238
+// write_directly_to_reference_pointer - 235, input: r236
+Set r235 , 6
+Add r235 , r255
+Write r235 , r236
+// write_directly_to_reference_pointer - end
+// index_array - end
+Set r242 , 0
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r238 , 0
+Set r236 , 6
+Add r236 , r255
+Read r236 , r236
+Set r237 , 0
+Add r236 , r237
+Add r236 , r238
+Read r241 , r236
+// read_register- end
+Phrp r242 , r241
+
+// handle number
+Set r241 , 3
+// allocate_stack - current: 7 size: 1
+Set r242 , 1
+Add r254 , r242
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r238 , 0
+Set r237 , 7
+Add r237 , r255
+Add r237 , r238
+Write r237 , r241
+// write_register- end
+// handle number end
+// index_array
+// index_array - var:Data { stack_frame_offset: -2, size: 1, data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 } }
+// allocate_stack - current: 8 size: 1
+Set r237 , 1
+Add r254 , r237
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r239 , 0
+Set r235 , 4
+Add r235 , r255
+Add r235 , r239
+Read r238 , r235
+// read_register- end
+Set r239 , 4294967294
+// array indexing- var_to_index.is_reference: true
+Cp r236 , r238
+Set r235 , 1
+Mul r236 , r235
+// add 1 offset to account for 1'st register holding size of array
+Set r235 , 1
+Add r236 , r235
+// read_addr_of_register - self: Data { stack_frame_offset: -2, size: 1, data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 } }
+Set r235 , 4294967294
+Add r235 , r255
+Read r235 , r235
+Set r234 , 0
+Add r235 , r234
+Add r235 , r236
+// read_addr_of_register - end
+// index_array: read_addr_of_register - end addr_register:235 , offset_register:236
+// write_directly_to_reference_pointer - 234, input: r235
+Set r234 , 8
+Add r234 , r255
+Write r234 , r235
+// write_directly_to_reference_pointer - end
+// index_array - end
+// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r238 , 0
+Set r234 , 7
+Add r234 , r255
+Add r234 , r238
+Read r239 , r234
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r238 , 0
+Set r235 , 8
+Add r235 , r255
+Read r235 , r235
+Set r234 , 0
+Add r235 , r234
+Add r235 , r238
+Read r236 , r235
+// read_register- end
+Phrp r239 , r236
+
+// for loop contents - end
+RSet r249 , :for_start32
+RJmp r249
+:for_end33
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r238 , 0
+Set r234 , 3
+Add r234 , r255
+Add r234 , r238
+Read r254 , r234
+// read_register- end
+// handle number
+Set r248 , 10
+// allocate_stack - current: 4 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r245 , 0
+Set r244 , 4
 Add r244 , r255
 Add r244 , r245
 Write r244 , r248
 // write_register- end
-Set r248 , 32
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 6
-Set r242 , 10
-Add r242 , r255
-Add r242 , r245
-Write r242 , r248
+// handle number end
+Set r250 , 0
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r245 , 0
+Set r246 , 4
+Add r246 , r255
+Add r246 , r245
+Read r249 , r246
+// read_register- end
+Phrp r250 , r249
+
+// handle number
+Set r250 , 3
+// allocate_stack - current: 5 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 5
+Add r246 , r255
+Add r246 , r247
+Write r246 , r250
 // write_register- end
-Set r248 , 108
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 7
-Set r244 , 10
+// handle number end
+// handle number
+Set r246 , 10
+// allocate_stack - current: 6 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r245 , 0
+Set r244 , 6
 Add r244 , r255
 Add r244 , r245
-Write r244 , r248
+Write r244 , r246
 // write_register- end
-Set r248 , 111
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 8
-Set r242 , 10
+// handle number end
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 5
 Add r242 , r255
-Add r242 , r245
-Write r242 , r248
-// write_register- end
-Set r248 , 110
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 9
-Set r244 , 10
-Add r244 , r255
-Add r244 , r245
-Write r244 , r248
-// write_register- end
-Set r248 , 103
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 10
-Set r242 , 10
-Add r242 , r255
-Add r242 , r245
-Write r242 , r248
-// write_register- end
-Set r248 , 32
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 11
-Set r244 , 10
-Add r244 , r255
-Add r244 , r245
-Write r244 , r248
-// write_register- end
-Set r248 , 108
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 12
-Set r242 , 10
-Add r242 , r255
-Add r242 , r245
-Write r242 , r248
-// write_register- end
-Set r248 , 111
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 13
-Set r244 , 10
-Add r244 , r255
-Add r244 , r245
-Write r244 , r248
-// write_register- end
-Set r248 , 110
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 14
-Set r242 , 10
-Add r242 , r255
-Add r242 , r245
-Write r242 , r248
-// write_register- end
-Set r248 , 103
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 15
-Set r244 , 10
-Add r244 , r255
-Add r244 , r245
-Write r244 , r248
-// write_register- end
-Set r248 , 32
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 16
-Set r242 , 10
-Add r242 , r255
-Add r242 , r245
-Write r242 , r248
-// write_register- end
-Set r248 , 108
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 17
-Set r244 , 10
-Add r244 , r255
-Add r244 , r245
-Write r244 , r248
-// write_register- end
-Set r248 , 111
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 18
-Set r242 , 10
-Add r242 , r255
-Add r242 , r245
-Write r242 , r248
-// write_register- end
-Set r248 , 110
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 19
-Set r244 , 10
-Add r244 , r255
-Add r244 , r245
-Write r244 , r248
-// write_register- end
-Set r248 , 103
-// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
-Set r245 , 20
-Set r242 , 10
-Add r242 , r255
-Add r242 , r245
-Write r242 , r248
+Add r242 , r243
+Read r244 , r242
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r243 , 0
+Set r248 , 6
+Add r248 , r255
+Add r248 , r243
+Read r245 , r248
+// read_register- end
+Phrp r244 , r245
+
+// Return
+
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Jmp r250
+// function: Function { is_interrupt_function: false, name: "panic", input: [FunctionInputData { name: "text", data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 }, stack_frame_offset: -2 }], output: None, label_name: "function_panic_2" }
+:function_panic_2
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
 // write_register- end
 // call_function_code
 // function.input.is_empty(), 0, 3
 // call_function_code - needed_stack: 3
-// allocate_stack - current: 31 size: 3
-Set r245 , 3
-Add r254 , r245
+// allocate_stack - current: 2 size: 3
+Set r246 , 3
+Add r254 , r246
 // alloc_stack - end
 // call_function_code - handle reference variable
+Set r247 , 4294967294
+Add r247 , r254
+// read_referenced_address
 Set r244 , 4294967294
-Add r244 , r254
-// read_addr_of_self
-Set r241 , 10
-Add r241 , r255
-// read_addr_of_self - end
-Write r244 , r241
+Add r244 , r255
+Read r244 , r244
+Set r242 , 0
+Add r244 , r242
+// read_referenced_address - end
+Write r247 , r244
 // call_function_code - handle reference variable, end
-RSet r241 , :function-return31
-Add r241 , r251
-Set r239 , 0
-Sub r241 , r239
-Write r254 , r241
-RSet r244 , :function_panic_2
-RJmp r244
-:function-return31
+RSet r244 , :function-return34
+Add r244 , r251
+Set r242 , 0
+Sub r244 , r242
+Write r254 , r244
+RSet r247 , :function_print_chars_1
+RJmp r247
+:function-return34
 // function call output: None
 // function call converted output data : None
 
-RSet r243 , :if_chain_end29
-RJmp r243
-// if contents end
-:if30
-// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+Halt
+
+// Return
+
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Jmp r250
+// function: Function { is_interrupt_function: false, name: "core_deallocate", input: [FunctionInputData { name: "addr", data_type: U32, stack_frame_offset: -2 }, FunctionInputData { name: "size_raw", data_type: U32, stack_frame_offset: -3 }], output: None, label_name: "function_core_deallocate_3" }
+:function_core_deallocate_3
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// assign_new_variable
+// call_function_code
+// call_function_code - needed_stack: 4
+// allocate_stack - current: 2 size: 4
+Set r246 , 4
+Add r254 , r246
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: -3, size: 1, data_type: U32 }
 Set r241 , 0
-Set r244 , 8
-Add r244 , r255
-Add r244 , r241
-Read r254 , r244
+Set r240 , 4294967293
+Add r240 , r255
+Add r240 , r241
+Read r244 , r240
 // read_register- end
-:if_chain_end29
+Set r247 , 4294967294
+Add r247 , r254
+Write r247 , r244
+RSet r244 , :function-return35
+Add r244 , r251
+Set r240 , 0
+Sub r244 , r240
+Write r254 , r244
+RSet r247 , :function_ceil_log2_7
+RJmp r247
+:function-return35
+// function call output: Some(FunctionInputData { name: "ceil_log2", data_type: U32, stack_frame_offset: -3 })
+// function call converted output data : Some(Data { stack_frame_offset: 3, size: 1, data_type: U32 })
+// assign_new_variable - end
+
+// assign_new_variable
+// access_static_variable
+// allocate_stack - current: 6 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+RSet r249 , :min_heap_size_pow_2
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 247, input: r249
+Set r247 , 6
+Add r247 , r255
+Write r247 , r249
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// assign_new_variable - end
+
 // assign_new_variable
 // handle_binary_expr
 // call_function_code
 // call_function_code - needed_stack: 5
-// allocate_stack - current: 9 size: 5
+// allocate_stack - current: 7 size: 5
 Set r249 , 5
 Add r254 , r249
 // alloc_stack - end
@@ -3263,24 +3527,24 @@ Read r247 , r245
 Set r248 , 4294967293
 Add r248 , r254
 Write r248 , r247
-RSet r247 , :function-return32
+RSet r247 , :function-return36
 Add r247 , r251
 Set r245 , 0
 Sub r247 , r245
 Write r254 , r247
 RSet r248 , :function_max_6
 RJmp r248
-:function-return32
+:function-return36
 // function call output: Some(FunctionInputData { name: "max", data_type: U32, stack_frame_offset: -4 })
-// function call converted output data : Some(Data { stack_frame_offset: 10, size: 1, data_type: U32 })
-// allocate_stack - current: 14 size: 1
+// function call converted output data : Some(Data { stack_frame_offset: 8, size: 1, data_type: U32 })
+// allocate_stack - current: 12 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
 // handle_binary_expr - output_register- r246
-// read_register- self: Data { stack_frame_offset: 10, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 10
+Set r242 , 8
 Add r242 , r255
 Add r242 , r243
 Read r247 , r242
@@ -3297,6 +3561,58 @@ Read r248 , r244
 // read_register- end
 Sub r247 , r248
 Cp r246 , r247
+// write_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 12
+Add r242 , r255
+Add r242 , r243
+Write r242 , r246
+// write_register- end
+// handle_binary_expr - end
+// assign_new_variable - end
+
+// assign_new_variable
+// handle_binary_expr
+// handle number
+Set r250 , 2
+// allocate_stack - current: 13 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 13
+Add r246 , r255
+Add r246 , r247
+Write r246 , r250
+// write_register- end
+// handle number end
+// handle_binary_expr
+// handle_binary_expr
+// allocate_stack - current: 14 size: 1
+Set r245 , 1
+Add r254 , r245
+// alloc_stack - end
+// handle_binary_expr - output_register- r246
+// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 12
+Add r242 , r255
+Add r242 , r243
+Read r247 , r242
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r243 , 0
+Set r244 , 6
+Add r244 , r255
+Read r244 , r244
+Set r242 , 0
+Add r244 , r242
+Add r244 , r243
+Read r248 , r244
+// read_register- end
+Add r247 , r248
+Cp r246 , r247
 // write_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
 Set r243 , 0
 Set r242 , 14
@@ -3305,11 +3621,83 @@ Add r242 , r243
 Write r242 , r246
 // write_register- end
 // handle_binary_expr - end
+// handle number
+Set r246 , 1
+// allocate_stack - current: 15 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 15, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 15
+Add r243 , r255
+Add r243 , r242
+Write r243 , r246
+// write_register- end
+// handle number end
+// allocate_stack - current: 16 size: 1
+Set r244 , 1
+Add r254 , r244
+// alloc_stack - end
+// handle_binary_expr - output_register- r243
+// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
+Set r240 , 0
+Set r239 , 14
+Add r239 , r255
+Add r239 , r240
+Read r242 , r239
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 15, size: 1, data_type: U32 }
+Set r240 , 0
+Set r241 , 15
+Add r241 , r255
+Add r241 , r240
+Read r247 , r241
+// read_register- end
+Sub r242 , r247
+Cp r243 , r242
+// write_register- self: Data { stack_frame_offset: 16, size: 1, data_type: U32 }
+Set r240 , 0
+Set r239 , 16
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+// handle_binary_expr - end
+// allocate_stack - current: 17 size: 1
+Set r239 , 1
+Add r254 , r239
+// alloc_stack - end
+// handle_binary_expr - output_register- r243
+// read_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
+Set r241 , 0
+Set r238 , 13
+Add r238 , r255
+Add r238 , r241
+Read r247 , r238
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 16, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 16
+Add r240 , r255
+Add r240 , r241
+Read r242 , r240
+// read_register- end
+Shl r247 , r242
+Cp r243 , r247
+// write_register- self: Data { stack_frame_offset: 17, size: 1, data_type: U32 }
+Set r241 , 0
+Set r238 , 17
+Add r238 , r255
+Add r238 , r241
+Write r238 , r243
+// write_register- end
+// handle_binary_expr - end
 // assign_new_variable - end
 
 // assign_new_variable
 // access_static_variable
-// allocate_stack - current: 15 size: 1
+// allocate_stack - current: 18 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
@@ -3318,25 +3706,25 @@ Add r249 , r251
 Set r248 , 0
 Sub r249 , r248
 // write_directly_to_reference_pointer - 247, input: r249
-Set r247 , 15
+Set r247 , 18
 Add r247 , r255
 Write r247 , r249
 // write_directly_to_reference_pointer - end
 // access_static_variable-end
 // index_array
-// index_array - var:Data { stack_frame_offset: 15, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-// allocate_stack - current: 16 size: 1
+// index_array - var:Data { stack_frame_offset: 18, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// allocate_stack - current: 19 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
 Set r245 , 0
-Set r244 , 14
+Set r244 , 12
 Add r244 , r255
 Add r244 , r245
 Read r247 , r244
 // read_register- end
-Set r245 , 15
+Set r245 , 18
 // array indexing- var_to_index.is_reference: true
 Cp r246 , r247
 Set r244 , 1
@@ -3344,8 +3732,8 @@ Mul r246 , r244
 // add 1 offset to account for 1'st register holding size of array
 Set r244 , 1
 Add r246 , r244
-// read_addr_of_register - self: Data { stack_frame_offset: 15, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-Set r244 , 15
+// read_addr_of_register - self: Data { stack_frame_offset: 18, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 18
 Add r244 , r255
 Read r244 , r244
 Set r243 , 0
@@ -3354,7 +3742,7 @@ Add r244 , r246
 // read_addr_of_register - end
 // index_array: read_addr_of_register - end addr_register:244 , offset_register:246
 // write_directly_to_reference_pointer - 243, input: r244
-Set r243 , 16
+Set r243 , 19
 Add r243 , r255
 Write r243 , r244
 // write_directly_to_reference_pointer - end
@@ -3363,32 +3751,32 @@ Write r243 , r244
 
 // assign_new_variable
 // direct_reference_access
-// allocate_stack - current: 17 size: 1
+// allocate_stack - current: 20 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
 // direct_reference_access - handle_inside_expr
 // direct_reference_access - handle_inside_expr- end
 // read_addr_of_last_reference_in_chain -output_register - r249
-Set r249 , 16
+Set r249 , 19
 Add r249 , r255
 Read r249 , r249
 Set r248 , 0
 Add r249 , r248
 // read_addr_of_last_reference_in_chain 
 // write_directly_to_reference_pointer - 248, input: r249
-Set r248 , 17
+Set r248 , 20
 Add r248 , r255
 Write r248 , r249
 // write_directly_to_reference_pointer - end
 // direct_reference_accesss - end
-// allocate_stack - current: 18 size: 1
+// allocate_stack - current: 21 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 17, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+// read_register- self: Data { stack_frame_offset: 20, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
 Set r246 , 0
-Set r245 , 17
+Set r245 , 20
 Add r245 , r255
 Read r245 , r245
 Set r247 , 0
@@ -3396,973 +3784,3774 @@ Add r245 , r247
 Add r245 , r246
 Read r248 , r245
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 21, size: 1, data_type: U32 }
 Set r246 , 0
-Set r247 , 18
+Set r247 , 21
 Add r247 , r255
 Add r247 , r246
 Write r247 , r248
 // write_register- end
 // assign_new_variable - end
 
-// if- condition: Binary { left: Identifier("addr", DebugData { line: 50, file: "allocator.tip" }), operator: Token { kind: Equals, value: "==", line: 50 }, right: Number(0, DebugData { line: 50, file: "allocator.tip" }), debug_data: DebugData { line: 50, file: "allocator.tip" } }
-// allocate_stack - current: 19 size: 1
+// assign_new_variable
+// handle_struct_initialization Struct {
+ //    size: 1,
+ //    name: "HeapBlock",
+ //    properties: {
+ //        "next": StructProperty {
+ //            data_type: Reference {
+ //                inside: Struct {
+ //                    name: "HeapBlock",
+ //                },
+ //                offset_of_data_from_reference_addr: 0,
+ //            },
+ //            offset_from_struct_base: 0,
+ //        },
+ //    },
+ //    contents_parsed: Done,
+ //}
+// allocate_stack - current: 22 size: 1
 Set r250 , 1
 Add r254 , r250
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 19, size: 1, data_type: U32 }
+// assignment
+// assignment data: Data { stack_frame_offset: 21, size: 1, data_type: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 } }
+// assignment- variable.is_reference: true data.is_reference: true 
+// read_referenced_address
+Set r249 , 21
+Add r249 , r255
+Read r249 , r249
 Set r248 , 0
-Set r247 , 19
+Add r249 , r248
+// read_referenced_address - end
+Set r247 , 22
 Add r247 , r255
-Add r247 , r248
-Write r247 , r254
-// write_register- end
-// if condition data
-// handle_binary_expr
-// handle number
-Set r247 , 0
-// allocate_stack - current: 20 size: 1
-Set r248 , 1
-Add r254 , r248
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: U32 }
-Set r246 , 0
-Set r245 , 20
-Add r245 , r255
-Add r245 , r246
-Write r245 , r247
-// write_register- end
-// handle number end
-// allocate_stack - current: 21 size: 1
-Set r244 , 1
-Add r254 , r244
+Write r247 , r249
+// end assignment
+// handle_struct_initialization end
+// assign_new_variable - end
+
+// assignment
+// memory_access
+// allocate_stack - current: 23 size: 1
+Set r250 , 1
+Add r254 , r250
 // alloc_stack - end
-// handle_binary_expr - output_register- r245
-// read_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
-Set r242 , 0
-Set r241 , 18
-Add r241 , r255
-Add r241 , r242
-Read r246 , r241
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 20, size: 1, data_type: U32 }
-Set r242 , 0
-Set r243 , 20
-Add r243 , r255
-Add r243 , r242
-Read r249 , r243
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 4294967294
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
 // read_register- end
-Eq r246 , r249 , r245
-// write_register- self: Data { stack_frame_offset: 21, size: 1, data_type: Bool }
-Set r242 , 0
-Set r241 , 21
-Add r241 , r255
-Add r241 , r242
-Write r241 , r245
-// write_register- end
-// handle_binary_expr - end
-// read condition
-// read_register- self: Data { stack_frame_offset: 21, size: 1, data_type: Bool }
-Set r246 , 0
-Set r241 , 21
-Add r241 , r255
-Add r241 , r246
-Read r245 , r241
+// write_directly_to_reference_pointer - 247, input: r249
+Set r247 , 23
+Add r247 , r255
+Write r247 , r249
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// assignment data: Data { stack_frame_offset: 21, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 21, size: 1, data_type: U32 }
+Set r247 , 0
+Set r248 , 21
+Add r248 , r255
+Add r248 , r247
+Read r246 , r248
 // read_register- end
-Not r245
-RSet r241 , :if34
-RJmpc r241 , r245
-// if contents
+// write_register- self: Data { stack_frame_offset: 23, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r247 , 0
+Set r249 , 23
+Add r249 , r255
+Read r249 , r249
+Set r248 , 0
+Add r249 , r248
+Add r249 , r247
+Write r249 , r246
+// write_register- end
+// end assignment
+
 // assignment
-// call_function_code
-// call_function_code - needed_stack: 4
-// allocate_stack - current: 22 size: 4
-Set r249 , 4
+// direct_reference_access
+// allocate_stack - current: 24 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// direct_reference_access - handle_inside_expr
+// access_static_variable
+// allocate_stack - current: 25 size: 1
+Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
-Set r238 , 0
-Set r237 , 14
-Add r237 , r255
-Add r237 , r238
-Read r243 , r237
-// read_register- end
-Set r242 , 4294967294
-Add r242 , r254
-Write r242 , r243
-RSet r243 , :function-return35
-Add r243 , r251
-Set r237 , 0
-Sub r243 , r237
-Write r254 , r243
-RSet r242 , :function_allocate_new_block_of_memory_from_main_pool_5
-RJmp r242
-:function-return35
-// function call output: Some(FunctionInputData { name: "allocate_new_block_of_memory_from_main_pool", data_type: U32, stack_frame_offset: -3 })
-// function call converted output data : Some(Data { stack_frame_offset: 23, size: 1, data_type: U32 })
-// assignment data: Data { stack_frame_offset: 23, size: 1, data_type: U32 }
-// assignment- variable.is_reference: false data.is_reference: false 
-// read_register- self: Data { stack_frame_offset: 23, size: 1, data_type: U32 }
-Set r242 , 0
-Set r246 , 23
+RSet r248 , :free_heap_blocks_linked_lists
+Add r248 , r251
+Set r247 , 0
+Sub r248 , r247
+// write_directly_to_reference_pointer - 246, input: r248
+Set r246 , 25
 Add r246 , r255
-Add r246 , r242
-Read r240 , r246
+Write r246 , r248
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// index_array
+// index_array - var:Data { stack_frame_offset: 25, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// allocate_stack - current: 26 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 12
+Add r243 , r255
+Add r243 , r244
+Read r246 , r243
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
-Set r242 , 0
-Set r243 , 18
+Set r244 , 25
+// array indexing- var_to_index.is_reference: true
+Cp r245 , r246
+Set r243 , 1
+Mul r245 , r243
+// add 1 offset to account for 1'st register holding size of array
+Set r243 , 1
+Add r245 , r243
+// read_addr_of_register - self: Data { stack_frame_offset: 25, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+Set r243 , 25
 Add r243 , r255
+Read r243 , r243
+Set r242 , 0
 Add r243 , r242
-Write r243 , r240
+Add r243 , r245
+// read_addr_of_register - end
+// index_array: read_addr_of_register - end addr_register:243 , offset_register:245
+// write_directly_to_reference_pointer - 242, input: r243
+Set r242 , 26
+Add r242 , r255
+Write r242 , r243
+// write_directly_to_reference_pointer - end
+// index_array - end
+// direct_reference_access - handle_inside_expr- end
+// read_addr_of_last_reference_in_chain -output_register - r244
+Set r244 , 26
+Add r244 , r255
+Read r244 , r244
+Set r245 , 0
+Add r244 , r245
+// read_addr_of_last_reference_in_chain 
+// write_directly_to_reference_pointer - 245, input: r244
+Set r245 , 24
+Add r245 , r255
+Write r245 , r244
+// write_directly_to_reference_pointer - end
+// direct_reference_accesss - end
+// assignment data: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+Set r243 , 0
+Set r246 , 4294967294
+Add r246 , r255
+Add r246 , r243
+Read r244 , r246
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 24, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r243 , 0
+Set r245 , 24
+Add r245 , r255
+Read r245 , r245
+Set r246 , 0
+Add r245 , r246
+Add r245 , r243
+Write r245 , r244
 // write_register- end
 // end assignment
 
-RSet r241 , :if_chain_end33
-RJmp r241
-// if contents end
-:if34
-// read_register- self: Data { stack_frame_offset: 19, size: 1, data_type: U32 }
-Set r243 , 0
-Set r242 , 19
-Add r242 , r255
-Add r242 , r243
-Read r254 , r242
+// Return
+
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
 // read_register- end
-// else - condition: None
-// allocate_stack - current: 20 size: 1
-Set r242 , 1
-Add r254 , r242
+Cp r255 , r249
+Read r250 , r250
+Jmp r250
+// function: Function { is_interrupt_function: false, name: "core_allocate", input: [FunctionInputData { name: "size", data_type: U32, stack_frame_offset: -2 }], output: Some(FunctionInputData { name: "core_allocate", data_type: U32, stack_frame_offset: -3 }), label_name: "function_core_allocate_4" }
+:function_core_allocate_4
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: U32 }
-Set r240 , 0
-Set r246 , 20
-Add r246 , r255
-Add r246 , r240
-Write r246 , r254
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
 // write_register- end
-// else contents
 // assign_new_variable
-// memory_access
-// allocate_stack - current: 21 size: 1
-Set r243 , 1
-Add r254 , r243
+// call_function_code
+// call_function_code - needed_stack: 4
+// allocate_stack - current: 2 size: 4
+Set r246 , 4
+Add r254 , r246
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
-Set r236 , 0
-Set r235 , 18
-Add r235 , r255
-Add r235 , r236
-Read r238 , r235
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+Set r241 , 0
+Set r240 , 4294967294
+Add r240 , r255
+Add r240 , r241
+Read r244 , r240
 // read_register- end
-// write_directly_to_reference_pointer - 236, input: r238
-Set r236 , 21
-Add r236 , r255
-Write r236 , r238
-// write_directly_to_reference_pointer - end
-// memory_access- end
+Set r247 , 4294967294
+Add r247 , r254
+Write r247 , r244
+RSet r244 , :function-return37
+Add r244 , r251
+Set r240 , 0
+Sub r244 , r240
+Write r254 , r244
+RSet r247 , :function_ceil_log2_7
+RJmp r247
+:function-return37
+// function call output: Some(FunctionInputData { name: "ceil_log2", data_type: U32, stack_frame_offset: -3 })
+// function call converted output data : Some(Data { stack_frame_offset: 3, size: 1, data_type: U32 })
 // assign_new_variable - end
 
 // assign_new_variable
 // access_static_variable
-// allocate_stack - current: 22 size: 1
-Set r235 , 1
-Add r254 , r235
+// allocate_stack - current: 6 size: 1
+Set r250 , 1
+Add r254 , r250
 // alloc_stack - end
-RSet r238 , :free_heap_blocks_linked_lists
-Add r238 , r251
-Set r236 , 0
-Sub r238 , r236
-// write_directly_to_reference_pointer - 239, input: r238
-Set r239 , 22
-Add r239 , r255
-Write r239 , r238
+RSet r249 , :min_heap_size_pow_2
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 247, input: r249
+Set r247 , 6
+Add r247 , r255
+Write r247 , r249
 // write_directly_to_reference_pointer - end
 // access_static_variable-end
-// index_array
-// index_array - var:Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-// allocate_stack - current: 23 size: 1
-Set r238 , 1
-Add r254 , r238
-// alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
-Set r233 , 0
-Set r232 , 14
-Add r232 , r255
-Add r232 , r233
-Read r239 , r232
-// read_register- end
-Set r233 , 22
-// array indexing- var_to_index.is_reference: true
-Cp r234 , r239
-Set r232 , 1
-Mul r234 , r232
-// add 1 offset to account for 1'st register holding size of array
-Set r232 , 1
-Add r234 , r232
-// read_addr_of_register - self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-Set r232 , 22
-Add r232 , r255
-Read r232 , r232
-Set r231 , 0
-Add r232 , r231
-Add r232 , r234
-// read_addr_of_register - end
-// index_array: read_addr_of_register - end addr_register:232 , offset_register:234
-// write_directly_to_reference_pointer - 231, input: r232
-Set r231 , 23
-Add r231 , r255
-Write r231 , r232
-// write_directly_to_reference_pointer - end
-// index_array - end
 // assign_new_variable - end
 
-// assignment
+// assign_new_variable
 // access_static_variable
-// allocate_stack - current: 24 size: 1
-Set r233 , 1
-Add r254 , r233
+// allocate_stack - current: 7 size: 1
+Set r250 , 1
+Add r254 , r250
 // alloc_stack - end
-RSet r234 , :free_heap_blocks_linked_lists
-Add r234 , r251
-Set r232 , 0
-Sub r234 , r232
-// write_directly_to_reference_pointer - 239, input: r234
-Set r239 , 24
-Add r239 , r255
-Write r239 , r234
+RSet r249 , :max_heap_size_pow_2
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 247, input: r249
+Set r247 , 7
+Add r247 , r255
+Write r247 , r249
 // write_directly_to_reference_pointer - end
 // access_static_variable-end
-// index_array
-// index_array - var:Data { stack_frame_offset: 24, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-// allocate_stack - current: 25 size: 1
-Set r234 , 1
-Add r254 , r234
-// alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
-Set r230 , 0
-Set r229 , 14
-Add r229 , r255
-Add r229 , r230
-Read r239 , r229
-// read_register- end
-Set r230 , 24
-// array indexing- var_to_index.is_reference: true
-Cp r231 , r239
-Set r229 , 1
-Mul r231 , r229
-// add 1 offset to account for 1'st register holding size of array
-Set r229 , 1
-Add r231 , r229
-// read_addr_of_register - self: Data { stack_frame_offset: 24, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
-Set r229 , 24
-Add r229 , r255
-Read r229 , r229
-Set r228 , 0
-Add r229 , r228
-Add r229 , r231
-// read_addr_of_register - end
-// index_array: read_addr_of_register - end addr_register:229 , offset_register:231
-// write_directly_to_reference_pointer - 228, input: r229
-Set r228 , 25
-Add r228 , r255
-Write r228 , r229
-// write_directly_to_reference_pointer - end
-// index_array - end
-// handle_struct_access- Data { stack_frame_offset: 16, size: 1, data_type: Reference { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, offset_of_data_from_reference_addr: 0 } } "HeapBlock"
-Set r231 , 0
-// read_addr_of_register - self: Data { stack_frame_offset: 16, size: 1, data_type: Reference { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, offset_of_data_from_reference_addr: 0 } }
-Set r230 , 16
-Add r230 , r255
-Read r230 , r230
-Set r229 , 0
-Add r230 , r229
-Read r230 , r230
-Set r229 , 0
-Add r230 , r229
-Add r230 , r231
-// read_addr_of_register - end
-// allocate_stack - current: 26 size: 1
-Set r229 , 1
-Add r254 , r229
-// alloc_stack - end
-Set r231 , 26
-Add r231 , r255
-Write r231 , r230
-// handle_struct_access- end
-// assignment data: Data { stack_frame_offset: 26, size: 1, data_type: Reference { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, offset_of_data_from_reference_addr: 0 } }
-// assignment- variable.is_reference: true data.is_reference: true 
-// read_referenced_address
-Set r231 , 26
-Add r231 , r255
-Read r231 , r231
-Set r230 , 0
-Add r231 , r230
-// read_referenced_address - end
-Read r231 , r231
-Set r230 , 0
-Add r231 , r230
-// read_referenced_address - end
-Set r239 , 25
-Add r239 , r255
-Read r239 , r239
-Set r230 , 0
-Add r239 , r230
-Write r239 , r231
-// end assignment
-
-RSet r246 , :if_chain_end33
-RJmp r246
-// else contents end
-:else36
-// read_register- self: Data { stack_frame_offset: 20, size: 1, data_type: U32 }
-Set r239 , 0
-Set r230 , 20
-Add r230 , r255
-Add r230 , r239
-Read r254 , r230
-// read_register- end
-:if_chain_end33
-// Return
-Set r249 , 4294967293
-// read_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 18
-Add r246 , r255
-Add r246 , r247
-Read r250 , r246
-// read_register- end
-Add r249 , r255
-Write r249 , r250
-Cp r250 , r255
-Cp r254 , r255
-// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r247 , 0
-Set r248 , 1
-Add r248 , r255
-Add r248 , r247
-Read r249 , r248
-// read_register- end
-Cp r255 , r249
-Read r250 , r250
-Jmp r250
+// assign_new_variable - end
 
-// function: Function { is_interrupt_function: false, name: "allocate_new_block_of_memory_from_main_pool", input: [FunctionInputData { name: "size_index", data_type: U32, stack_frame_offset: -2 }], output: Some(FunctionInputData { name: "allocate_new_block_of_memory_from_main_pool", data_type: U32, stack_frame_offset: -3 }), label_name: "function_allocate_new_block_of_memory_from_main_pool_5" }
-:function_allocate_new_block_of_memory_from_main_pool_5
-Cp r250 , r255
-Cp r255 , r254
-// allocate_stack - current: 0 size: 1
-Set r249 , 1
-Add r254 , r249
-// alloc_stack - end
-// allocate_stack - current: 1 size: 1
-Set r248 , 1
-Add r254 , r248
+// if- condition: Binary { left: Identifier("size_log2", DebugData { line: 43, file: "allocator.tip" }), operator: Token { kind: Greater, value: ">", line: 43 }, right: Identifier("max", DebugData { line: 43, file: "allocator.tip" }), debug_data: DebugData { line: 43, file: "allocator.tip" } }
+// allocate_stack - current: 8 size: 1
+Set r250 , 1
+Add r254 , r250
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r246 , 0
-Set r245 , 1
-Add r245 , r255
-Add r245 , r246
-Write r245 , r250
+// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+Set r248 , 0
+Set r247 , 8
+Add r247 , r255
+Add r247 , r248
+Write r247 , r254
 // write_register- end
-// assign_new_variable
+// if condition data
 // handle_binary_expr
-// handle number
-Set r245 , 2
-// allocate_stack - current: 2 size: 1
+// allocate_stack - current: 9 size: 1
 Set r246 , 1
 Add r254 , r246
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+// handle_binary_expr - output_register- r247
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
 Set r244 , 0
-Set r243 , 2
+Set r243 , 3
 Add r243 , r255
 Add r243 , r244
-Write r243 , r245
-// write_register- end
-// handle number end
-// handle_binary_expr
-// handle_binary_expr
-// access_static_variable
-// allocate_stack - current: 3 size: 1
-Set r243 , 1
-Add r254 , r243
-// alloc_stack - end
-RSet r244 , :min_heap_size_pow_2
-Add r244 , r251
-Set r247 , 0
-Sub r244 , r247
-// write_directly_to_reference_pointer - 242, input: r244
-Set r242 , 3
-Add r242 , r255
-Write r242 , r244
-// write_directly_to_reference_pointer - end
-// access_static_variable-end
-// allocate_stack - current: 4 size: 1
-Set r240 , 1
-Add r254 , r240
-// alloc_stack - end
-// handle_binary_expr - output_register- r244
-// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
-Set r238 , 0
-Set r237 , 4294967294
-Add r237 , r255
-Add r237 , r238
-Read r242 , r237
+Read r248 , r243
 // read_register- end
-// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r238 , 0
+// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r245 , 7
+Add r245 , r255
+Read r245 , r245
+Set r243 , 0
+Add r245 , r243
+Add r245 , r244
+Read r249 , r245
+// read_register- end
+Gt r248 , r249 , r247
+// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: Bool }
+Set r244 , 0
+Set r243 , 9
+Add r243 , r255
+Add r243 , r244
+Write r243 , r247
+// write_register- end
+// handle_binary_expr - end
+// read condition
+// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: Bool }
+Set r248 , 0
+Set r243 , 9
+Add r243 , r255
+Add r243 , r248
+Read r247 , r243
+// read_register- end
+Not r247
+RSet r243 , :if39
+RJmpc r243 , r247
+// if contents
+// allocate_stack - current: 10 size: 21
+Set r249 , 21
+Add r254 , r249
+// alloc_stack - end
+Set r248 , 20
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 0
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 97
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 1
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 108
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 2
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 108
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 3
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 111
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 4
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 99
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 5
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 32
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 6
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 108
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 7
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 111
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 8
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 110
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 9
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 103
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 10
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 32
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 11
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 108
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 12
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 111
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 13
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 110
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 14
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 103
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 15
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 32
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 16
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 108
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 17
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 111
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 18
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+Set r248 , 110
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 19
+Set r244 , 10
+Add r244 , r255
+Add r244 , r245
+Write r244 , r248
+// write_register- end
+Set r248 , 103
+// write_register- self: Data { stack_frame_offset: 10, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r245 , 20
+Set r242 , 10
+Add r242 , r255
+Add r242 , r245
+Write r242 , r248
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 31 size: 3
+Set r245 , 3
+Add r254 , r245
+// alloc_stack - end
+// call_function_code - handle reference variable
+Set r244 , 4294967294
+Add r244 , r254
+// read_addr_of_self
+Set r241 , 10
+Add r241 , r255
+// read_addr_of_self - end
+Write r244 , r241
+// call_function_code - handle reference variable, end
+RSet r241 , :function-return40
+Add r241 , r251
+Set r239 , 0
+Sub r241 , r239
+Write r254 , r241
+RSet r244 , :function_panic_2
+RJmp r244
+:function-return40
+// function call output: None
+// function call converted output data : None
+
+RSet r243 , :if_chain_end38
+RJmp r243
+// if contents end
+:if39
+// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+Set r241 , 0
+Set r244 , 8
+Add r244 , r255
+Add r244 , r241
+Read r254 , r244
+// read_register- end
+:if_chain_end38
+// assign_new_variable
+// handle_binary_expr
+// call_function_code
+// call_function_code - needed_stack: 5
+// allocate_stack - current: 9 size: 5
+Set r249 , 5
+Add r254 , r249
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r243 , 6
+Add r243 , r255
+Read r243 , r243
+Set r245 , 0
+Add r243 , r245
+Add r243 , r244
+Read r247 , r243
+// read_register- end
+Set r248 , 4294967294
+Add r248 , r254
+Write r248 , r247
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r244 , 0
+Set r245 , 3
+Add r245 , r255
+Add r245 , r244
+Read r247 , r245
+// read_register- end
+Set r248 , 4294967293
+Add r248 , r254
+Write r248 , r247
+RSet r247 , :function-return41
+Add r247 , r251
+Set r245 , 0
+Sub r247 , r245
+Write r254 , r247
+RSet r248 , :function_max_6
+RJmp r248
+:function-return41
+// function call output: Some(FunctionInputData { name: "max", data_type: U32, stack_frame_offset: -4 })
+// function call converted output data : Some(Data { stack_frame_offset: 10, size: 1, data_type: U32 })
+// allocate_stack - current: 14 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// handle_binary_expr - output_register- r246
+// read_register- self: Data { stack_frame_offset: 10, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 10
+Add r242 , r255
+Add r242 , r243
+Read r247 , r242
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r243 , 0
+Set r244 , 6
+Add r244 , r255
+Read r244 , r244
+Set r242 , 0
+Add r244 , r242
+Add r244 , r243
+Read r248 , r244
+// read_register- end
+Sub r247 , r248
+Cp r246 , r247
+// write_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 14
+Add r242 , r255
+Add r242 , r243
+Write r242 , r246
+// write_register- end
+// handle_binary_expr - end
+// assign_new_variable - end
+
+// assign_new_variable
+// access_static_variable
+// allocate_stack - current: 15 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+RSet r249 , :free_heap_blocks_linked_lists
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 247, input: r249
+Set r247 , 15
+Add r247 , r255
+Write r247 , r249
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// index_array
+// index_array - var:Data { stack_frame_offset: 15, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// allocate_stack - current: 16 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
+Set r245 , 0
+Set r244 , 14
+Add r244 , r255
+Add r244 , r245
+Read r247 , r244
+// read_register- end
+Set r245 , 15
+// array indexing- var_to_index.is_reference: true
+Cp r246 , r247
+Set r244 , 1
+Mul r246 , r244
+// add 1 offset to account for 1'st register holding size of array
+Set r244 , 1
+Add r246 , r244
+// read_addr_of_register - self: Data { stack_frame_offset: 15, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 15
+Add r244 , r255
+Read r244 , r244
+Set r243 , 0
+Add r244 , r243
+Add r244 , r246
+// read_addr_of_register - end
+// index_array: read_addr_of_register - end addr_register:244 , offset_register:246
+// write_directly_to_reference_pointer - 243, input: r244
+Set r243 , 16
+Add r243 , r255
+Write r243 , r244
+// write_directly_to_reference_pointer - end
+// index_array - end
+// assign_new_variable - end
+
+// assign_new_variable
+// direct_reference_access
+// allocate_stack - current: 17 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// direct_reference_access - handle_inside_expr
+// direct_reference_access - handle_inside_expr- end
+// read_addr_of_last_reference_in_chain -output_register - r249
+Set r249 , 16
+Add r249 , r255
+Read r249 , r249
+Set r248 , 0
+Add r249 , r248
+// read_addr_of_last_reference_in_chain 
+// write_directly_to_reference_pointer - 248, input: r249
+Set r248 , 17
+Add r248 , r255
+Write r248 , r249
+// write_directly_to_reference_pointer - end
+// direct_reference_accesss - end
+// allocate_stack - current: 18 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 17, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r246 , 0
+Set r245 , 17
+Add r245 , r255
+Read r245 , r245
+Set r247 , 0
+Add r245 , r247
+Add r245 , r246
+Read r248 , r245
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
+Set r246 , 0
+Set r247 , 18
+Add r247 , r255
+Add r247 , r246
+Write r247 , r248
+// write_register- end
+// assign_new_variable - end
+
+// if- condition: Binary { left: Identifier("addr", DebugData { line: 50, file: "allocator.tip" }), operator: Token { kind: Equals, value: "==", line: 50 }, right: Number(0, DebugData { line: 50, file: "allocator.tip" }), debug_data: DebugData { line: 50, file: "allocator.tip" } }
+// allocate_stack - current: 19 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 19, size: 1, data_type: U32 }
+Set r248 , 0
+Set r247 , 19
+Add r247 , r255
+Add r247 , r248
+Write r247 , r254
+// write_register- end
+// if condition data
+// handle_binary_expr
+// handle number
+Set r247 , 0
+// allocate_stack - current: 20 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 20
+Add r245 , r255
+Add r245 , r246
+Write r245 , r247
+// write_register- end
+// handle number end
+// allocate_stack - current: 21 size: 1
+Set r244 , 1
+Add r254 , r244
+// alloc_stack - end
+// handle_binary_expr - output_register- r245
+// read_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 18
+Add r241 , r255
+Add r241 , r242
+Read r246 , r241
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 20, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 20
+Add r243 , r255
+Add r243 , r242
+Read r249 , r243
+// read_register- end
+Eq r246 , r249 , r245
+// write_register- self: Data { stack_frame_offset: 21, size: 1, data_type: Bool }
+Set r242 , 0
+Set r241 , 21
+Add r241 , r255
+Add r241 , r242
+Write r241 , r245
+// write_register- end
+// handle_binary_expr - end
+// read condition
+// read_register- self: Data { stack_frame_offset: 21, size: 1, data_type: Bool }
+Set r246 , 0
+Set r241 , 21
+Add r241 , r255
+Add r241 , r246
+Read r245 , r241
+// read_register- end
+Not r245
+RSet r241 , :if43
+RJmpc r241 , r245
+// if contents
+// assignment
+// call_function_code
+// call_function_code - needed_stack: 4
+// allocate_stack - current: 22 size: 4
+Set r249 , 4
+Add r254 , r249
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
+Set r238 , 0
+Set r237 , 14
+Add r237 , r255
+Add r237 , r238
+Read r243 , r237
+// read_register- end
+Set r242 , 4294967294
+Add r242 , r254
+Write r242 , r243
+RSet r243 , :function-return44
+Add r243 , r251
+Set r237 , 0
+Sub r243 , r237
+Write r254 , r243
+RSet r242 , :function_allocate_new_block_of_memory_from_main_pool_5
+RJmp r242
+:function-return44
+// function call output: Some(FunctionInputData { name: "allocate_new_block_of_memory_from_main_pool", data_type: U32, stack_frame_offset: -3 })
+// function call converted output data : Some(Data { stack_frame_offset: 23, size: 1, data_type: U32 })
+// assignment data: Data { stack_frame_offset: 23, size: 1, data_type: U32 }
+// assignment- variable.is_reference: false data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 23, size: 1, data_type: U32 }
+Set r242 , 0
+Set r246 , 23
+Add r246 , r255
+Add r246 , r242
+Read r240 , r246
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 18
+Add r243 , r255
+Add r243 , r242
+Write r243 , r240
+// write_register- end
+// end assignment
+
+RSet r241 , :if_chain_end42
+RJmp r241
+// if contents end
+:if43
+// read_register- self: Data { stack_frame_offset: 19, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 19
+Add r242 , r255
+Add r242 , r243
+Read r254 , r242
+// read_register- end
+// else - condition: None
+// allocate_stack - current: 20 size: 1
+Set r242 , 1
+Add r254 , r242
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 20, size: 1, data_type: U32 }
+Set r240 , 0
+Set r246 , 20
+Add r246 , r255
+Add r246 , r240
+Write r246 , r254
+// write_register- end
+// else contents
+// assign_new_variable
+// memory_access
+// allocate_stack - current: 21 size: 1
+Set r243 , 1
+Add r254 , r243
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
+Set r236 , 0
+Set r235 , 18
+Add r235 , r255
+Add r235 , r236
+Read r238 , r235
+// read_register- end
+// write_directly_to_reference_pointer - 236, input: r238
+Set r236 , 21
+Add r236 , r255
+Write r236 , r238
+// write_directly_to_reference_pointer - end
+// memory_access- end
+// assign_new_variable - end
+
+// assign_new_variable
+// access_static_variable
+// allocate_stack - current: 22 size: 1
+Set r235 , 1
+Add r254 , r235
+// alloc_stack - end
+RSet r238 , :free_heap_blocks_linked_lists
+Add r238 , r251
+Set r236 , 0
+Sub r238 , r236
+// write_directly_to_reference_pointer - 239, input: r238
+Set r239 , 22
+Add r239 , r255
+Write r239 , r238
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// index_array
+// index_array - var:Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// allocate_stack - current: 23 size: 1
+Set r238 , 1
+Add r254 , r238
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
+Set r233 , 0
+Set r232 , 14
+Add r232 , r255
+Add r232 , r233
+Read r239 , r232
+// read_register- end
+Set r233 , 22
+// array indexing- var_to_index.is_reference: true
+Cp r234 , r239
+Set r232 , 1
+Mul r234 , r232
+// add 1 offset to account for 1'st register holding size of array
+Set r232 , 1
+Add r234 , r232
+// read_addr_of_register - self: Data { stack_frame_offset: 22, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+Set r232 , 22
+Add r232 , r255
+Read r232 , r232
+Set r231 , 0
+Add r232 , r231
+Add r232 , r234
+// read_addr_of_register - end
+// index_array: read_addr_of_register - end addr_register:232 , offset_register:234
+// write_directly_to_reference_pointer - 231, input: r232
+Set r231 , 23
+Add r231 , r255
+Write r231 , r232
+// write_directly_to_reference_pointer - end
+// index_array - end
+// assign_new_variable - end
+
+// assignment
+// access_static_variable
+// allocate_stack - current: 24 size: 1
+Set r233 , 1
+Add r254 , r233
+// alloc_stack - end
+RSet r234 , :free_heap_blocks_linked_lists
+Add r234 , r251
+Set r232 , 0
+Sub r234 , r232
+// write_directly_to_reference_pointer - 239, input: r234
+Set r239 , 24
+Add r239 , r255
+Write r239 , r234
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// index_array
+// index_array - var:Data { stack_frame_offset: 24, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+// allocate_stack - current: 25 size: 1
+Set r234 , 1
+Add r254 , r234
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
+Set r230 , 0
+Set r229 , 14
+Add r229 , r255
+Add r229 , r230
+Read r239 , r229
+// read_register- end
+Set r230 , 24
+// array indexing- var_to_index.is_reference: true
+Cp r231 , r239
+Set r229 , 1
+Mul r231 , r229
+// add 1 offset to account for 1'st register holding size of array
+Set r229 , 1
+Add r231 , r229
+// read_addr_of_register - self: Data { stack_frame_offset: 24, size: 1, data_type: Reference { inside: Array { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, len: 8 }, offset_of_data_from_reference_addr: 0 } }
+Set r229 , 24
+Add r229 , r255
+Read r229 , r229
+Set r228 , 0
+Add r229 , r228
+Add r229 , r231
+// read_addr_of_register - end
+// index_array: read_addr_of_register - end addr_register:229 , offset_register:231
+// write_directly_to_reference_pointer - 228, input: r229
+Set r228 , 25
+Add r228 , r255
+Write r228 , r229
+// write_directly_to_reference_pointer - end
+// index_array - end
+// handle_struct_access- Data { stack_frame_offset: 16, size: 1, data_type: Reference { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, offset_of_data_from_reference_addr: 0 } } "HeapBlock"
+Set r231 , 0
+// read_addr_of_register - self: Data { stack_frame_offset: 16, size: 1, data_type: Reference { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, offset_of_data_from_reference_addr: 0 } }
+Set r230 , 16
+Add r230 , r255
+Read r230 , r230
+Set r229 , 0
+Add r230 , r229
+Read r230 , r230
+Set r229 , 0
+Add r230 , r229
+Add r230 , r231
+// read_addr_of_register - end
+// allocate_stack - current: 26 size: 1
+Set r229 , 1
+Add r254 , r229
+// alloc_stack - end
+Set r231 , 26
+Add r231 , r255
+Write r231 , r230
+// handle_struct_access- end
+// assignment data: Data { stack_frame_offset: 26, size: 1, data_type: Reference { inside: Reference { inside: Struct { name: "HeapBlock" }, offset_of_data_from_reference_addr: 0 }, offset_of_data_from_reference_addr: 0 } }
+// assignment- variable.is_reference: true data.is_reference: true 
+// read_referenced_address
+Set r231 , 26
+Add r231 , r255
+Read r231 , r231
+Set r230 , 0
+Add r231 , r230
+// read_referenced_address - end
+Read r231 , r231
+Set r230 , 0
+Add r231 , r230
+// read_referenced_address - end
+Set r239 , 25
+Add r239 , r255
+Read r239 , r239
+Set r230 , 0
+Add r239 , r230
+Write r239 , r231
+// end assignment
+
+RSet r246 , :if_chain_end42
+RJmp r246
+// else contents end
+:else45
+// read_register- self: Data { stack_frame_offset: 20, size: 1, data_type: U32 }
+Set r239 , 0
+Set r230 , 20
+Add r230 , r255
+Add r230 , r239
+Read r254 , r230
+// read_register- end
+:if_chain_end42
+// Return
+Set r249 , 4294967293
+// read_register- self: Data { stack_frame_offset: 18, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 18
+Add r246 , r255
+Add r246 , r247
+Read r250 , r246
+// read_register- end
+Add r249 , r255
+Write r249 , r250
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r248 , 1
+Add r248 , r255
+Add r248 , r247
+Read r249 , r248
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Jmp r250
+
+// function: Function { is_interrupt_function: false, name: "allocate_new_block_of_memory_from_main_pool", input: [FunctionInputData { name: "size_index", data_type: U32, stack_frame_offset: -2 }], output: Some(FunctionInputData { name: "allocate_new_block_of_memory_from_main_pool", data_type: U32, stack_frame_offset: -3 }), label_name: "function_allocate_new_block_of_memory_from_main_pool_5" }
+:function_allocate_new_block_of_memory_from_main_pool_5
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// assign_new_variable
+// handle_binary_expr
+// handle number
+Set r245 , 2
+// allocate_stack - current: 2 size: 1
+Set r246 , 1
+Add r254 , r246
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+// handle number end
+// handle_binary_expr
+// handle_binary_expr
+// access_static_variable
+// allocate_stack - current: 3 size: 1
+Set r243 , 1
+Add r254 , r243
+// alloc_stack - end
+RSet r244 , :min_heap_size_pow_2
+Add r244 , r251
+Set r247 , 0
+Sub r244 , r247
+// write_directly_to_reference_pointer - 242, input: r244
+Set r242 , 3
+Add r242 , r255
+Write r242 , r244
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// allocate_stack - current: 4 size: 1
+Set r240 , 1
+Add r254 , r240
+// alloc_stack - end
+// handle_binary_expr - output_register- r244
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+Set r238 , 0
+Set r237 , 4294967294
+Add r237 , r255
+Add r237 , r238
+Read r242 , r237
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r238 , 0
 Set r239 , 3
 Add r239 , r255
-Read r239 , r239
-Set r237 , 0
-Add r239 , r237
+Read r239 , r239
+Set r237 , 0
+Add r239 , r237
+Add r239 , r238
+Read r241 , r239
+// read_register- end
+Add r242 , r241
+Cp r244 , r242
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r238 , 0
+Set r237 , 4
+Add r237 , r255
+Add r237 , r238
+Write r237 , r244
+// write_register- end
+// handle_binary_expr - end
+// handle number
+Set r244 , 1
+// allocate_stack - current: 5 size: 1
+Set r241 , 1
+Add r254 , r241
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r237 , 0
+Set r238 , 5
+Add r238 , r255
+Add r238 , r237
+Write r238 , r244
+// write_register- end
+// handle number end
+// allocate_stack - current: 6 size: 1
+Set r239 , 1
+Add r254 , r239
+// alloc_stack - end
+// handle_binary_expr - output_register- r238
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r235 , 0
+Set r234 , 4
+Add r234 , r255
+Add r234 , r235
+Read r237 , r234
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r235 , 0
+Set r236 , 5
+Add r236 , r255
+Add r236 , r235
+Read r242 , r236
+// read_register- end
+Sub r237 , r242
+Cp r238 , r237
+// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r235 , 0
+Set r234 , 6
+Add r234 , r255
+Add r234 , r235
+Write r234 , r238
+// write_register- end
+// handle_binary_expr - end
+// allocate_stack - current: 7 size: 1
+Set r234 , 1
+Add r254 , r234
+// alloc_stack - end
+// handle_binary_expr - output_register- r238
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r236 , 0
+Set r233 , 2
+Add r233 , r255
+Add r233 , r236
+Read r242 , r233
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r236 , 0
+Set r235 , 6
+Add r235 , r255
+Add r235 , r236
+Read r237 , r235
+// read_register- end
+Shl r242 , r237
+Cp r238 , r242
+// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r236 , 0
+Set r233 , 7
+Add r233 , r255
+Add r233 , r236
+Write r233 , r238
+// write_register- end
+// handle_binary_expr - end
+// assign_new_variable - end
+
+// assign_new_variable
+// access_static_variable
+// allocate_stack - current: 8 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+RSet r249 , :heap_main_pool_head
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 247, input: r249
+Set r247 , 8
+Add r247 , r255
+Write r247 , r249
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// assign_new_variable - end
+
+// assign_new_variable
+// allocate_stack - current: 9 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r247 , 0
+Set r246 , 8
+Add r246 , r255
+Read r246 , r246
+Set r248 , 0
+Add r246 , r248
+Add r246 , r247
+Read r249 , r246
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+Set r247 , 0
+Set r248 , 9
+Add r248 , r255
+Add r248 , r247
+Write r248 , r249
+// write_register- end
+// assign_new_variable - end
+
+// assignment
+// access_static_variable
+// allocate_stack - current: 10 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+RSet r249 , :heap_main_pool_head
+Add r249 , r251
+Set r248 , 0
+Sub r249 , r248
+// write_directly_to_reference_pointer - 247, input: r249
+Set r247 , 10
+Add r247 , r255
+Write r247 , r249
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// handle_binary_expr
+// access_static_variable
+// allocate_stack - current: 11 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+RSet r247 , :heap_main_pool_head
+Add r247 , r251
+Set r246 , 0
+Sub r247 , r246
+// write_directly_to_reference_pointer - 245, input: r247
+Set r245 , 11
+Add r245 , r255
+Write r245 , r247
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// allocate_stack - current: 12 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 7
+Add r242 , r255
+Add r242 , r243
+Read r245 , r242
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+Set r243 , 0
+Set r244 , 12
+Add r244 , r255
+Add r244 , r243
+Write r244 , r245
+// write_register- end
+// allocate_stack - current: 13 size: 1
+Set r242 , 1
+Add r254 , r242
+// alloc_stack - end
+// handle_binary_expr - output_register- r245
+// read_register- self: Data { stack_frame_offset: 11, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r240 , 0
+Set r239 , 11
+Add r239 , r255
+Read r239 , r239
+Set r241 , 0
+Add r239 , r241
+Add r239 , r240
+Read r244 , r239
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
+Set r240 , 0
+Set r241 , 12
+Add r241 , r255
+Add r241 , r240
+Read r243 , r241
+// read_register- end
+Add r244 , r243
+Cp r245 , r244
+// write_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
+Set r240 , 0
+Set r239 , 13
+Add r239 , r255
+Add r239 , r240
+Write r239 , r245
+// write_register- end
+// handle_binary_expr - end
+// assignment data: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
+// assignment- variable.is_reference: true data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
+Set r244 , 0
+Set r239 , 13
+Add r239 , r255
+Add r239 , r244
+Read r245 , r239
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 10, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r244 , 0
+Set r243 , 10
+Add r243 , r255
+Read r243 , r243
+Set r239 , 0
+Add r243 , r239
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+// end assignment
+
+// if- condition: Binary { left: Identifier("heap_pool_head", DebugData { line: 70, file: "allocator.tip" }), operator: Token { kind: Greater, value: ">", line: 70 }, right: FunctionCall { left: Identifier("access_static", DebugData { line: 70, file: "allocator.tip" }), values: [String("\"heap_end", DebugData { line: 70, file: "allocator.tip" })], debug_data: DebugData { line: 70, file: "allocator.tip" } }, debug_data: DebugData { line: 70, file: "allocator.tip" } }
+// allocate_stack - current: 14 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
+Set r248 , 0
+Set r247 , 14
+Add r247 , r255
+Add r247 , r248
+Write r247 , r254
+// write_register- end
+// if condition data
+// handle_binary_expr
+// access_static_variable
+// allocate_stack - current: 15 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+RSet r248 , :heap_end
+Add r248 , r251
+Set r249 , 0
+Sub r248 , r249
+// write_directly_to_reference_pointer - 246, input: r248
+Set r246 , 15
+Add r246 , r255
+Write r246 , r248
+// write_directly_to_reference_pointer - end
+// access_static_variable-end
+// allocate_stack - current: 16 size: 1
+Set r244 , 1
+Add r254 , r244
+// alloc_stack - end
+// handle_binary_expr - output_register- r248
+// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r242 , 0
+Set r241 , 8
+Add r241 , r255
+Read r241 , r241
+Set r243 , 0
+Add r241 , r243
+Add r241 , r242
+Read r246 , r241
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 15, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r242 , 0
+Set r243 , 15
+Add r243 , r255
+Read r243 , r243
+Set r241 , 0
+Add r243 , r241
+Add r243 , r242
+Read r245 , r243
+// read_register- end
+Gt r246 , r245 , r248
+// write_register- self: Data { stack_frame_offset: 16, size: 1, data_type: Bool }
+Set r242 , 0
+Set r241 , 16
+Add r241 , r255
+Add r241 , r242
+Write r241 , r248
+// write_register- end
+// handle_binary_expr - end
+// read condition
+// read_register- self: Data { stack_frame_offset: 16, size: 1, data_type: Bool }
+Set r246 , 0
+Set r241 , 16
+Add r241 , r255
+Add r241 , r246
+Read r248 , r241
+// read_register- end
+Not r248
+RSet r241 , :if47
+RJmpc r241 , r248
+// if contents
+// handle number
+Set r242 , 251
+// allocate_stack - current: 17 size: 1
+Set r243 , 1
+Add r254 , r243
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 17, size: 1, data_type: U32 }
+Set r239 , 0
+Set r238 , 17
+Add r238 , r255
+Add r238 , r239
+Write r238 , r242
+// write_register- end
+// handle number end
+Set r246 , 0
+// read_register- self: Data { stack_frame_offset: 17, size: 1, data_type: U32 }
+Set r239 , 0
+Set r240 , 17
+Add r240 , r255
+Add r240 , r239
+Read r245 , r240
+// read_register- end
+Phrp r246 , r245
+
+RSet r241 , :if_chain_end46
+RJmp r241
+// if contents end
+:if47
+// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
+Set r246 , 0
+Set r240 , 14
+Add r240 , r255
+Add r240 , r246
+Read r254 , r240
+// read_register- end
+:if_chain_end46
+// Return
+Set r249 , 4294967293
+// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 9
+Add r246 , r255
+Add r246 , r247
+Read r250 , r246
+// read_register- end
+Add r249 , r255
+Write r249 , r250
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r248 , 1
+Add r248 , r255
+Add r248 , r247
+Read r249 , r248
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Jmp r250
+
+// function: Function { is_interrupt_function: false, name: "max", input: [FunctionInputData { name: "a", data_type: U32, stack_frame_offset: -2 }, FunctionInputData { name: "b", data_type: U32, stack_frame_offset: -3 }], output: Some(FunctionInputData { name: "max", data_type: U32, stack_frame_offset: -4 }), label_name: "function_max_6" }
+:function_max_6
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// assign_new_variable
+// allocate_stack - current: 3 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r244 , 0
+Set r239 , 2
+Add r239 , r255
+Add r239 , r244
+Read r245 , r239
+// read_register- end
+Not r245
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 3
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+// handle_binary_expr - end
+// assign_new_variable - end
+
+// Return
+// handle_binary_expr
+// handle_binary_expr
+// allocate_stack - current: 4 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 3
+Add r245 , r255
+Add r245 , r246
+Read r250 , r245
+// read_register- end
+Not r250
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r246 , 0
+Set r247 , 4
+Add r247 , r255
+Add r247 , r246
+Write r247 , r250
+// write_register- end
+// handle_binary_expr - end
+// allocate_stack - current: 5 size: 1
+Set r246 , 1
+Add r254 , r246
+// alloc_stack - end
+// handle_binary_expr - output_register- r249
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 4294967294
+Add r243 , r255
+Add r243 , r244
+Read r250 , r243
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r244 , 0
+Set r245 , 4
+Add r245 , r255
+Add r245 , r244
+Read r247 , r245
+// read_register- end
+And r250 , r247
+Cp r249 , r250
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 5
+Add r243 , r255
+Add r243 , r244
+Write r243 , r249
+// write_register- end
+// handle_binary_expr - end
+// handle_binary_expr
+// allocate_stack - current: 6 size: 1
+Set r243 , 1
+Add r254 , r243
+// alloc_stack - end
+// handle_binary_expr - output_register- r249
+// read_register- self: Data { stack_frame_offset: -3, size: 1, data_type: U32 }
+Set r245 , 0
+Set r242 , 4294967293
+Add r242 , r255
+Add r242 , r245
+Read r247 , r242
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r245 , 0
+Set r244 , 3
+Add r244 , r255
+Add r244 , r245
+Read r250 , r244
+// read_register- end
+And r247 , r250
+Cp r249 , r247
+// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r245 , 0
+Set r242 , 6
+Add r242 , r255
+Add r242 , r245
+Write r242 , r249
+// write_register- end
+// handle_binary_expr - end
+// allocate_stack - current: 7 size: 1
+Set r242 , 1
+Add r254 , r242
+// alloc_stack - end
+// handle_binary_expr - output_register- r249
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r244 , 0
+Set r241 , 5
+Add r241 , r255
+Add r241 , r244
+Read r250 , r241
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r244 , 0
+Set r245 , 6
+Add r245 , r255
+Add r245 , r244
+Read r247 , r245
+// read_register- end
+Or r250 , r247
+Cp r249 , r250
+// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r244 , 0
+Set r241 , 7
+Add r241 , r255
+Add r241 , r244
+Write r241 , r249
+// write_register- end
+// handle_binary_expr - end
+Set r247 , 4294967292
+// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r241 , 0
+Set r244 , 7
+Add r244 , r255
+Add r244 , r241
+Read r249 , r244
+// read_register- end
+Add r247 , r255
+Write r247 , r249
+Cp r249 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r241 , 0
+Set r250 , 1
+Add r250 , r255
+Add r250 , r241
+Read r247 , r250
+// read_register- end
+Cp r255 , r247
+Read r249 , r249
+Jmp r249
+
+// function: Function { is_interrupt_function: false, name: "ceil_log2", input: [FunctionInputData { name: "x", data_type: U32, stack_frame_offset: -2 }], output: Some(FunctionInputData { name: "ceil_log2", data_type: U32, stack_frame_offset: -3 }), label_name: "function_ceil_log2_7" }
+:function_ceil_log2_7
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// assign_new_variable
+// handle number
+Set r245 , 0
+// allocate_stack - current: 2 size: 1
+Set r246 , 1
+Add r254 , r246
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+// handle number end
+// assign_new_variable - end
+
+// assign_new_variable
+// handle number
+Set r250 , 1
+// allocate_stack - current: 3 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 3
+Add r246 , r255
+Add r246 , r247
+Write r246 , r250
+// write_register- end
+// handle number end
+// assign_new_variable - end
+
+// allocate_stack - current: 4 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r248 , 0
+Set r247 , 4
+Add r247 , r255
+Add r247 , r248
+Write r247 , r254
+// write_register- end
+:while_start48
+// handle_binary_expr
+// allocate_stack - current: 5 size: 1
+Set r244 , 1
+Add r254 , r244
+// alloc_stack - end
+// handle_binary_expr - output_register- r249
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r242 , 0
+Set r241 , 3
+Add r241 , r255
+Add r241 , r242
+Read r246 , r241
+// read_register- end
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+Set r242 , 0
+Set r243 , 4294967294
+Add r243 , r255
+Add r243 , r242
+Read r245 , r243
+// read_register- end
+Lt r246 , r245 , r249
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: Bool }
+Set r242 , 0
+Set r241 , 5
+Add r241 , r255
+Add r241 , r242
+Write r241 , r249
+// write_register- end
+// handle_binary_expr - end
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: Bool }
+Set r245 , 0
+Set r246 , 5
+Add r246 , r255
+Add r246 , r245
+Read r247 , r246
+// read_register- end
+Not r247
+RSet r248 , :while_end49
+RJmpc r248 , r247
+// while loop contents:
+// assignment
+// handle_binary_expr
+// handle number
+Set r246 , 1
+// allocate_stack - current: 6 size: 1
+Set r245 , 1
+Add r254 , r245
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r241 , 0
+Set r242 , 6
+Add r242 , r255
+Add r242 , r241
+Write r242 , r246
+// write_register- end
+// handle number end
+// allocate_stack - current: 7 size: 1
+Set r243 , 1
+Add r254 , r243
+// alloc_stack - end
+// handle_binary_expr - output_register- r242
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r239 , 0
+Set r238 , 3
+Add r238 , r255
+Add r238 , r239
+Read r241 , r238
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+Set r239 , 0
+Set r240 , 6
+Add r240 , r255
+Add r240 , r239
+Read r249 , r240
+// read_register- end
+Shl r241 , r249
+Cp r242 , r241
+// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r239 , 0
+Set r238 , 7
+Add r238 , r255
+Add r238 , r239
+Write r238 , r242
+// write_register- end
+// handle_binary_expr - end
+// assignment data: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+// assignment- variable.is_reference: false data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Set r241 , 0
+Set r238 , 7
+Add r238 , r255
+Add r238 , r241
+Read r242 , r238
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r241 , 0
+Set r249 , 3
+Add r249 , r255
+Add r249 , r241
+Write r249 , r242
+// write_register- end
+// end assignment
+
+// assignment
+// handle_binary_expr
+// handle number
+Set r242 , 1
+// allocate_stack - current: 8 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+Set r238 , 0
+Set r239 , 8
+Add r239 , r255
 Add r239 , r238
-Read r241 , r239
+Write r239 , r242
+// write_register- end
+// handle number end
+// allocate_stack - current: 9 size: 1
+Set r240 , 1
+Add r254 , r240
+// alloc_stack - end
+// handle_binary_expr - output_register- r239
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r236 , 0
+Set r235 , 2
+Add r235 , r255
+Add r235 , r236
+Read r238 , r235
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
+Set r236 , 0
+Set r237 , 8
+Add r237 , r255
+Add r237 , r236
+Read r241 , r237
+// read_register- end
+Add r238 , r241
+Cp r239 , r238
+// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+Set r236 , 0
+Set r235 , 9
+Add r235 , r255
+Add r235 , r236
+Write r235 , r239
+// write_register- end
+// handle_binary_expr - end
+// assignment data: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+// assignment- variable.is_reference: false data.is_reference: false 
+// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
+Set r238 , 0
+Set r235 , 9
+Add r235 , r255
+Add r235 , r238
+Read r239 , r235
+// read_register- end
+// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r238 , 0
+Set r241 , 2
+Add r241 , r255
+Add r241 , r238
+Write r241 , r239
+// write_register- end
+// end assignment
+
+// while loop contents - end
+RSet r248 , :while_start48
+RJmp r248
+:while_end49
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r241 , 0
+Set r238 , 4
+Add r238 , r255
+Add r238 , r241
+Read r254 , r238
+// read_register- end
+// Return
+Set r249 , 4294967293
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 2
+Add r246 , r255
+Add r246 , r247
+Read r250 , r246
+// read_register- end
+Add r249 , r255
+Write r249 , r250
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r248 , 1
+Add r248 , r255
+Add r248 , r247
+Read r249 , r248
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Jmp r250
+
+// function: Function { is_interrupt_function: true, name: "keyboard_interrupt", input: [FunctionInputData { name: "data", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_keyboard_interrupt_8" }
+:function_keyboard_interrupt_8
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+Set r245 , 0
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 4294967294
+Add r243 , r255
+Add r243 , r244
+Read r246 , r243
+// read_register- end
+Phrp r245 , r246
+
+// Return
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Iret r250
+// function: Function { is_interrupt_function: true, name: "timer_interrupt", input: [FunctionInputData { name: "data", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_timer_interrupt_9" }
+:function_timer_interrupt_9
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// allocate_stack - current: 2 size: 2
+Set r246 , 2
+Add r254 , r246
+// alloc_stack - end
+Set r245 , 1
+// write_register- self: Data { stack_frame_offset: 2, size: 2, data_type: Array { inside: Char, len: 1 } }
+Set r244 , 0
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 116
+// write_register- self: Data { stack_frame_offset: 2, size: 2, data_type: Array { inside: Char, len: 1 } }
+Set r244 , 1
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 4 size: 3
+Set r244 , 3
+Add r254 , r244
+// alloc_stack - end
+// call_function_code - handle reference variable
+Set r243 , 4294967294
+Add r243 , r254
+// read_addr_of_self
+Set r242 , 2
+Add r242 , r255
+// read_addr_of_self - end
+Write r243 , r242
+// call_function_code - handle reference variable, end
+RSet r242 , :function-return50
+Add r242 , r251
+Set r240 , 0
+Sub r242 , r240
+Write r254 , r242
+RSet r243 , :function_print_chars_1
+RJmp r243
+:function-return50
+// function call output: None
+// function call converted output data : None
+
+// Return
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Iret r250
+// function: Function { is_interrupt_function: false, name: "fault", input: [FunctionInputData { name: "text", data_type: Reference { inside: Array { inside: U32, len: 0 }, offset_of_data_from_reference_addr: 0 }, stack_frame_offset: -2 }], output: None, label_name: "function_fault_10" }
+:function_fault_10
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 2 size: 3
+Set r246 , 3
+Add r254 , r246
+// alloc_stack - end
+// call_function_code - handle reference variable
+Set r247 , 4294967294
+Add r247 , r254
+// read_referenced_address
+Set r244 , 4294967294
+Add r244 , r255
+Read r244 , r244
+Set r242 , 0
+Add r244 , r242
+// read_referenced_address - end
+Write r247 , r244
+// call_function_code - handle reference variable, end
+RSet r244 , :function-return51
+Add r244 , r251
+Set r242 , 0
+Sub r244 , r242
+Write r254 , r244
+RSet r247 , :function_print_chars_1
+RJmp r247
+:function-return51
+// function call output: None
+// function call converted output data : None
+
+// allocate_stack - current: 5 size: 1
+Set r250 , 1
+Add r254 , r250
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r248 , 0
+Set r247 , 5
+Add r247 , r255
+Add r247 , r248
+Write r247 , r254
+// write_register- end
+:while_start52
+Set r249 , 1
+// allocate_stack - current: 6 size: 1
+Set r246 , 1
+Add r254 , r246
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Bool }
+Set r244 , 0
+Set r243 , 6
+Add r243 , r255
+Add r243 , r244
+Write r243 , r249
+// write_register- end
+// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: Bool }
+Set r244 , 0
+Set r245 , 6
+Add r245 , r255
+Add r245 , r244
+Read r247 , r245
+// read_register- end
+Not r247
+RSet r248 , :while_end53
+RJmpc r248 , r247
+// while loop contents:
+Halt
+
+// while loop contents - end
+RSet r248 , :while_start52
+RJmp r248
+:while_end53
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
+Set r244 , 0
+Set r243 , 5
+Add r243 , r255
+Add r243 , r244
+Read r254 , r243
+// read_register- end
+// Return
+
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Jmp r250
+// function: Function { is_interrupt_function: true, name: "illegal_instruction_interrupt", input: [FunctionInputData { name: "data", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_illegal_instruction_interrupt_11" }
+:function_illegal_instruction_interrupt_11
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// allocate_stack - current: 2 size: 20
+Set r246 , 20
+Add r254 , r246
+// alloc_stack - end
+Set r245 , 19
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 0
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 105
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 1
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+Set r245 , 108
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 2
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 108
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 3
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+Set r245 , 101
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 4
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 103
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 5
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+Set r245 , 97
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 6
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 108
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 7
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+Set r245 , 32
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 8
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 105
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 9
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+Set r245 , 110
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 10
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 115
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 11
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+Set r245 , 116
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 12
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 114
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 13
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+Set r245 , 117
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 14
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 99
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 15
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+Set r245 , 116
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 16
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 105
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 17
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+Set r245 , 111
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 18
+Set r243 , 2
+Add r243 , r255
+Add r243 , r244
+Write r243 , r245
+// write_register- end
+Set r245 , 110
+// write_register- self: Data { stack_frame_offset: 2, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r244 , 19
+Set r247 , 2
+Add r247 , r255
+Add r247 , r244
+Write r247 , r245
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 22 size: 3
+Set r244 , 3
+Add r254 , r244
+// alloc_stack - end
+// call_function_code - handle reference variable
+Set r243 , 4294967294
+Add r243 , r254
+// read_addr_of_self
+Set r242 , 2
+Add r242 , r255
+// read_addr_of_self - end
+Write r243 , r242
+// call_function_code - handle reference variable, end
+RSet r242 , :function-return54
+Add r242 , r251
+Set r240 , 0
+Sub r242 , r240
+Write r254 , r242
+RSet r243 , :function_fault_10
+RJmp r243
+:function-return54
+// function call output: None
+// function call converted output data : None
+
+// Return
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
 // read_register- end
-Add r242 , r241
-Cp r244 , r242
-// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
-Set r238 , 0
-Set r237 , 4
-Add r237 , r255
-Add r237 , r238
-Write r237 , r244
+Cp r255 , r249
+Read r250 , r250
+Iret r250
+// function: Function { is_interrupt_function: true, name: "divide_by_zero_interrupt", input: [FunctionInputData { name: "cause", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_divide_by_zero_interrupt_12" }
+:function_divide_by_zero_interrupt_12
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
 // write_register- end
-// handle_binary_expr - end
+// if- condition: Binary { left: Identifier("cause", DebugData { line: 27, file: "interrupt_handlers.tip" }), operator: Token { kind: Equals, value: "==", line: 27 }, right: Number(0, DebugData { line: 27, file: "interrupt_handlers.tip" }), debug_data: DebugData { line: 27, file: "interrupt_handlers.tip" } }
+// allocate_stack - current: 2 size: 1
+Set r245 , 1
+Add r254 , r245
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r247 , 0
+Set r244 , 2
+Add r244 , r255
+Add r244 , r247
+Write r244 , r254
+// write_register- end
+// if condition data
+// handle_binary_expr
 // handle number
-Set r244 , 1
-// allocate_stack - current: 5 size: 1
+Set r244 , 0
+// allocate_stack - current: 3 size: 1
+Set r247 , 1
+Add r254 , r247
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 3
+Add r242 , r255
+Add r242 , r243
+Write r242 , r244
+// write_register- end
+// handle number end
+// allocate_stack - current: 4 size: 1
 Set r241 , 1
 Add r254 , r241
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
-Set r237 , 0
-Set r238 , 5
+// handle_binary_expr - output_register- r242
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+Set r239 , 0
+Set r238 , 4294967294
 Add r238 , r255
-Add r238 , r237
-Write r238 , r244
+Add r238 , r239
+Read r243 , r238
+// read_register- end
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r239 , 0
+Set r240 , 3
+Add r240 , r255
+Add r240 , r239
+Read r246 , r240
+// read_register- end
+Eq r243 , r246 , r242
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Bool }
+Set r239 , 0
+Set r238 , 4
+Add r238 , r255
+Add r238 , r239
+Write r238 , r242
+// write_register- end
+// handle_binary_expr - end
+// read condition
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Bool }
+Set r243 , 0
+Set r238 , 4
+Add r238 , r255
+Add r238 , r243
+Read r242 , r238
+// read_register- end
+Not r242
+RSet r238 , :if56
+RJmpc r238 , r242
+// if contents
+// allocate_stack - current: 5 size: 22
+Set r246 , 22
+Add r254 , r246
+// alloc_stack - end
+Set r243 , 21
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 0
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 1
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 2
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 118
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 3
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 4
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 5
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 6
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 7
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 98
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 8
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 121
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 9
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 10
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 122
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 11
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 12
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 114
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 13
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 111
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 14
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 15
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 16
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 110
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 17
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 18
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 19
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 20
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 118
+// write_register- self: Data { stack_frame_offset: 5, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r240 , 21
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
 // write_register- end
-// handle number end
-// allocate_stack - current: 6 size: 1
-Set r239 , 1
-Add r254 , r239
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 27 size: 3
+Set r240 , 3
+Add r254 , r240
 // alloc_stack - end
-// handle_binary_expr - output_register- r238
-// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
-Set r235 , 0
-Set r234 , 4
-Add r234 , r255
-Add r234 , r235
-Read r237 , r234
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
-Set r235 , 0
+// call_function_code - handle reference variable
+Set r237 , 4294967294
+Add r237 , r254
+// read_addr_of_self
 Set r236 , 5
 Add r236 , r255
-Add r236 , r235
-Read r242 , r236
-// read_register- end
-Sub r237 , r242
-Cp r238 , r237
-// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
-Set r235 , 0
-Set r234 , 6
-Add r234 , r255
-Add r234 , r235
-Write r234 , r238
-// write_register- end
-// handle_binary_expr - end
-// allocate_stack - current: 7 size: 1
-Set r234 , 1
-Add r254 , r234
-// alloc_stack - end
-// handle_binary_expr - output_register- r238
+// read_addr_of_self - end
+Write r237 , r236
+// call_function_code - handle reference variable, end
+RSet r236 , :function-return57
+Add r236 , r251
+Set r234 , 0
+Sub r236 , r234
+Write r254 , r236
+RSet r237 , :function_fault_10
+RJmp r237
+:function-return57
+// function call output: None
+// function call converted output data : None
+
+RSet r238 , :if_chain_end55
+RJmp r238
+// if contents end
+:if56
 // read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
 Set r236 , 0
-Set r233 , 2
-Add r233 , r255
-Add r233 , r236
-Read r242 , r233
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
-Set r236 , 0
-Set r235 , 6
-Add r235 , r255
-Add r235 , r236
-Read r237 , r235
+Set r237 , 2
+Add r237 , r255
+Add r237 , r236
+Read r254 , r237
 // read_register- end
-Shl r242 , r237
-Cp r238 , r242
-// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
-Set r236 , 0
-Set r233 , 7
-Add r233 , r255
-Add r233 , r236
-Write r233 , r238
-// write_register- end
-// handle_binary_expr - end
-// assign_new_variable - end
-
-// assign_new_variable
-// access_static_variable
-// allocate_stack - current: 8 size: 1
-Set r250 , 1
-Add r254 , r250
+// else - condition: None
+// allocate_stack - current: 3 size: 1
+Set r237 , 1
+Add r254 , r237
 // alloc_stack - end
-RSet r249 , :heap_main_pool_head
-Add r249 , r251
-Set r248 , 0
-Sub r249 , r248
-// write_directly_to_reference_pointer - 247, input: r249
-Set r247 , 8
-Add r247 , r255
-Write r247 , r249
-// write_directly_to_reference_pointer - end
-// access_static_variable-end
-// assign_new_variable - end
-
-// assign_new_variable
-// allocate_stack - current: 9 size: 1
-Set r250 , 1
-Add r254 , r250
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r235 , 0
+Set r239 , 3
+Add r239 , r255
+Add r239 , r235
+Write r239 , r254
+// write_register- end
+// else contents
+// allocate_stack - current: 4 size: 22
+Set r233 , 22
+Add r254 , r233
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r247 , 0
-Set r246 , 8
-Add r246 , r255
-Read r246 , r246
-Set r248 , 0
-Add r246 , r248
-Add r246 , r247
-Read r249 , r246
-// read_register- end
-// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
-Set r247 , 0
-Set r248 , 9
-Add r248 , r255
-Add r248 , r247
-Write r248 , r249
+Set r236 , 21
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 0
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
 // write_register- end
-// assign_new_variable - end
-
-// assignment
-// access_static_variable
-// allocate_stack - current: 10 size: 1
-Set r250 , 1
-Add r254 , r250
+Set r236 , 100
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 1
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 105
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 2
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 118
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 3
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 105
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 4
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 100
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 5
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 101
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 6
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 32
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 7
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 98
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 8
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 121
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 9
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 32
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 10
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 122
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 11
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 101
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 12
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 114
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 13
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 111
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 14
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 32
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 15
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 105
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 16
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 110
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 17
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 32
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 18
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 109
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 19
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 111
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 20
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 100
+// write_register- self: Data { stack_frame_offset: 4, size: 22, data_type: Array { inside: Char, len: 21 } }
+Set r231 , 21
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 26 size: 3
+Set r231 , 3
+Add r254 , r231
 // alloc_stack - end
-RSet r249 , :heap_main_pool_head
-Add r249 , r251
-Set r248 , 0
-Sub r249 , r248
-// write_directly_to_reference_pointer - 247, input: r249
-Set r247 , 10
-Add r247 , r255
-Write r247 , r249
-// write_directly_to_reference_pointer - end
-// access_static_variable-end
-// handle_binary_expr
-// access_static_variable
-// allocate_stack - current: 11 size: 1
+// call_function_code - handle reference variable
+Set r230 , 4294967294
+Add r230 , r254
+// read_addr_of_self
+Set r229 , 4
+Add r229 , r255
+// read_addr_of_self - end
+Write r230 , r229
+// call_function_code - handle reference variable, end
+RSet r229 , :function-return59
+Add r229 , r251
+Set r227 , 0
+Sub r229 , r227
+Write r254 , r229
+RSet r230 , :function_fault_10
+RJmp r230
+:function-return59
+// function call output: None
+// function call converted output data : None
+
+RSet r239 , :if_chain_end55
+RJmp r239
+// else contents end
+:else58
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r229 , 0
+Set r230 , 3
+Add r230 , r255
+Add r230 , r229
+Read r254 , r230
+// read_register- end
+:if_chain_end55
+// Return
+Cp r250 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
+// read_register- end
+Cp r255 , r249
+Read r250 , r250
+Iret r250
+// function: Function { is_interrupt_function: true, name: "bad_address_interrupt", input: [FunctionInputData { name: "cause", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_bad_address_interrupt_13" }
+:function_bad_address_interrupt_13
+Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
 Set r249 , 1
 Add r254 , r249
 // alloc_stack - end
-RSet r247 , :heap_main_pool_head
-Add r247 , r251
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
 Set r246 , 0
-Sub r247 , r246
-// write_directly_to_reference_pointer - 245, input: r247
-Set r245 , 11
+Set r245 , 1
 Add r245 , r255
-Write r245 , r247
-// write_directly_to_reference_pointer - end
-// access_static_variable-end
-// allocate_stack - current: 12 size: 1
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// if- condition: Binary { left: Identifier("cause", DebugData { line: 37, file: "interrupt_handlers.tip" }), operator: Token { kind: Equals, value: "==", line: 37 }, right: Number(2, DebugData { line: 37, file: "interrupt_handlers.tip" }), debug_data: DebugData { line: 37, file: "interrupt_handlers.tip" } }
+// allocate_stack - current: 2 size: 1
+Set r245 , 1
+Add r254 , r245
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r247 , 0
+Set r244 , 2
+Add r244 , r255
+Add r244 , r247
+Write r244 , r254
+// write_register- end
+// if condition data
+// handle_binary_expr
+// handle number
+Set r244 , 2
+// allocate_stack - current: 3 size: 1
 Set r247 , 1
 Add r254 , r247
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
 Set r243 , 0
-Set r242 , 7
+Set r242 , 3
 Add r242 , r255
 Add r242 , r243
-Read r245 , r242
+Write r242 , r244
+// write_register- end
+// handle number end
+// allocate_stack - current: 4 size: 1
+Set r241 , 1
+Add r254 , r241
+// alloc_stack - end
+// handle_binary_expr - output_register- r242
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+Set r239 , 0
+Set r238 , 4294967294
+Add r238 , r255
+Add r238 , r239
+Read r243 , r238
 // read_register- end
-// write_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
-Set r243 , 0
-Set r244 , 12
-Add r244 , r255
-Add r244 , r243
-Write r244 , r245
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r239 , 0
+Set r240 , 3
+Add r240 , r255
+Add r240 , r239
+Read r246 , r240
+// read_register- end
+Eq r243 , r246 , r242
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Bool }
+Set r239 , 0
+Set r238 , 4
+Add r238 , r255
+Add r238 , r239
+Write r238 , r242
 // write_register- end
-// allocate_stack - current: 13 size: 1
-Set r242 , 1
-Add r254 , r242
+// handle_binary_expr - end
+// read condition
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Bool }
+Set r243 , 0
+Set r238 , 4
+Add r238 , r255
+Add r238 , r243
+Read r242 , r238
+// read_register- end
+Not r242
+RSet r238 , :if61
+RJmpc r238 , r242
+// if contents
+// allocate_stack - current: 5 size: 20
+Set r246 , 20
+Add r254 , r246
 // alloc_stack - end
-// handle_binary_expr - output_register- r245
-// read_register- self: Data { stack_frame_offset: 11, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
+Set r243 , 19
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
 Set r240 , 0
-Set r239 , 11
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 98
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 1
+Set r239 , 5
 Add r239 , r255
-Read r239 , r239
-Set r241 , 0
-Add r239 , r241
 Add r239 , r240
-Read r244 , r239
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 12, size: 1, data_type: U32 }
-Set r240 , 0
-Set r241 , 12
-Add r241 , r255
-Add r241 , r240
-Read r243 , r241
-// read_register- end
-Add r244 , r243
-Cp r245 , r244
-// write_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
-Set r240 , 0
-Set r239 , 13
+Write r239 , r243
+// write_register- end
+Set r243 , 97
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 2
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 3
+Set r239 , 5
 Add r239 , r255
 Add r239 , r240
-Write r239 , r245
+Write r239 , r243
 // write_register- end
-// handle_binary_expr - end
-// assignment data: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
-// assignment- variable.is_reference: true data.is_reference: false 
-// read_register- self: Data { stack_frame_offset: 13, size: 1, data_type: U32 }
-Set r244 , 0
-Set r239 , 13
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 4
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 97
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 5
+Set r239 , 5
 Add r239 , r255
-Add r239 , r244
-Read r245 , r239
-// read_register- end
-// write_register- self: Data { stack_frame_offset: 10, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r244 , 0
-Set r243 , 10
-Add r243 , r255
-Read r243 , r243
-Set r239 , 0
-Add r243 , r239
-Add r243 , r244
-Write r243 , r245
+Add r239 , r240
+Write r239 , r243
 // write_register- end
-// end assignment
-
-// if- condition: Binary { left: Identifier("heap_pool_head", DebugData { line: 70, file: "allocator.tip" }), operator: Token { kind: Greater, value: ">", line: 70 }, right: FunctionCall { left: Identifier("access_static", DebugData { line: 70, file: "allocator.tip" }), values: [String("\"heap_end", DebugData { line: 70, file: "allocator.tip" })], debug_data: DebugData { line: 70, file: "allocator.tip" } }, debug_data: DebugData { line: 70, file: "allocator.tip" } }
-// allocate_stack - current: 14 size: 1
-Set r250 , 1
-Add r254 , r250
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
-Set r248 , 0
-Set r247 , 14
-Add r247 , r255
-Add r247 , r248
-Write r247 , r254
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 6
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
 // write_register- end
-// if condition data
-// handle_binary_expr
-// access_static_variable
-// allocate_stack - current: 15 size: 1
-Set r247 , 1
-Add r254 , r247
-// alloc_stack - end
-RSet r248 , :heap_end
-Add r248 , r251
-Set r249 , 0
-Sub r248 , r249
-// write_directly_to_reference_pointer - 246, input: r248
-Set r246 , 15
-Add r246 , r255
-Write r246 , r248
-// write_directly_to_reference_pointer - end
-// access_static_variable-end
-// allocate_stack - current: 16 size: 1
-Set r244 , 1
-Add r254 , r244
-// alloc_stack - end
-// handle_binary_expr - output_register- r248
-// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r242 , 0
-Set r241 , 8
-Add r241 , r255
-Read r241 , r241
-Set r243 , 0
-Add r241 , r243
-Add r241 , r242
-Read r246 , r241
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 15, size: 1, data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 } }
-Set r242 , 0
-Set r243 , 15
-Add r243 , r255
-Read r243 , r243
-Set r241 , 0
-Add r243 , r241
-Add r243 , r242
-Read r245 , r243
-// read_register- end
-Gt r246 , r245 , r248
-// write_register- self: Data { stack_frame_offset: 16, size: 1, data_type: Bool }
-Set r242 , 0
-Set r241 , 16
-Add r241 , r255
-Add r241 , r242
-Write r241 , r248
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 7
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 114
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 8
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 9
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 115
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 10
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 115
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 11
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 12
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
 // write_register- end
-// handle_binary_expr - end
-// read condition
-// read_register- self: Data { stack_frame_offset: 16, size: 1, data_type: Bool }
-Set r246 , 0
-Set r241 , 16
-Add r241 , r255
-Add r241 , r246
-Read r248 , r241
-// read_register- end
-Not r248
-RSet r241 , :if38
-RJmpc r241 , r248
-// if contents
-// handle number
-Set r242 , 251
-// allocate_stack - current: 17 size: 1
-Set r243 , 1
-Add r254 , r243
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 17, size: 1, data_type: U32 }
-Set r239 , 0
-Set r238 , 17
-Add r238 , r255
-Add r238 , r239
-Write r238 , r242
+Set r243 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 13
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
 // write_register- end
-// handle number end
-Set r246 , 0
-// read_register- self: Data { stack_frame_offset: 17, size: 1, data_type: U32 }
-Set r239 , 0
+Set r243 , 110
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 14
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 15
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 114
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 16
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
 Set r240 , 17
-Add r240 , r255
-Add r240 , r239
-Read r245 , r240
-// read_register- end
-Phrp r246 , r245
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 97
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 18
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 20, data_type: Array { inside: Char, len: 19 } }
+Set r240 , 19
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 25 size: 3
+Set r240 , 3
+Add r254 , r240
+// alloc_stack - end
+// call_function_code - handle reference variable
+Set r237 , 4294967294
+Add r237 , r254
+// read_addr_of_self
+Set r236 , 5
+Add r236 , r255
+// read_addr_of_self - end
+Write r237 , r236
+// call_function_code - handle reference variable, end
+RSet r236 , :function-return62
+Add r236 , r251
+Set r234 , 0
+Sub r236 , r234
+Write r254 , r236
+RSet r237 , :function_fault_10
+RJmp r237
+:function-return62
+// function call output: None
+// function call converted output data : None
 
-RSet r241 , :if_chain_end37
-RJmp r241
+RSet r238 , :if_chain_end60
+RJmp r238
 // if contents end
-:if38
-// read_register- self: Data { stack_frame_offset: 14, size: 1, data_type: U32 }
-Set r246 , 0
-Set r240 , 14
-Add r240 , r255
-Add r240 , r246
-Read r254 , r240
-// read_register- end
-:if_chain_end37
-// Return
-Set r249 , 4294967293
-// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 9
-Add r246 , r255
-Add r246 , r247
-Read r250 , r246
-// read_register- end
-Add r249 , r255
-Write r249 , r250
-Cp r250 , r255
-Cp r254 , r255
-// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r247 , 0
-Set r248 , 1
-Add r248 , r255
-Add r248 , r247
-Read r249 , r248
+:if61
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r236 , 0
+Set r237 , 2
+Add r237 , r255
+Add r237 , r236
+Read r254 , r237
 // read_register- end
-Cp r255 , r249
-Read r250 , r250
-Jmp r250
-
-// function: Function { is_interrupt_function: false, name: "max", input: [FunctionInputData { name: "a", data_type: U32, stack_frame_offset: -2 }, FunctionInputData { name: "b", data_type: U32, stack_frame_offset: -3 }], output: Some(FunctionInputData { name: "max", data_type: U32, stack_frame_offset: -4 }), label_name: "function_max_6" }
-:function_max_6
-Cp r250 , r255
-Cp r255 , r254
-// allocate_stack - current: 0 size: 1
-Set r249 , 1
-Add r254 , r249
-// alloc_stack - end
-// allocate_stack - current: 1 size: 1
-Set r248 , 1
-Add r254 , r248
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r246 , 0
-Set r245 , 1
-Add r245 , r255
-Add r245 , r246
-Write r245 , r250
-// write_register- end
-// assign_new_variable
+// else - condition: Some(Binary { left: Identifier("cause", DebugData { line: 39, file: "interrupt_handlers.tip" }), operator: Token { kind: Equals, value: "==", line: 39 }, right: Number(3, DebugData { line: 39, file: "interrupt_handlers.tip" }), debug_data: DebugData { line: 39, file: "interrupt_handlers.tip" } })
 // allocate_stack - current: 3 size: 1
-Set r247 , 1
-Add r254 , r247
+Set r237 , 1
+Add r254 , r237
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
-Set r244 , 0
-Set r239 , 2
-Add r239 , r255
-Add r239 , r244
-Read r245 , r239
-// read_register- end
-Not r245
 // write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
-Set r244 , 0
-Set r243 , 3
-Add r243 , r255
-Add r243 , r244
-Write r243 , r245
+Set r235 , 0
+Set r239 , 3
+Add r239 , r255
+Add r239 , r235
+Write r239 , r254
 // write_register- end
-// handle_binary_expr - end
-// assign_new_variable - end
-
-// Return
-// handle_binary_expr
+// else condition data
 // handle_binary_expr
+// handle number
+Set r236 , 3
 // allocate_stack - current: 4 size: 1
-Set r248 , 1
-Add r254 , r248
+Set r233 , 1
+Add r254 , r233
 // alloc_stack - end
-// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
-Set r246 , 0
-Set r245 , 3
-Add r245 , r255
-Add r245 , r246
-Read r250 , r245
-// read_register- end
-Not r250
 // write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
-Set r246 , 0
-Set r247 , 4
-Add r247 , r255
-Add r247 , r246
-Write r247 , r250
+Set r231 , 0
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
 // write_register- end
-// handle_binary_expr - end
+// handle number end
 // allocate_stack - current: 5 size: 1
-Set r246 , 1
-Add r254 , r246
+Set r229 , 1
+Add r254 , r229
 // alloc_stack - end
-// handle_binary_expr - output_register- r249
+// handle_binary_expr - output_register- r230
 // read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
-Set r244 , 0
-Set r243 , 4294967294
-Add r243 , r255
-Add r243 , r244
-Read r250 , r243
+Set r227 , 0
+Set r226 , 4294967294
+Add r226 , r255
+Add r226 , r227
+Read r231 , r226
 // read_register- end
 // read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
-Set r244 , 0
-Set r245 , 4
-Add r245 , r255
-Add r245 , r244
-Read r247 , r245
+Set r227 , 0
+Set r228 , 4
+Add r228 , r255
+Add r228 , r227
+Read r232 , r228
 // read_register- end
-And r250 , r247
-Cp r249 , r250
-// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
-Set r244 , 0
-Set r243 , 5
-Add r243 , r255
-Add r243 , r244
-Write r243 , r249
+Eq r231 , r232 , r230
+// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: Bool }
+Set r227 , 0
+Set r226 , 5
+Add r226 , r255
+Add r226 , r227
+Write r226 , r230
+// write_register- end
+// handle_binary_expr - end
+// read condition
+// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: Bool }
+Set r232 , 0
+Set r231 , 5
+Add r231 , r255
+Add r231 , r232
+Read r235 , r231
+// read_register- end
+Not r235
+RSet r239 , :else63
+RJmpc r239 , r235
+// else contents
+// allocate_stack - current: 6 size: 21
+Set r232 , 21
+Add r254 , r232
+// alloc_stack - end
+Set r231 , 20
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 0
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 98
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 1
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
+// write_register- end
+Set r231 , 97
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 2
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 100
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 3
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
+// write_register- end
+Set r231 , 32
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 4
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 97
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 5
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
+// write_register- end
+Set r231 , 100
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 6
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 100
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 7
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
+// write_register- end
+Set r231 , 114
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 8
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 101
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 9
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
+// write_register- end
+Set r231 , 115
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 10
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 115
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 11
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
+// write_register- end
+Set r231 , 32
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 12
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 105
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 13
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
+// write_register- end
+Set r231 , 110
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 14
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 32
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 15
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
+// write_register- end
+Set r231 , 119
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 16
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 114
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 17
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
+// write_register- end
+Set r231 , 105
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 18
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+Set r231 , 116
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 19
+Set r230 , 6
+Add r230 , r255
+Add r230 , r226
+Write r230 , r231
 // write_register- end
-// handle_binary_expr - end
-// handle_binary_expr
-// allocate_stack - current: 6 size: 1
-Set r243 , 1
-Add r254 , r243
+Set r231 , 101
+// write_register- self: Data { stack_frame_offset: 6, size: 21, data_type: Array { inside: Char, len: 20 } }
+Set r226 , 20
+Set r227 , 6
+Add r227 , r255
+Add r227 , r226
+Write r227 , r231
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 27 size: 3
+Set r226 , 3
+Add r254 , r226
 // alloc_stack - end
-// handle_binary_expr - output_register- r249
-// read_register- self: Data { stack_frame_offset: -3, size: 1, data_type: U32 }
-Set r245 , 0
-Set r242 , 4294967293
-Add r242 , r255
-Add r242 , r245
-Read r247 , r242
-// read_register- end
+// call_function_code - handle reference variable
+Set r230 , 4294967294
+Add r230 , r254
+// read_addr_of_self
+Set r228 , 6
+Add r228 , r255
+// read_addr_of_self - end
+Write r230 , r228
+// call_function_code - handle reference variable, end
+RSet r228 , :function-return64
+Add r228 , r251
+Set r224 , 0
+Sub r228 , r224
+Write r254 , r228
+RSet r230 , :function_fault_10
+RJmp r230
+:function-return64
+// function call output: None
+// function call converted output data : None
+
+RSet r239 , :if_chain_end60
+RJmp r239
+// else contents end
+:else63
 // read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
-Set r245 , 0
-Set r244 , 3
-Add r244 , r255
-Add r244 , r245
-Read r250 , r244
+Set r228 , 0
+Set r230 , 3
+Add r230 , r255
+Add r230 , r228
+Read r254 , r230
 // read_register- end
-And r247 , r250
-Cp r249 , r247
-// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
-Set r245 , 0
-Set r242 , 6
-Add r242 , r255
-Add r242 , r245
-Write r242 , r249
+// else - condition: None
+// allocate_stack - current: 4 size: 1
+Set r235 , 1
+Add r254 , r235
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r230 , 0
+Set r228 , 4
+Add r228 , r255
+Add r228 , r230
+Write r228 , r254
 // write_register- end
-// handle_binary_expr - end
-// allocate_stack - current: 7 size: 1
-Set r242 , 1
-Add r254 , r242
+// else contents
+// allocate_stack - current: 5 size: 23
+Set r225 , 23
+Add r254 , r225
 // alloc_stack - end
-// handle_binary_expr - output_register- r249
-// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: U32 }
-Set r244 , 0
-Set r241 , 5
-Add r241 , r255
-Add r241 , r244
-Read r250 , r241
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
-Set r244 , 0
-Set r245 , 6
-Add r245 , r255
-Add r245 , r244
-Read r247 , r245
-// read_register- end
-Or r250 , r247
-Cp r249 , r250
-// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
-Set r244 , 0
-Set r241 , 7
-Add r241 , r255
-Add r241 , r244
-Write r241 , r249
+Set r239 , 22
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 0
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 98
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 1
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 97
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 2
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 3
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 4
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 97
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 5
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 6
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 7
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 114
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 8
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 9
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
 // write_register- end
-// handle_binary_expr - end
-Set r247 , 4294967292
-// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
-Set r241 , 0
-Set r244 , 7
-Add r244 , r255
-Add r244 , r241
-Read r249 , r244
+Set r239 , 115
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 10
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 115
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 11
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 12
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 13
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 110
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 14
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 15
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 16
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 120
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 17
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 18
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 99
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 19
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 117
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 20
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+Set r239 , 116
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 21
+Set r227 , 5
+Add r227 , r255
+Add r227 , r223
+Write r227 , r239
+// write_register- end
+Set r239 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 23, data_type: Array { inside: Char, len: 22 } }
+Set r223 , 22
+Set r222 , 5
+Add r222 , r255
+Add r222 , r223
+Write r222 , r239
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 28 size: 3
+Set r223 , 3
+Add r254 , r223
+// alloc_stack - end
+// call_function_code - handle reference variable
+Set r227 , 4294967294
+Add r227 , r254
+// read_addr_of_self
+Set r221 , 5
+Add r221 , r255
+// read_addr_of_self - end
+Write r227 , r221
+// call_function_code - handle reference variable, end
+RSet r221 , :function-return66
+Add r221 , r251
+Set r219 , 0
+Sub r221 , r219
+Write r254 , r221
+RSet r227 , :function_fault_10
+RJmp r227
+:function-return66
+// function call output: None
+// function call converted output data : None
+
+RSet r228 , :if_chain_end60
+RJmp r228
+// else contents end
+:else65
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
+Set r221 , 0
+Set r227 , 4
+Add r227 , r255
+Add r227 , r221
+Read r254 , r227
 // read_register- end
-Add r247 , r255
-Write r247 , r249
-Cp r249 , r255
+:if_chain_end60
+// Return
+Cp r250 , r255
 Cp r254 , r255
 // read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r241 , 0
-Set r250 , 1
-Add r250 , r255
-Add r250 , r241
-Read r247 , r250
+Set r247 , 0
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
 // read_register- end
-Cp r255 , r247
-Read r249 , r249
-Jmp r249
-
-// function: Function { is_interrupt_function: false, name: "ceil_log2", input: [FunctionInputData { name: "x", data_type: U32, stack_frame_offset: -2 }], output: Some(FunctionInputData { name: "ceil_log2", data_type: U32, stack_frame_offset: -3 }), label_name: "function_ceil_log2_7" }
-:function_ceil_log2_7
+Cp r255 , r249
+Read r250 , r250
+Iret r250
+// function: Function { is_interrupt_function: true, name: "bad_thread_index_interrupt", input: [FunctionInputData { name: "cause", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_bad_thread_index_interrupt_14" }
+:function_bad_thread_index_interrupt_14
 Cp r250 , r255
 Cp r255 , r254
 // allocate_stack - current: 0 size: 1
@@ -4380,255 +7569,587 @@ Add r245 , r255
 Add r245 , r246
 Write r245 , r250
 // write_register- end
-// assign_new_variable
-// handle number
-Set r245 , 0
+// if- condition: Binary { left: Identifier("cause", DebugData { line: 49, file: "interrupt_handlers.tip" }), operator: Token { kind: Equals, value: "==", line: 49 }, right: Number(5, DebugData { line: 49, file: "interrupt_handlers.tip" }), debug_data: DebugData { line: 49, file: "interrupt_handlers.tip" } }
 // allocate_stack - current: 2 size: 1
-Set r246 , 1
-Add r254 , r246
+Set r245 , 1
+Add r254 , r245
 // alloc_stack - end
 // write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
-Set r244 , 0
-Set r243 , 2
-Add r243 , r255
-Add r243 , r244
-Write r243 , r245
-// write_register- end
-// handle number end
-// assign_new_variable - end
-
-// assign_new_variable
-// handle number
-Set r250 , 1
-// allocate_stack - current: 3 size: 1
-Set r249 , 1
-Add r254 , r249
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
 Set r247 , 0
-Set r246 , 3
-Add r246 , r255
-Add r246 , r247
-Write r246 , r250
-// write_register- end
-// handle number end
-// assign_new_variable - end
-
-// allocate_stack - current: 4 size: 1
-Set r250 , 1
-Add r254 , r250
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
-Set r248 , 0
-Set r247 , 4
-Add r247 , r255
-Add r247 , r248
-Write r247 , r254
-// write_register- end
-:while_start39
-// handle_binary_expr
-// allocate_stack - current: 5 size: 1
-Set r244 , 1
-Add r254 , r244
-// alloc_stack - end
-// handle_binary_expr - output_register- r249
-// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
-Set r242 , 0
-Set r241 , 3
-Add r241 , r255
-Add r241 , r242
-Read r246 , r241
-// read_register- end
-// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
-Set r242 , 0
-Set r243 , 4294967294
-Add r243 , r255
-Add r243 , r242
-Read r245 , r243
-// read_register- end
-Lt r246 , r245 , r249
-// write_register- self: Data { stack_frame_offset: 5, size: 1, data_type: Bool }
-Set r242 , 0
-Set r241 , 5
-Add r241 , r255
-Add r241 , r242
-Write r241 , r249
+Set r244 , 2
+Add r244 , r255
+Add r244 , r247
+Write r244 , r254
 // write_register- end
-// handle_binary_expr - end
-// read_register- self: Data { stack_frame_offset: 5, size: 1, data_type: Bool }
-Set r245 , 0
-Set r246 , 5
-Add r246 , r255
-Add r246 , r245
-Read r247 , r246
-// read_register- end
-Not r247
-RSet r248 , :while_end40
-RJmpc r248 , r247
-// while loop contents:
-// assignment
+// if condition data
 // handle_binary_expr
 // handle number
-Set r246 , 1
-// allocate_stack - current: 6 size: 1
-Set r245 , 1
-Add r254 , r245
+Set r244 , 5
+// allocate_stack - current: 3 size: 1
+Set r247 , 1
+Add r254 , r247
 // alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
-Set r241 , 0
-Set r242 , 6
-Add r242 , r255
-Add r242 , r241
-Write r242 , r246
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r243 , 0
+Set r242 , 3
+Add r242 , r255
+Add r242 , r243
+Write r242 , r244
 // write_register- end
 // handle number end
-// allocate_stack - current: 7 size: 1
-Set r243 , 1
-Add r254 , r243
+// allocate_stack - current: 4 size: 1
+Set r241 , 1
+Add r254 , r241
 // alloc_stack - end
 // handle_binary_expr - output_register- r242
-// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
 Set r239 , 0
-Set r238 , 3
+Set r238 , 4294967294
 Add r238 , r255
 Add r238 , r239
-Read r241 , r238
+Read r243 , r238
 // read_register- end
-// read_register- self: Data { stack_frame_offset: 6, size: 1, data_type: U32 }
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
 Set r239 , 0
-Set r240 , 6
+Set r240 , 3
 Add r240 , r255
 Add r240 , r239
-Read r249 , r240
+Read r246 , r240
 // read_register- end
-Shl r241 , r249
-Cp r242 , r241
-// write_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
+Eq r243 , r246 , r242
+// write_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Bool }
 Set r239 , 0
-Set r238 , 7
+Set r238 , 4
 Add r238 , r255
 Add r238 , r239
 Write r238 , r242
 // write_register- end
-// handle_binary_expr - end
-// assignment data: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
-// assignment- variable.is_reference: false data.is_reference: false 
-// read_register- self: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
-Set r241 , 0
-Set r238 , 7
-Add r238 , r255
-Add r238 , r241
-Read r242 , r238
-// read_register- end
-// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
-Set r241 , 0
-Set r249 , 3
-Add r249 , r255
-Add r249 , r241
-Write r249 , r242
+// handle_binary_expr - end
+// read condition
+// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: Bool }
+Set r243 , 0
+Set r238 , 4
+Add r238 , r255
+Add r238 , r243
+Read r242 , r238
+// read_register- end
+Not r242
+RSet r238 , :if68
+RJmpc r238 , r242
+// if contents
+// allocate_stack - current: 5 size: 25
+Set r246 , 25
+Add r254 , r246
+// alloc_stack - end
+Set r243 , 24
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 0
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 98
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 1
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 97
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 2
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 3
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 4
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 116
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 5
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 104
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 6
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 114
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 7
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 8
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 97
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 9
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 10
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 11
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 12
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 110
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 13
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 100
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 14
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 101
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 15
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 120
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 16
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 17
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 18
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 110
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 19
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 32
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 20
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 21
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 110
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 22
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+Set r243 , 105
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 23
+Set r239 , 5
+Add r239 , r255
+Add r239 , r240
+Write r239 , r243
+// write_register- end
+Set r243 , 116
+// write_register- self: Data { stack_frame_offset: 5, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r240 , 24
+Set r237 , 5
+Add r237 , r255
+Add r237 , r240
+Write r237 , r243
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 30 size: 3
+Set r240 , 3
+Add r254 , r240
+// alloc_stack - end
+// call_function_code - handle reference variable
+Set r239 , 4294967294
+Add r239 , r254
+// read_addr_of_self
+Set r236 , 5
+Add r236 , r255
+// read_addr_of_self - end
+Write r239 , r236
+// call_function_code - handle reference variable, end
+RSet r236 , :function-return69
+Add r236 , r251
+Set r234 , 0
+Sub r236 , r234
+Write r254 , r236
+RSet r239 , :function_fault_10
+RJmp r239
+:function-return69
+// function call output: None
+// function call converted output data : None
+
+RSet r238 , :if_chain_end67
+RJmp r238
+// if contents end
+:if68
+// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
+Set r236 , 0
+Set r239 , 2
+Add r239 , r255
+Add r239 , r236
+Read r254 , r239
+// read_register- end
+// else - condition: None
+// allocate_stack - current: 3 size: 1
+Set r239 , 1
+Add r254 , r239
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r235 , 0
+Set r237 , 3
+Add r237 , r255
+Add r237 , r235
+Write r237 , r254
+// write_register- end
+// else contents
+// allocate_stack - current: 4 size: 25
+Set r233 , 25
+Add r254 , r233
+// alloc_stack - end
+Set r236 , 24
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 0
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 98
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 1
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 97
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 2
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 100
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 3
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 32
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 4
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 116
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 5
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 104
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 6
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 114
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 7
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 101
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 8
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 97
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 9
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 100
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 10
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 32
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 11
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 105
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 12
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 110
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 13
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 100
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 14
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 101
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 15
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 120
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 16
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 32
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 17
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
 // write_register- end
-// end assignment
-
-// assignment
-// handle_binary_expr
-// handle number
-Set r242 , 1
-// allocate_stack - current: 8 size: 1
-Set r249 , 1
-Add r254 , r249
-// alloc_stack - end
-// write_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
-Set r238 , 0
-Set r239 , 8
-Add r239 , r255
-Add r239 , r238
-Write r239 , r242
+Set r236 , 105
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 18
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
 // write_register- end
-// handle number end
-// allocate_stack - current: 9 size: 1
-Set r240 , 1
-Add r254 , r240
-// alloc_stack - end
-// handle_binary_expr - output_register- r239
-// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
-Set r236 , 0
-Set r235 , 2
-Add r235 , r255
-Add r235 , r236
-Read r238 , r235
-// read_register- end
-// read_register- self: Data { stack_frame_offset: 8, size: 1, data_type: U32 }
-Set r236 , 0
-Set r237 , 8
-Add r237 , r255
-Add r237 , r236
-Read r241 , r237
-// read_register- end
-Add r238 , r241
-Cp r239 , r238
-// write_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
-Set r236 , 0
-Set r235 , 9
-Add r235 , r255
-Add r235 , r236
-Write r235 , r239
+Set r236 , 110
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 19
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
 // write_register- end
-// handle_binary_expr - end
-// assignment data: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
-// assignment- variable.is_reference: false data.is_reference: false 
-// read_register- self: Data { stack_frame_offset: 9, size: 1, data_type: U32 }
-Set r238 , 0
-Set r235 , 9
-Add r235 , r255
-Add r235 , r238
-Read r239 , r235
-// read_register- end
-// write_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
-Set r238 , 0
-Set r241 , 2
-Add r241 , r255
-Add r241 , r238
-Write r241 , r239
+Set r236 , 32
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 20
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
 // write_register- end
-// end assignment
+Set r236 , 105
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 21
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 110
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 22
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+Set r236 , 116
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 23
+Set r232 , 4
+Add r232 , r255
+Add r232 , r231
+Write r232 , r236
+// write_register- end
+Set r236 , 114
+// write_register- self: Data { stack_frame_offset: 4, size: 25, data_type: Array { inside: Char, len: 24 } }
+Set r231 , 24
+Set r230 , 4
+Add r230 , r255
+Add r230 , r231
+Write r230 , r236
+// write_register- end
+// call_function_code
+// function.input.is_empty(), 0, 3
+// call_function_code - needed_stack: 3
+// allocate_stack - current: 29 size: 3
+Set r231 , 3
+Add r254 , r231
+// alloc_stack - end
+// call_function_code - handle reference variable
+Set r232 , 4294967294
+Add r232 , r254
+// read_addr_of_self
+Set r229 , 4
+Add r229 , r255
+// read_addr_of_self - end
+Write r232 , r229
+// call_function_code - handle reference variable, end
+RSet r229 , :function-return71
+Add r229 , r251
+Set r227 , 0
+Sub r229 , r227
+Write r254 , r229
+RSet r232 , :function_fault_10
+RJmp r232
+:function-return71
+// function call output: None
+// function call converted output data : None
 
-// while loop contents - end
-RSet r248 , :while_start39
-RJmp r248
-:while_end40
-// read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
-Set r241 , 0
-Set r238 , 4
-Add r238 , r255
-Add r238 , r241
-Read r254 , r238
+RSet r237 , :if_chain_end67
+RJmp r237
+// else contents end
+:else70
+// read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
+Set r229 , 0
+Set r232 , 3
+Add r232 , r255
+Add r232 , r229
+Read r254 , r232
 // read_register- end
+:if_chain_end67
 // Return
-Set r249 , 4294967293
-// read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 2
-Add r246 , r255
-Add r246 , r247
-Read r250 , r246
-// read_register- end
-Add r249 , r255
-Write r249 , r250
 Cp r250 , r255
 Cp r254 , r255
 // read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
 Set r247 , 0
-Set r248 , 1
-Add r248 , r255
-Add r248 , r247
-Read r249 , r248
+Set r246 , 1
+Add r246 , r255
+Add r246 , r247
+Read r249 , r246
 // read_register- end
 Cp r255 , r249
 Read r250 , r250
-Jmp r250
-
-// function: Function { is_interrupt_function: true, name: "keyboard_interrupt", input: [FunctionInputData { name: "data", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_keyboard_interrupt_8" }
-:function_keyboard_interrupt_8
+Iret r250
+// function: Function { is_interrupt_function: true, name: "serial_input_interrupt", input: [FunctionInputData { name: "count", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_serial_input_interrupt_15" }
+:function_serial_input_interrupt_15
 Cp r250 , r255
 Cp r255 , r254
 // allocate_stack - current: 0 size: 1
@@ -4646,31 +8167,53 @@ Add r245 , r255
 Add r245 , r246
 Write r245 , r250
 // write_register- end
-Set r245 , 0
-// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
+// Return
+Cp r245 , r255
+Cp r254 , r255
+// read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
 Set r244 , 0
-Set r243 , 4294967294
+Set r243 , 1
 Add r243 , r255
 Add r243 , r244
 Read r246 , r243
 // read_register- end
-Phrp r245 , r246
-
-// Return
+Cp r255 , r246
+Read r245 , r245
+Iret r245
+// function: Function { is_interrupt_function: true, name: "vblank_interrupt", input: [FunctionInputData { name: "frame_count", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_vblank_interrupt_16" }
+:function_vblank_interrupt_16
 Cp r250 , r255
+Cp r255 , r254
+// allocate_stack - current: 0 size: 1
+Set r249 , 1
+Add r254 , r249
+// alloc_stack - end
+// allocate_stack - current: 1 size: 1
+Set r248 , 1
+Add r254 , r248
+// alloc_stack - end
+// write_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
+Set r246 , 0
+Set r245 , 1
+Add r245 , r255
+Add r245 , r246
+Write r245 , r250
+// write_register- end
+// Return
+Cp r245 , r255
 Cp r254 , r255
 // read_register- self: Data { stack_frame_offset: 1, size: 1, data_type: U32 }
-Set r247 , 0
-Set r246 , 1
-Add r246 , r255
-Add r246 , r247
-Read r249 , r246
+Set r244 , 0
+Set r243 , 1
+Add r243 , r255
+Add r243 , r244
+Read r246 , r243
 // read_register- end
-Cp r255 , r249
-Read r250 , r250
-Iret r250
-// function: Function { is_interrupt_function: true, name: "timer_interrupt", input: [FunctionInputData { name: "data", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_timer_interrupt_9" }
-:function_timer_interrupt_9
+Cp r255 , r246
+Read r245 , r245
+Iret r245
+// function: Function { is_interrupt_function: true, name: "ipi_interrupt", input: [FunctionInputData { name: "message", data_type: U32, stack_frame_offset: -2 }], output: None, label_name: "function_ipi_interrupt_17" }
+:function_ipi_interrupt_17
 Cp r250 , r255
 Cp r255 , r254
 // allocate_stack - current: 0 size: 1
@@ -4688,52 +8231,15 @@ Add r245 , r255
 Add r245 , r246
 Write r245 , r250
 // write_register- end
-// allocate_stack - current: 2 size: 2
-Set r246 , 2
-Add r254 , r246
-// alloc_stack - end
-Set r245 , 1
-// write_register- self: Data { stack_frame_offset: 2, size: 2, data_type: Array { inside: Char, len: 1 } }
+Set r245 , 0
+// read_register- self: Data { stack_frame_offset: -2, size: 1, data_type: U32 }
 Set r244 , 0
-Set r243 , 2
+Set r243 , 4294967294
 Add r243 , r255
 Add r243 , r244
-Write r243 , r245
-// write_register- end
-Set r245 , 116
-// write_register- self: Data { stack_frame_offset: 2, size: 2, data_type: Array { inside: Char, len: 1 } }
-Set r244 , 1
-Set r247 , 2
-Add r247 , r255
-Add r247 , r244
-Write r247 , r245
-// write_register- end
-// call_function_code
-// function.input.is_empty(), 0, 3
-// call_function_code - needed_stack: 3
-// allocate_stack - current: 4 size: 3
-Set r244 , 3
-Add r254 , r244
-// alloc_stack - end
-// call_function_code - handle reference variable
-Set r243 , 4294967294
-Add r243 , r254
-// read_addr_of_self
-Set r242 , 2
-Add r242 , r255
-// read_addr_of_self - end
-Write r243 , r242
-// call_function_code - handle reference variable, end
-RSet r242 , :function-return41
-Add r242 , r251
-Set r240 , 0
-Sub r242 , r240
-Write r254 , r242
-RSet r243 , :function_print_chars_1
-RJmp r243
-:function-return41
-// function call output: None
-// function call converted output data : None
+Read r246 , r243
+// read_register- end
+Phrp r245 , r246
 
 // Return
 Cp r250 , r255
@@ -4748,8 +8254,8 @@ Read r249 , r246
 Cp r255 , r249
 Read r250 , r250
 Iret r250
-// function: Function { is_interrupt_function: false, name: "syscall_handler", input: [FunctionInputData { name: "syscall_id", data_type: U32, stack_frame_offset: -2 }, FunctionInputData { name: "additional_data_addr", data_type: U32, stack_frame_offset: -3 }, FunctionInputData { name: "output_data", data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 }, stack_frame_offset: -4 }], output: None, label_name: "function_syscall_handler_10" }
-:function_syscall_handler_10
+// function: Function { is_interrupt_function: false, name: "syscall_handler", input: [FunctionInputData { name: "syscall_id", data_type: U32, stack_frame_offset: -2 }, FunctionInputData { name: "additional_data_addr", data_type: U32, stack_frame_offset: -3 }, FunctionInputData { name: "output_data", data_type: Reference { inside: U32, offset_of_data_from_reference_addr: 0 }, stack_frame_offset: -4 }], output: None, label_name: "function_syscall_handler_18" }
+:function_syscall_handler_18
 Cp r250 , r255
 Cp r255 , r254
 // allocate_stack - current: 0 size: 1
@@ -4832,7 +8338,7 @@ Add r238 , r243
 Read r242 , r238
 // read_register- end
 Not r242
-RSet r238 , :if43
+RSet r238 , :if73
 RJmpc r238 , r242
 // if contents
 // assign_new_variable
@@ -4857,21 +8363,21 @@ Add r240 , r236
 // read_referenced_address - end
 Write r239 , r240
 // call_function_code - handle reference variable, end
-RSet r240 , :function-return44
+RSet r240 , :function-return74
 Add r240 , r251
 Set r236 , 0
 Sub r240 , r236
 Write r254 , r240
 RSet r239 , :function_print_chars_1
 RJmp r239
-:function-return44
+:function-return74
 // function call output: None
 // function call converted output data : None
 
-RSet r238 , :if_chain_end42
+RSet r238 , :if_chain_end72
 RJmp r238
 // if contents end
-:if43
+:if73
 // read_register- self: Data { stack_frame_offset: 2, size: 1, data_type: U32 }
 Set r240 , 0
 Set r239 , 2
@@ -4944,7 +8450,7 @@ Add r233 , r234
 Read r237 , r233
 // read_register- end
 Not r237
-RSet r243 , :else45
+RSet r243 , :else75
 RJmpc r243 , r237
 // else contents
 // assignment
@@ -4964,14 +8470,14 @@ Read r228 , r226
 Set r232 , 4294967294
 Add r232 , r254
 Write r232 , r228
-RSet r228 , :function-return46
+RSet r228 , :function-return76
 Add r228 , r251
 Set r226 , 0
 Sub r228 , r226
 Write r254 , r228
 RSet r232 , :function_core_allocate_4
 RJmp r232
-:function-return46
+:function-return76
 // function call output: Some(FunctionInputData { name: "core_allocate", data_type: U32, stack_frame_offset: -3 })
 // function call converted output data : Some(Data { stack_frame_offset: 7, size: 1, data_type: U32 })
 // assignment data: Data { stack_frame_offset: 7, size: 1, data_type: U32 }
@@ -4995,10 +8501,10 @@ Write r228 , r229
 // write_register- end
 // end assignment
 
-RSet r243 , :if_chain_end42
+RSet r243 , :if_chain_end72
 RJmp r243
 // else contents end
-:else45
+:else75
 // read_register- self: Data { stack_frame_offset: 3, size: 1, data_type: U32 }
 Set r228 , 0
 Set r232 , 3
@@ -5263,21 +8769,21 @@ Add r225 , r255
 // read_addr_of_self - end
 Write r230 , r225
 // call_function_code - handle reference variable, end
-RSet r225 , :function-return48
+RSet r225 , :function-return78
 Add r225 , r251
 Set r223 , 0
 Sub r225 , r223
 Write r254 , r225
 RSet r230 , :function_print_chars_1
 RJmp r230
-:function-return48
+:function-return78
 // function call output: None
 // function call converted output data : None
 
-RSet r228 , :if_chain_end42
+RSet r228 , :if_chain_end72
 RJmp r228
 // else contents end
-:else47
+:else77
 // read_register- self: Data { stack_frame_offset: 4, size: 1, data_type: U32 }
 Set r225 , 0
 Set r230 , 4
@@ -5285,7 +8791,7 @@ Add r230 , r255
 Add r230 , r225
 Read r254 , r230
 // read_register- end
-:if_chain_end42
+:if_chain_end72
 // Return
 
 Cp r250 , r255
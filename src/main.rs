@@ -1,6 +1,8 @@
-use std::{thread::sleep, time::Duration};
+use std::{path::PathBuf, thread::sleep, time::Duration};
 
 use anyhow::{Context, Ok, Result};
+use clap::Parser;
+use cpu::fb::FrameBufferMode;
 use log::{error, trace};
 
 const OS_ASM_FILE_ADDR: &str = "./programs/os/compiled/trash_can_os.asm";
@@ -13,8 +15,21 @@ const COMMAND_LINE_BIN_FILE_ADDR: &str = "./programs/command_line/compiled/comma
 const COMMAND_LINE_MAIN_CODE_FILE: &str = "command_line.tip";
 const COMMAND_LINE_INPUT_CODE_FILES_BASE_ADDRESS: &str = "./programs/command_line/code/";
 
+#[derive(clap::Parser)]
+struct Cli {
+    /// Run without a window, the frame buffer is only kept in memory
+    #[arg(long)]
+    headless: bool,
+    /// Save the frame buffer to this file (.png or .ppm) when the machine stops, headless only
+    #[arg(long, requires = "headless")]
+    screenshot: Option<PathBuf>,
+    #[command(flatten)]
+    cpu: cpu::Args,
+}
+
 #[tokio::main]
 pub async fn main() -> Result<()> {
+    let args = Cli::parse();
     colog::init();
     trace!("init log");
 
@@ -33,8 +48,24 @@ pub async fn main() -> Result<()> {
     )
     .await?;
 
-    cpu::main(OS_BIN_FILE_ADDR, COMMAND_LINE_BIN_FILE_ADDR).await?;
+    let frame_buffer_mode = if args.headless {
+        FrameBufferMode::Headless {
+            screenshot: args.screenshot,
+        }
+    } else {
+        FrameBufferMode::Window
+    };
+    cpu::main(
+        &args.cpu,
+        frame_buffer_mode.clone(),
+        OS_BIN_FILE_ADDR,
+        COMMAND_LINE_BIN_FILE_ADDR,
+    )
+    .await?;
 
+    if let FrameBufferMode::Headless { .. } = frame_buffer_mode {
+        return Ok(());
+    }
     loop {
         sleep(Duration::from_secs(100000));
     }